@@ -38,24 +38,140 @@ pub const ONE_DAY_SLOTS: u64 = 24 * ONE_HOUR_SLOTS;
 /// The number of slots in one week.
 pub const ONE_WEEK_SLOTS: u64 = 7 * ONE_DAY_SLOTS;
 
+/// Pause flag blocking Deploy.
+pub const PAUSE_DEPLOY: u64 = 1 << 0;
+
+/// Pause flag blocking Reset.
+pub const PAUSE_RESET: u64 = 1 << 1;
+
+/// Pause flag blocking Checkpoint.
+pub const PAUSE_CHECKPOINT: u64 = 1 << 2;
+
+/// Pause flag blocking ClaimSOL, ClaimORE, ReloadSOL and ClaimYield.
+pub const PAUSE_CLAIM: u64 = 1 << 3;
+
+/// Pause flag blocking Deposit.
+pub const PAUSE_STAKE: u64 = 1 << 4;
+
+/// Pause flag blocking Withdraw.
+pub const PAUSE_WITHDRAW: u64 = 1 << 5;
+
+/// Halts the game and new stake, leaving claims and withdrawals open so users can always exit.
+pub const PAUSE_ALL: u64 = PAUSE_DEPLOY | PAUSE_RESET | PAUSE_STAKE;
+
+/// The default maximum amount of SOL swapped per buyback (100 SOL).
+pub const DEFAULT_BUYBACK_CAP: u64 = 100 * 1_000_000_000;
+
+/// The default share of a referee's claim fee paid to their referrer (bps).
+pub const DEFAULT_REFERRAL_FEE: u64 = 1_000;
+
+/// The default odds of a round hitting the motherlode (1 in 625).
+pub const DEFAULT_MOTHERLODE_ODDS: u64 = 625;
+
+/// The default amount of ORE added to the motherlode per round, across all boards.
+pub const DEFAULT_MOTHERLODE_RATE: u64 = ONE_ORE / 5;
+
+/// Rounds draw their randomness from the SlotHashes sysvar.
+pub const RNG_MODE_SLOT_HASHES: u64 = 0;
+
+/// Rounds draw their randomness from their board's entropy var. Resets wait for the var to be
+/// revealed, and fall back to SlotHashes only after ENTROPY_TIMEOUT_SLOTS.
+pub const RNG_MODE_ENTROPY: u64 = 1;
+
+/// The number of slots after a round ends that a reset waits for the entropy var to be revealed
+/// before falling back to SlotHashes.
+pub const ENTROPY_TIMEOUT_SLOTS: u64 = 2 * ONE_MINUTE_SLOTS;
+
+/// The default number of seconds over which boosted ORE rewards vest (7 days).
+pub const DEFAULT_VESTING_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// The number of squares round and miner accounts have room for, enough for a 6x6 board. Boards
+/// may use fewer.
+pub const MAX_SQUARES: usize = 36;
+
+/// The number of squares round and miner accounts had room for before 6x6 boards, and the size
+/// of the default 5x5 board.
+pub const LEGACY_MAX_SQUARES: usize = 25;
+
+/// The smallest board geometry (2x2), enough room for the default 3 bonus squares.
+pub const MIN_SQUARES: u64 = 4;
+
+/// The number of bonus squares round accounts have room for.
+pub const MAX_BONUS_SQUARES: usize = 7;
+
+/// Marks an unused bonus square entry.
+pub const NO_BONUS_SQUARE: u8 = u8::MAX;
+
+/// The default number of bonus squares per round.
+pub const DEFAULT_BONUS_SQUARE_COUNT: u64 = 3;
+
+/// The default reward multiplier when a bonus square wins (percent, 200 = 2x).
+pub const DEFAULT_BONUS_SQUARE_MULTIPLIER: u64 = 200;
+
+/// The largest reward multiplier a bonus square may have (percent, 1_000 = 10x).
+pub const MAX_BONUS_SQUARE_MULTIPLIER: u64 = 1_000;
+
+/// The number of miners on a season leaderboard who share the prize.
+pub const SEASON_WINNERS: usize = 10;
+
+/// The largest tournament roster. Round-robin tournaments last one round less than their roster.
+pub const MAX_TOURNAMENT_PLAYERS: usize = 8;
+
+/// Share of a tournament's prize pool paid to each of the top finishers (bps). Tournaments with
+/// fewer players split the pool among the places they have.
+pub const TOURNAMENT_PAYOUTS: [u64; 3] = [5_000, 3_000, 2_000];
+
+/// The maximum share of a guild member's ORE rewards paid to the guild (bps).
+pub const MAX_GUILD_REVENUE_SHARE: u64 = 2_000;
+
+/// The default weight of a board in the split of round rewards.
+pub const DEFAULT_BOARD_WEIGHT: u64 = 100;
+
+/// The default fee taken from deployments on a board (bps).
+pub const DEFAULT_BOARD_ADMIN_FEE: u64 = 100;
+
+/// The maximum fee taken from deployments on a board (bps).
+pub const MAX_BOARD_ADMIN_FEE: u64 = 100;
+
+/// The default delay, in slots, before a scheduled config change can be executed.
+pub const DEFAULT_CHANGE_DELAY_SLOTS: u64 = ONE_DAY_SLOTS;
+
+/// The maximum delay, in slots, before a scheduled config change can be executed.
+pub const MAX_CHANGE_DELAY_SLOTS: u64 = 30 * ONE_DAY_SLOTS;
+
 /// The number of slots for breather between rounds.
 pub const INTERMISSION_SLOTS: u64 = 35;
 
 /// The maximum token supply (5 million).
 pub const MAX_SUPPLY: u64 = ONE_ORE * 5_000_000;
 
+/// The seed of the achievements account PDA.
+pub const ACHIEVEMENTS: &[u8] = b"achievements";
+
 /// The seed of the automation account PDA.
 pub const AUTOMATION: &[u8] = b"automation";
 
 /// The seed of the board account PDA.
 pub const BOARD: &[u8] = b"board";
 
+/// The seed of the change account PDA.
+pub const CHANGE: &[u8] = b"change";
+
 /// The seed of the config account PDA.
 pub const CONFIG: &[u8] = b"config";
 
+/// The seed of the guild account PDA.
+pub const GUILD: &[u8] = b"guild";
+
 /// The seed of the miner account PDA.
 pub const MINER: &[u8] = b"miner";
 
+/// The seed of the season account PDA.
+pub const SEASON: &[u8] = b"season";
+
+/// The seed of the season miner account PDA.
+pub const SEASON_MINER: &[u8] = b"season_miner";
+
 /// The seed of the seeker account PDA.
 pub const SEEKER: &[u8] = b"seeker";
 
@@ -68,6 +184,9 @@ pub const STAKE: &[u8] = b"stake";
 /// The seed of the round account PDA.
 pub const ROUND: &[u8] = b"round";
 
+/// The seed of the tournament account PDA.
+pub const TOURNAMENT: &[u8] = b"tournament";
+
 /// The seed of the treasury account PDA.
 pub const TREASURY: &[u8] = b"treasury";
 
@@ -106,3 +225,9 @@ pub const BOOST_RESERVE_TOKEN: Pubkey = pubkey!("Gce36ZUsBDJsoLrfCBxUB5Sfq2DsGun
 
 /// The fee paid to bots if they checkpoint a user.
 pub const CHECKPOINT_FEE: u64 = 10_000; // 0.00001 SOL
+
+/// The most accounts a batch of miners may add to a checkpoint_many transaction. A legacy
+/// transaction has room for about 33 accounts, 9 of which go to the payer, the compute budget
+/// program, the accounts shared by the batch and a season. The checkpoint_many benchmark in the
+/// program tests checks a full batch fits, in size and in compute units.
+pub const CHECKPOINT_MANY_MAX_ACCOUNTS: usize = 24;
@@ -8,6 +8,27 @@ pub enum OreError {
 
     #[error("Not authorized")]
     NotAuthorized = 1,
+
+    #[error("Automation daily spend cap reached")]
+    DailyCapReached = 2,
+
+    #[error("Change is not yet effective")]
+    ChangeNotReady = 3,
+
+    #[error("Instruction is paused")]
+    Paused = 4,
+
+    #[error("Swap output below minimum")]
+    SlippageExceeded = 5,
+
+    #[error("Buyback exceeds the SOL cap")]
+    BuybackCapExceeded = 6,
+
+    #[error("Miner has no claim recipient")]
+    NoClaimRecipient = 7,
+
+    #[error("Entropy var has not been revealed")]
+    EntropyNotReady = 8,
 }
 
 error!(OreError);
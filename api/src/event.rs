@@ -2,16 +2,30 @@ use serde::{Deserialize, Serialize};
 use steel::*;
 
 pub enum OreEvent {
-    Reset = 0,
+    /// Resets logged before multiple boards, whose events end at `ts` without a board id.
+    LegacyReset = 0,
     Bury = 1,
-    Deploy = 2,
-    Liq = 3,
+    /// Deploys logged before multiple boards, whose events end at `ts` without a board id.
+    LegacyDeploy = 2,
+    /// Liqs logged before the treasury share was recorded, whose events end at `ts`.
+    LegacyLiq = 3,
+    Liq = 4,
+    Reset = 5,
+    Achievement = 6,
+    Motherlode = 7,
+    EntropyFallback = 8,
+    Checkpoint = 9,
+    Commit = 10,
+    Reveal = 11,
+    AutomationClosed = 12,
+    Deploy = 13,
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct ResetEvent {
-    /// The event discriminator.
+    /// The event discriminator. Resets logged before multiple boards used OreEvent::LegacyReset
+    /// and end at `ts`.
     pub disc: u64,
 
     /// The block that was opened for trading.
@@ -49,6 +63,9 @@ pub struct ResetEvent {
 
     /// The timestamp of the event.
     pub ts: i64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
 }
 
 #[repr(C)]
@@ -76,7 +93,8 @@ pub struct BuryEvent {
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct DeployEvent {
-    /// The event discriminator.
+    /// The event discriminator. Deploys logged before multiple boards used OreEvent::LegacyDeploy
+    /// and end at `ts`.
     pub disc: u64,
 
     /// The authority of the deployer.
@@ -93,12 +111,16 @@ pub struct DeployEvent {
 
     /// The timestamp of the event.
     pub ts: i64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
 pub struct LiqEvent {
-    /// The event discriminator.
+    /// The event discriminator. Liqs logged before the treasury share was recorded used
+    /// OreEvent::LegacyLiq and end at `ts`.
     pub disc: u64,
 
     /// The amount of SOL sent to the liq manager.
@@ -109,9 +131,198 @@ pub struct LiqEvent {
 
     /// The timestamp of the event.
     pub ts: i64,
+
+    /// The treasury's SOL balance before the transfer.
+    pub treasury_sol_balance: u64,
+
+    /// The share of the treasury's SOL balance that was sent (bps).
+    pub share_bps: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct AchievementEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The achievement unlocked.
+    pub achievement: u64,
+
+    /// The round the achievement was unlocked in.
+    pub round_id: u64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct MotherlodeEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The round the motherlode hit in.
+    pub round_id: u64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
+
+    /// The amount of ORE paid out.
+    pub amount: u64,
+
+    /// The winning square, whose miners share the motherlode.
+    pub winning_square: u64,
+
+    /// The number of miners on the winning square.
+    pub num_winners: u64,
+
+    /// The amount of SOL deployed on the winning square. Each winner's share is their
+    /// deployment divided by this amount.
+    pub winners_deployed: u64,
+
+    /// The amount of ORE paid per SOL deployed on the winning square.
+    pub ore_per_sol: u64,
+
+    /// The odds of the motherlode hitting when it hit (1 in this many rounds).
+    pub odds: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct EntropyFallbackEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The round that fell back to SlotHashes.
+    pub round_id: u64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
+
+    /// The end slot the entropy var should have sampled.
+    pub end_slot: u64,
+
+    /// The entropy var that was not revealed in time.
+    pub var: Pubkey,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct CheckpointEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The round that was checkpointed.
+    pub round_id: u64,
+
+    /// The board the round was played on.
+    pub board_id: u64,
+
+    /// The amount of SOL credited to the miner.
+    pub rewards_sol: u64,
+
+    /// The amount of ORE credited to the miner, excluding the vesting bonus.
+    pub rewards_ore: u64,
+
+    /// The amount of bonus ORE set to vest.
+    pub bonus_ore: u64,
+
+    /// The total multiplier applied to the ORE rewards (100 = 1.0x).
+    pub multiplier: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct CommitEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The round the commitment was submitted for.
+    pub round_id: u64,
+
+    /// The board the round is played on.
+    pub board_id: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct RevealEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The round the choice was revealed for.
+    pub round_id: u64,
+
+    /// The board the round is played on.
+    pub board_id: u64,
+
+    /// The revealed square.
+    pub square: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct AutomationClosedEvent {
+    /// The event discriminator.
+    pub disc: u64,
+
+    /// The authority of the automation.
+    pub authority: Pubkey,
+
+    /// The board the automation deployed on.
+    pub board_id: u64,
+
+    /// Why the automation was closed (see AutomationCloseReason).
+    pub reason: u64,
+
+    /// The number of rounds the automation deployed in.
+    pub rounds: u64,
+
+    /// The balance left in the automation, including any guild funds returned to the guild.
+    pub balance: u64,
+
+    /// The timestamp of the event.
+    pub ts: i64,
 }
 
 event!(ResetEvent);
 event!(BuryEvent);
 event!(DeployEvent);
 event!(LiqEvent);
+event!(AchievementEvent);
+event!(MotherlodeEvent);
+event!(EntropyFallbackEvent);
+event!(CheckpointEvent);
+event!(CommitEvent);
+event!(RevealEvent);
+event!(AutomationClosedEvent);
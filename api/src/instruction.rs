@@ -1,5 +1,7 @@
 use steel::*;
 
+use crate::consts::*;
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
 pub enum OreInstruction {
@@ -13,6 +15,13 @@ pub enum OreInstruction {
     Log = 8,
     Reset = 9,
     ReloadSOL = 21,
+    SetAutomationLimits = 31,
+    ClaimSeason = 49,
+    OpenAchievements = 61,
+    CheckpointMany = 63,
+    SetClaimRecipient = 64,
+    Sweep = 65,
+    ArmEntropy = 68,
 
     // Staker
     Deposit = 10,
@@ -32,6 +41,44 @@ pub enum OreInstruction {
     MigrateAutomation = 22,
     Liq = 25,
     Initialize = 26,
+    AcceptAdmin = 32,
+    SetBuryAuthority = 33,
+    SetFeeAuthority = 34,
+    SetPauseAuthority = 35,
+    MigrateConfig = 36,
+    SetChangeDelay = 37,
+    ExecuteChange = 38,
+    CancelChange = 39,
+    SetPause = 40,
+    SetBuybackCap = 41,
+    SetLiqManager = 42,
+    UpdateMetadataUri = 43,
+    SetReferralFee = 44,
+    NewBoard = 45,
+    UpdateBoard = 46,
+    SetBoardWeight = 47,
+    NewSeason = 48,
+    SetVestingPeriod = 62,
+    SetMotherlodeOdds = 66,
+    SetMotherlodeRate = 67,
+    SetRngMode = 69,
+    SetBonusSquareCount = 70,
+    SetBonusSquareMultiplier = 71,
+
+    // Guild
+    NewGuild = 50,
+    UpdateGuild = 51,
+    JoinGuild = 52,
+    LeaveGuild = 53,
+    DepositGuild = 54,
+    FundMember = 55,
+    ClaimGuild = 56,
+
+    // Tournament
+    NewTournament = 57,
+    JoinTournament = 58,
+    ScoreTournament = 59,
+    SettleTournament = 60,
 
     // Skill System (v0.2)
     SubmitPrediction = 27,
@@ -39,6 +86,30 @@ pub enum OreInstruction {
     // Commit-Reveal System (v0.6)
     SubmitCommit = 28,
     RevealChoice = 29,
+    SetAutomationReveal = 30,
+}
+
+impl OreInstruction {
+    /// The pause flag that blocks this instruction, or 0 if it cannot be paused.
+    /// Pausable instructions take the config account as their last account.
+    pub fn pause_flag(&self) -> u64 {
+        match self {
+            Self::Deploy => PAUSE_DEPLOY,
+            Self::Reset => PAUSE_RESET,
+            Self::Checkpoint | Self::CheckpointMany => PAUSE_CHECKPOINT,
+            Self::ClaimSOL
+            | Self::ClaimORE
+            | Self::ReloadSOL
+            | Self::ClaimYield
+            | Self::ClaimSeason
+            | Self::Sweep
+            | Self::ClaimGuild
+            | Self::SettleTournament => PAUSE_CLAIM,
+            Self::Deposit => PAUSE_STAKE,
+            Self::Withdraw => PAUSE_WITHDRAW,
+            _ => 0,
+        }
+    }
 }
 
 #[repr(C)]
@@ -50,6 +121,10 @@ pub struct Automate {
     pub mask: [u8; 8],
     pub strategy: u8,
     pub reload: [u8; 8],
+    /// The miner who referred the signer. Only recorded when the miner account is created.
+    pub referrer: [u8; 32],
+    /// The board the executor deploys to.
+    pub board_id: [u8; 8],
 }
 
 #[repr(C)]
@@ -64,7 +139,10 @@ pub struct ClaimORE {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct Deploy {
     pub amount: [u8; 8],
-    pub squares: [u8; 4],
+    /// A bit per square to deploy to, enough for MAX_SQUARES.
+    pub squares: [u8; 8],
+    /// The miner who referred the signer. Only recorded when the miner account is created.
+    pub referrer: [u8; 32],
 }
 
 #[repr(C)]
@@ -120,7 +198,10 @@ pub struct Wrap {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Buyback {}
+pub struct Buyback {
+    /// The minimum amount of ORE the swap must return. Swap data follows these args.
+    pub min_ore_out: [u8; 8],
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -169,6 +250,7 @@ pub struct NewVar {
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct SetAdminFee {
+    pub board_id: [u8; 8],
     pub admin_fee: [u8; 8],
 }
 
@@ -180,13 +262,19 @@ pub struct SetSwapProgram {}
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct SetVarAddress {}
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ArmEntropy {}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct MigrateAutomation {}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
-pub struct Liq {}
+pub struct Liq {
+    pub amount: [u8; 8],
+}
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -194,13 +282,183 @@ pub struct Initialize {
     pub admin: [u8; 32],
     pub fee_collector: [u8; 32],
     pub var_address: [u8; 32],
+    /// Token metadata, zero-padded UTF-8. Only used when the mint is created with Token-2022.
+    pub name: [u8; 32],
+    pub symbol: [u8; 16],
+    pub uri: [u8; 128],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetReferralFee {
+    pub referral_fee: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetVestingPeriod {
+    pub vesting_period: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetMotherlodeOdds {
+    pub motherlode_odds: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetMotherlodeRate {
+    pub motherlode_rate: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetRngMode {
+    pub rng_mode: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBonusSquareCount {
+    pub bonus_square_count: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBonusSquareMultiplier {
+    pub bonus_square_multiplier: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct NewBoard {
+    pub min_deploy: [u8; 8],
+    pub deploy_slots: [u8; 8],
+    pub commit_slots: [u8; 8],
+    pub reveal_slots: [u8; 8],
+    pub admin_fee: [u8; 8],
+    pub weight: [u8; 8],
+    pub squares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateBoard {
+    pub min_deploy: [u8; 8],
+    pub deploy_slots: [u8; 8],
+    pub commit_slots: [u8; 8],
+    pub reveal_slots: [u8; 8],
+    pub squares: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBoardWeight {
+    pub board_id: [u8; 8],
+    pub weight: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct NewSeason {
+    pub board_id: [u8; 8],
+    pub start_round: [u8; 8],
+    pub end_round: [u8; 8],
+    pub prize: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimSeason {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct OpenAchievements {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CheckpointMany {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetClaimRecipient {
+    /// The address to claim rewards to, or the default address to claim to the authority.
+    pub claim_recipient: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Sweep {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct NewGuild {
+    /// The guild name, zero-padded UTF-8.
+    pub name: [u8; 32],
+    pub executor: [u8; 32],
+    pub revenue_share: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateGuild {
+    pub executor: [u8; 32],
+    pub revenue_share: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct JoinGuild {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct LeaveGuild {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct DepositGuild {
+    pub amount: [u8; 8],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct FundMember {
+    pub amount: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ClaimGuild {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct NewTournament {
+    pub board_id: [u8; 8],
+    pub start_round: [u8; 8],
+    pub max_players: [u8; 8],
+    pub entry_fee: [u8; 8],
+    /// SOL the creator adds to the prize pool.
+    pub prize: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct JoinTournament {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ScoreTournament {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SettleTournament {}
+
 /// v0.2: Submit a prediction for the winning square.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct SubmitPrediction {
-    /// The predicted winning square (0-24).
+    /// The predicted winning square.
     pub square: u8,
 }
 
@@ -216,7 +474,7 @@ pub struct SubmitCommit {
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
 pub struct RevealChoice {
-    /// The revealed square (0-24).
+    /// The revealed square.
     pub square: u8,
     /// Padding for alignment.
     pub _padding: [u8; 15],
@@ -224,6 +482,94 @@ pub struct RevealChoice {
     pub salt: [u8; 16],
 }
 
+/// Configure how the automation executor commits and reveals on the authority's behalf.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetAutomationReveal {
+    /// The reveal mode (see AutomationReveal).
+    pub mode: u8,
+    /// The number of pre-committed hashes provided.
+    pub count: u8,
+    /// Pre-committed hashes: keccak256(square || salt || authority).
+    pub commitments: [[u8; 32]; 8],
+}
+
+/// Configure the optional limits of an automation. Zero disables a limit.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetAutomationLimits {
+    pub max_rounds: [u8; 8],
+    pub expires_at: [u8; 8],
+    pub daily_cap: [u8; 8],
+    pub stop_loss: [u8; 8],
+    pub take_profit: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct AcceptAdmin {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBuryAuthority {
+    pub authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetFeeAuthority {
+    pub authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPauseAuthority {
+    pub authority: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct MigrateConfig {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetChangeDelay {
+    pub delay: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct ExecuteChange {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct CancelChange {}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetPause {
+    pub paused: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetBuybackCap {
+    pub buyback_cap: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SetLiqManager {
+    pub liq_manager: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct UpdateMetadataUri {
+    /// The new metadata URI, zero-padded UTF-8.
+    pub uri: [u8; 128],
+}
+
 instruction!(OreInstruction, Automate);
 instruction!(OreInstruction, Close);
 instruction!(OreInstruction, Checkpoint);
@@ -245,9 +591,51 @@ instruction!(OreInstruction, NewVar);
 instruction!(OreInstruction, SetAdminFee);
 instruction!(OreInstruction, SetSwapProgram);
 instruction!(OreInstruction, SetVarAddress);
+instruction!(OreInstruction, ArmEntropy);
 instruction!(OreInstruction, MigrateAutomation);
 instruction!(OreInstruction, Liq);
 instruction!(OreInstruction, Initialize);
+instruction!(OreInstruction, AcceptAdmin);
+instruction!(OreInstruction, SetBuryAuthority);
+instruction!(OreInstruction, SetFeeAuthority);
+instruction!(OreInstruction, SetPauseAuthority);
+instruction!(OreInstruction, MigrateConfig);
+instruction!(OreInstruction, SetChangeDelay);
+instruction!(OreInstruction, ExecuteChange);
+instruction!(OreInstruction, CancelChange);
+instruction!(OreInstruction, SetPause);
+instruction!(OreInstruction, SetBuybackCap);
+instruction!(OreInstruction, SetLiqManager);
+instruction!(OreInstruction, UpdateMetadataUri);
+instruction!(OreInstruction, SetReferralFee);
+instruction!(OreInstruction, SetVestingPeriod);
+instruction!(OreInstruction, SetMotherlodeOdds);
+instruction!(OreInstruction, SetMotherlodeRate);
+instruction!(OreInstruction, SetRngMode);
+instruction!(OreInstruction, SetBonusSquareCount);
+instruction!(OreInstruction, SetBonusSquareMultiplier);
+instruction!(OreInstruction, NewBoard);
+instruction!(OreInstruction, UpdateBoard);
+instruction!(OreInstruction, SetBoardWeight);
+instruction!(OreInstruction, NewSeason);
+instruction!(OreInstruction, ClaimSeason);
+instruction!(OreInstruction, OpenAchievements);
+instruction!(OreInstruction, CheckpointMany);
+instruction!(OreInstruction, SetClaimRecipient);
+instruction!(OreInstruction, Sweep);
+instruction!(OreInstruction, NewGuild);
+instruction!(OreInstruction, UpdateGuild);
+instruction!(OreInstruction, JoinGuild);
+instruction!(OreInstruction, LeaveGuild);
+instruction!(OreInstruction, DepositGuild);
+instruction!(OreInstruction, FundMember);
+instruction!(OreInstruction, ClaimGuild);
+instruction!(OreInstruction, NewTournament);
+instruction!(OreInstruction, JoinTournament);
+instruction!(OreInstruction, ScoreTournament);
+instruction!(OreInstruction, SettleTournament);
 instruction!(OreInstruction, SubmitPrediction);
 instruction!(OreInstruction, SubmitCommit);
 instruction!(OreInstruction, RevealChoice);
+instruction!(OreInstruction, SetAutomationReveal);
+instruction!(OreInstruction, SetAutomationLimits);
@@ -1,9 +1,14 @@
 use solana_program::pubkey::Pubkey;
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
 use steel::*;
 
 use crate::{
-    consts::{BOARD, MINT_ADDRESS, SOL_MINT, TREASURY_ADDRESS},
+    consts::{
+        BOARD, CHECKPOINT_MANY_MAX_ACCOUNTS, CONFIG_ADDRESS, MAX_SQUARES, MINT_ADDRESS, SOL_MINT,
+        TREASURY_ADDRESS,
+    },
     instruction::*,
     state::*,
 };
@@ -22,10 +27,24 @@ pub fn program_log(accounts: &[AccountInfo], msg: &[u8]) -> Result<(), ProgramEr
     invoke_signed(&log(*accounts[0].key, msg), accounts, &crate::ID, &[BOARD])
 }
 
-// let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
+/// Logs a message signed by the given board, the first account.
+pub fn board_log(board_id: u64, accounts: &[AccountInfo], msg: &[u8]) -> Result<(), ProgramError> {
+    invoke_signed(
+        &log(*accounts[0].key, msg),
+        accounts,
+        &crate::ID,
+        &board_seeds(&board_id.to_le_bytes()),
+    )
+}
+
+// let [signer_info, automation_info, executor_info, miner_info, system_program, guild_info?] = accounts else {
 
+/// Sets up, updates or closes (with the default executor) the signer's automation. `guild` must be
+/// the guild that funded the automation when it is closed or its executor changes, if it holds
+/// guild funds.
 pub fn automate(
     signer: Pubkey,
+    board_id: u64,
     amount: u64,
     deposit: u64,
     executor: Pubkey,
@@ -33,18 +52,24 @@ pub fn automate(
     mask: u64,
     strategy: u8,
     reload: bool,
+    referrer: Pubkey,
+    guild: Option<Pubkey>,
 ) -> Instruction {
     let automation_address = automation_pda(signer).0;
     let miner_address = miner_pda(signer).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(automation_address, false),
+        AccountMeta::new(executor, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if let Some(guild) = guild {
+        accounts.push(AccountMeta::new(guild, false));
+    }
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(automation_address, false),
-            AccountMeta::new(executor, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        accounts,
         data: Automate {
             amount: amount.to_le_bytes(),
             deposit: deposit.to_le_bytes(),
@@ -52,12 +77,82 @@ pub fn automate(
             mask: mask.to_le_bytes(),
             strategy: strategy as u8,
             reload: (reload as u64).to_le_bytes(),
+            referrer: referrer.to_bytes(),
+            board_id: board_id.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-pub fn claim_sol(signer: Pubkey) -> Instruction {
+// let [signer_info, miner_info, system_program, recipient_info?] =
+
+/// Claims SOL rewards. `claim_recipient` must be the miner's claim recipient, if it has one.
+pub fn claim_sol(signer: Pubkey, claim_recipient: Option<Pubkey>) -> Instruction {
+    let miner_address = miner_pda(signer).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if let Some(claim_recipient) = claim_recipient {
+        accounts.push(AccountMeta::new(claim_recipient, false));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ClaimSOL {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, config_info, referrer_miner_info, claim_recipient_info?] =
+
+/// Claims ORE rewards. `referrer` must be the miner's referrer, or the default address if none.
+/// `claim_recipient` must be the miner's claim recipient, if it has one.
+pub fn claim_ore(
+    signer: Pubkey,
+    token_program: Pubkey,
+    referrer: Pubkey,
+    claim_recipient: Option<Pubkey>,
+) -> Instruction {
+    let miner_address = miner_pda(signer).0;
+    let referrer_miner_address = miner_pda(referrer).0;
+    let treasury_address = treasury_pda().0;
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
+    let recipient_address = get_associated_token_address_with_program_id(
+        &claim_recipient.unwrap_or(signer),
+        &MINT_ADDRESS,
+        &token_program,
+    );
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(MINT_ADDRESS, false),
+        AccountMeta::new(recipient_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+        AccountMeta::new(referrer_miner_address, false),
+    ];
+    if let Some(claim_recipient) = claim_recipient {
+        accounts.push(AccountMeta::new_readonly(claim_recipient, false));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ClaimORE {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, system_program] = accounts
+
+/// Sets the address the signer's rewards are claimed to. The default address claims to the
+/// signer.
+pub fn set_claim_recipient(signer: Pubkey, claim_recipient: Pubkey) -> Instruction {
     let miner_address = miner_pda(signer).0;
     Instruction {
         program_id: crate::ID,
@@ -66,72 +161,102 @@ pub fn claim_sol(signer: Pubkey) -> Instruction {
             AccountMeta::new(miner_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
-        data: ClaimSOL {}.to_bytes(),
+        data: SetClaimRecipient {
+            claim_recipient: claim_recipient.to_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
-// let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+// let [signer_info, miner_info, claim_recipient_info, recipient_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, config_info, referrer_miner_info] =
 
-pub fn claim_ore(signer: Pubkey) -> Instruction {
-    let miner_address = miner_pda(signer).0;
-    let treasury_address = treasury_pda().0;
-    let treasury_tokens_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+/// Sweeps a miner's SOL and ORE rewards to its claim recipient. `referrer` must be the miner's
+/// referrer, or the default address if none.
+pub fn sweep(
+    signer: Pubkey,
+    authority: Pubkey,
+    claim_recipient: Pubkey,
+    token_program: Pubkey,
+    referrer: Pubkey,
+) -> Instruction {
+    let miner_address = miner_pda(authority).0;
+    let referrer_miner_address = miner_pda(referrer).0;
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
+    let recipient_address = get_associated_token_address_with_program_id(
+        &claim_recipient,
+        &MINT_ADDRESS,
+        &token_program,
+    );
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(miner_address, false),
-            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(claim_recipient, false),
             AccountMeta::new(recipient_address, false),
-            AccountMeta::new(treasury_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
             AccountMeta::new(treasury_tokens_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+            AccountMeta::new(referrer_miner_address, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
         ],
-        data: ClaimORE {}.to_bytes(),
+        data: Sweep {}.to_bytes(),
     }
 }
 
-// let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program] =
+// let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program, guild_info?] =
 
-/// Schelling Point: Deploy SOL to vote for squares (no entropy needed)
+/// Schelling Point: Deploy SOL to vote for squares (no entropy needed). `guild` must be the guild
+/// that funded the authority's automation, if it holds guild funds, so they can be returned to it
+/// if the automation closes.
 pub fn deploy(
     signer: Pubkey,
     authority: Pubkey,
+    board_id: u64,
     amount: u64,
     round_id: u64,
-    squares: [bool; 25],
+    squares: [bool; MAX_SQUARES],
+    referrer: Pubkey,
+    guild: Option<Pubkey>,
 ) -> Instruction {
     let automation_address = automation_pda(authority).0;
-    let board_address = board_pda().0;
+    let board_address = board_pda(board_id).0;
     let miner_address = miner_pda(authority).0;
-    let round_address = round_pda(round_id).0;
+    let round_address = round_pda(board_id, round_id).0;
 
-    // Convert array of 25 booleans into a 32-bit mask where each bit represents whether
+    // Convert array of square booleans into a 64-bit mask where each bit represents whether
     // that square index is selected (1) or not (0)
-    let mut mask: u32 = 0;
+    let mut mask: u64 = 0;
     for (i, &square) in squares.iter().enumerate() {
         if square {
             mask |= 1 << i;
         }
     }
 
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new(automation_address, false),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(round_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if let Some(guild) = guild {
+        accounts.push(AccountMeta::new(guild, false));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(authority, false),
-            AccountMeta::new(automation_address, false),
-            AccountMeta::new(board_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new(round_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        accounts,
         data: Deploy {
             amount: amount.to_le_bytes(),
             squares: mask.to_le_bytes(),
+            referrer: referrer.to_bytes(),
         }
         .to_bytes(),
     }
@@ -147,18 +272,25 @@ pub fn deploy(
 ///
 /// # Arguments
 /// * `signer` - The player's wallet
+/// * `board_id` - The board to play on
 /// * `amount` - Amount of lamports to deploy per square
 /// * `squares` - Which squares to deploy to (bitmask as bool array)
 /// * `fee_collector` - Address to receive admin fees (from Config)
 /// * `current_round_id` - Current round from Board
 /// * `round_ended` - Whether current round has ended (slot >= end_slot + intermission)
+/// * `referrer` - The player's referrer, recorded if this is their first deploy
+/// * `guild` - The player's guild, if they are a member of one
 pub fn play(
     signer: Pubkey,
+    board_id: u64,
     amount: u64,
-    squares: [bool; 25],
+    squares: [bool; MAX_SQUARES],
     fee_collector: Pubkey,
+    token_program: Pubkey,
     current_round_id: u64,
     round_ended: bool,
+    referrer: Pubkey,
+    guild: Option<Pubkey>,
 ) -> Vec<Instruction> {
     let mut instructions = vec![];
 
@@ -166,14 +298,24 @@ pub fn play(
         // Prepend reset instruction to finalize current round and create next
         instructions.push(reset(
             signer,
+            board_id,
             fee_collector,
+            token_program,
             current_round_id,
             Pubkey::default(), // top_miner placeholder
+            None,
         ));
 
         // Checkpoint the previous round to claim rewards before deploying
         // This is required - miners must checkpoint before joining new round
-        instructions.push(checkpoint(signer, signer, current_round_id));
+        instructions.push(checkpoint(
+            signer,
+            signer,
+            board_id,
+            current_round_id,
+            guild,
+            None,
+        ));
     }
 
     // Deploy to the appropriate round
@@ -183,15 +325,24 @@ pub fn play(
         current_round_id
     };
 
-    instructions.push(deploy(signer, signer, amount, target_round, squares));
+    instructions.push(deploy(
+        signer,
+        signer,
+        board_id,
+        amount,
+        target_round,
+        squares,
+        referrer,
+        None,
+    ));
 
     instructions
 }
 
 /// Helper to create a bitmask for a single square
-pub fn single_square(index: u8) -> [bool; 25] {
-    let mut squares = [false; 25];
-    if index < 25 {
+pub fn single_square(index: u8) -> [bool; MAX_SQUARES] {
+    let mut squares = [false; MAX_SQUARES];
+    if (index as usize) < MAX_SQUARES {
         squares[index as usize] = true;
     }
     squares
@@ -199,12 +350,18 @@ pub fn single_square(index: u8) -> [bool; 25] {
 
 // let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user_key, vault_program, token_program] =
 
-pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8]) -> Instruction {
-    let board_address = board_pda().0;
+pub fn buyback(
+    signer: Pubkey,
+    token_program: Pubkey,
+    min_ore_out: u64,
+    swap_accounts: &[AccountMeta],
+    swap_data: &[u8],
+) -> Instruction {
+    let board_address = board_pda(0).0;
     let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
     let treasury_address = TREASURY_ADDRESS;
-    let treasury_ore_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
+    let treasury_ore_address = treasury_tokens_address(&token_program);
     let treasury_sol_address = get_associated_token_address(&treasury_address, &SOL_MINT);
     let mut accounts = vec![
         AccountMeta::new(signer, true),
@@ -214,6 +371,7 @@ pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8])
         AccountMeta::new(treasury_address, false),
         AccountMeta::new(treasury_ore_address, false),
         AccountMeta::new(treasury_sol_address, false),
+        AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(spl_token::ID, false),
         AccountMeta::new_readonly(crate::ID, false),
     ];
@@ -222,7 +380,10 @@ pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8])
         acc_clone.is_signer = false;
         accounts.push(acc_clone);
     }
-    let mut data = Buyback {}.to_bytes();
+    let mut data = Buyback {
+        min_ore_out: min_ore_out.to_le_bytes(),
+    }
+    .to_bytes();
     data.extend_from_slice(swap_data);
     Instruction {
         program_id: crate::ID,
@@ -233,13 +394,13 @@ pub fn buyback(signer: Pubkey, swap_accounts: &[AccountMeta], swap_data: &[u8])
 
 // let [signer_info, sender_info, board_info, mint_info, treasury_info, treasury_ore_info, token_program, ore_program] =
 
-pub fn bury(signer: Pubkey, amount: u64) -> Instruction {
-    let board_address = board_pda().0;
-    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+pub fn bury(signer: Pubkey, token_program: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(0).0;
+    let sender_address =
+        get_associated_token_address_with_program_id(&signer, &MINT_ADDRESS, &token_program);
     let mint_address = MINT_ADDRESS;
     let treasury_address = TREASURY_ADDRESS;
-    let treasury_ore_address = get_associated_token_address(&treasury_address, &MINT_ADDRESS);
-    let token_program = spl_token::ID;
+    let treasury_ore_address = treasury_tokens_address(&token_program);
     let ore_program = crate::ID;
     Instruction {
         program_id: crate::ID,
@@ -262,8 +423,8 @@ pub fn bury(signer: Pubkey, amount: u64) -> Instruction {
 
 // let [signer_info, board_info, config_info, manager_info, manager_sol_info, treasury_info, treasury_sol_info, token_program, ore_program] =
 
-pub fn liq(signer: Pubkey, manager: Pubkey) -> Instruction {
-    let board_address = board_pda().0;
+pub fn liq(signer: Pubkey, manager: Pubkey, amount: u64) -> Instruction {
+    let board_address = board_pda(0).0;
     let config_address = config_pda().0;
     let manager_sol_address = get_associated_token_address(&manager, &SOL_MINT);
     let treasury_address = TREASURY_ADDRESS;
@@ -283,7 +444,10 @@ pub fn liq(signer: Pubkey, manager: Pubkey) -> Instruction {
             AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(ore_program, false),
         ],
-        data: Liq {}.to_bytes(),
+        data: Liq {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
@@ -304,51 +468,84 @@ pub fn wrap(signer: Pubkey) -> Instruction {
     }
 }
 
-// let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+// let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar, entropy_var_info?] =
 
-/// Schelling Point: Reset determines winner by majority vote (no entropy needed)
+/// Schelling Point: Reset determines winner by majority vote. In entropy RNG mode, `entropy` must
+/// be set to pass the board's var, which the round draws its randomness from.
 pub fn reset(
     signer: Pubkey,
+    board_id: u64,
     fee_collector: Pubkey,
+    token_program: Pubkey,
     round_id: u64,
     top_miner: Pubkey,
+    entropy: bool,
 ) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(board_id).0;
     let config_address = config_pda().0;
     let mint_address = MINT_ADDRESS;
-    let round_address = round_pda(round_id).0;
-    let round_next_address = round_pda(round_id + 1).0;
+    let round_address = round_pda(board_id, round_id).0;
+    let round_next_address = round_pda(board_id, round_id + 1).0;
     let top_miner_address = miner_pda(top_miner).0;
     let treasury_address = TREASURY_ADDRESS;
-    let treasury_tokens_address = treasury_tokens_address();
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(fee_collector, false),
+        AccountMeta::new(mint_address, false),
+        AccountMeta::new(round_address, false),
+        AccountMeta::new(round_next_address, false),
+        AccountMeta::new(top_miner_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new(treasury_tokens_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+    ];
+    if entropy {
+        accounts.push(AccountMeta::new_readonly(
+            board_var_pda(board_address).0,
+            false,
+        ));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: Reset {}.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, config_info, var_info, entropy_program] = accounts
+
+/// Arms the board's entropy var to sample the slot hash at the end of the board's current round.
+/// Permissionless; the round must have started.
+pub fn arm_entropy(signer: Pubkey, board_id: u64) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let var = board_var_pda(board_address).0;
+    let config_address = config_pda().0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(board_address, false),
-            AccountMeta::new(config_address, false),
-            AccountMeta::new(fee_collector, false),
-            AccountMeta::new(mint_address, false),
-            AccountMeta::new(round_address, false),
-            AccountMeta::new(round_next_address, false),
-            AccountMeta::new(top_miner_address, false),
-            AccountMeta::new(treasury_address, false),
-            AccountMeta::new(treasury_tokens_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-            AccountMeta::new_readonly(crate::ID, false),
-            AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(var, false),
+            AccountMeta::new_readonly(entropy_api::ID, false),
         ],
-        data: Reset {}.to_bytes(),
+        data: ArmEntropy {}.to_bytes(),
     }
 }
 
 // let [signer_info, board_info, rent_payer_info, round_info, treasury_info, system_program] =
 
-pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
-    let board_address = board_pda().0;
+pub fn close(signer: Pubkey, board_id: u64, round_id: u64, rent_payer: Pubkey) -> Instruction {
+    let board_address = board_pda(board_id).0;
     let treasury_address = TREASURY_ADDRESS;
-    let round_address = round_pda(round_id).0;
+    let round_address = round_pda(board_id, round_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -363,27 +560,143 @@ pub fn close(signer: Pubkey, round_id: u64, rent_payer: Pubkey) -> Instruction {
     }
 }
 
-// let [signer_info, automation_info, board_info, miner_info, round_info, treasury_info, system_program] =
+// let [signer_info, board_info, miner_info, round_info, treasury_info, system_program, config_info, guild_info?, achievements_info, ore_program, season_info?, season_miner_info?] =
 
-pub fn checkpoint(signer: Pubkey, authority: Pubkey, round_id: u64) -> Instruction {
+/// Checkpoints a miner's rewards for a round. `guild` must be the miner's guild if they are a
+/// member of one. Achievements are recorded if the miner has opened an achievements account. If
+/// the round is part of a season, `season_id` must be given and the miner's season points are
+/// recorded too, in a season account the signer pays for if the miner has none yet.
+pub fn checkpoint(
+    signer: Pubkey,
+    authority: Pubkey,
+    board_id: u64,
+    round_id: u64,
+    guild: Option<Pubkey>,
+    season_id: Option<u64>,
+) -> Instruction {
     let miner_address = miner_pda(authority).0;
-    let board_address = board_pda().0;
-    let round_address = round_pda(round_id).0;
+    let board_address = board_pda(board_id).0;
+    let round_address = round_pda(board_id, round_id).0;
     let treasury_address = TREASURY_ADDRESS;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new(round_address, false),
+        AccountMeta::new(treasury_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+    ];
+    if let Some(guild) = guild {
+        accounts.push(AccountMeta::new(guild, false));
+    }
+    accounts.push(AccountMeta::new(achievements_pda(authority).0, false));
+    accounts.push(AccountMeta::new_readonly(crate::ID, false));
+    if let Some(season_id) = season_id {
+        accounts.push(AccountMeta::new(season_pda(season_id).0, false));
+        accounts.push(AccountMeta::new(
+            season_miner_pda(season_id, authority).0,
+            false,
+        ));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
     Instruction {
         program_id: crate::ID,
-        accounts: vec![
-            AccountMeta::new(signer, true),
-            AccountMeta::new(board_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new(round_address, false),
-            AccountMeta::new(treasury_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
-        ],
+        accounts,
         data: Checkpoint {}.to_bytes(),
     }
 }
 
+/// A miner to checkpoint in a batch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CheckpointMiner {
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The miner's guild, if they are a member of one.
+    pub guild: Option<Pubkey>,
+}
+
+impl CheckpointMiner {
+    /// The number of accounts the miner adds to a checkpoint_many instruction, given whether
+    /// the instruction records season points.
+    pub fn account_count(&self, season: bool) -> usize {
+        2 + self.guild.is_some() as usize + season as usize
+    }
+}
+
+/// Splits miners into batches that fit in a checkpoint_many transaction, adding at most
+/// CHECKPOINT_MANY_MAX_ACCOUNTS accounts each. `season` is whether the batches record season
+/// points.
+pub fn checkpoint_many_batches(
+    miners: &[CheckpointMiner],
+    season: bool,
+) -> Vec<&[CheckpointMiner]> {
+    let mut batches = vec![];
+    let mut start = 0;
+    let mut accounts = 0;
+    for (i, miner) in miners.iter().enumerate() {
+        if accounts + miner.account_count(season) > CHECKPOINT_MANY_MAX_ACCOUNTS {
+            batches.push(&miners[start..i]);
+            start = i;
+            accounts = 0;
+        }
+        accounts += miner.account_count(season);
+    }
+    if start < miners.len() {
+        batches.push(&miners[start..]);
+    }
+    batches
+}
+
+// let [signer_info, board_info, round_info, treasury_info, system_program, config_info, ore_program, season_info?, (miner_info, guild_info?, achievements_info, season_miner_info?)..] =
+
+/// Checkpoints many miners of one round. The round must be each miner's last round. If the
+/// round is part of a season, `season_id` must be given and season points are recorded too, in
+/// season accounts the signer pays for if a miner has none yet.
+pub fn checkpoint_many(
+    signer: Pubkey,
+    board_id: u64,
+    round_id: u64,
+    miners: &[CheckpointMiner],
+    season_id: Option<u64>,
+) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let round_address = round_pda(board_id, round_id).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(board_address, false),
+        AccountMeta::new(round_address, false),
+        AccountMeta::new(TREASURY_ADDRESS, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    if let Some(season_id) = season_id {
+        accounts.push(AccountMeta::new(season_pda(season_id).0, false));
+    }
+    for miner in miners {
+        accounts.push(AccountMeta::new(miner_pda(miner.authority).0, false));
+        if let Some(guild) = miner.guild {
+            accounts.push(AccountMeta::new(guild, false));
+        }
+        accounts.push(AccountMeta::new(achievements_pda(miner.authority).0, false));
+        if let Some(season_id) = season_id {
+            accounts.push(AccountMeta::new(
+                season_miner_pda(season_id, miner.authority).0,
+                false,
+            ));
+        }
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: CheckpointMany {}.to_bytes(),
+    }
+}
+
+/// Proposes a new admin. The transfer completes when the new admin calls accept_admin.
 pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
     let config_address = config_pda().0;
     Instruction {
@@ -400,29 +713,121 @@ pub fn set_admin(signer: Pubkey, admin: Pubkey) -> Instruction {
     }
 }
 
-pub fn set_admin_fee(signer: Pubkey, admin_fee: u64) -> Instruction {
+// let [signer_info, config_info] = accounts
+
+pub fn accept_admin(signer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: AcceptAdmin {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info] = accounts
+
+pub fn set_bury_authority(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetBuryAuthority {
+            authority: authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info] = accounts
+
+pub fn set_fee_authority(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetFeeAuthority {
+            authority: authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info] = accounts
+
+pub fn set_pause_authority(signer: Pubkey, authority: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetPauseAuthority {
+            authority: authority.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, system_program] = accounts
+
+pub fn migrate_config(signer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: MigrateConfig {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a change of a board's admin fee. `change_id` must be the config's current
+/// change_count.
+pub fn set_admin_fee(signer: Pubkey, board_id: u64, admin_fee: u64, change_id: u64) -> Instruction {
     let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: SetAdminFee {
+            board_id: board_id.to_le_bytes(),
             admin_fee: admin_fee.to_le_bytes(),
         }
         .to_bytes(),
     }
 }
 
-pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a fee collector change. `change_id` must be the config's current change_count.
+pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey, change_id: u64) -> Instruction {
     let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: SetFeeCollector {
@@ -434,11 +839,13 @@ pub fn set_fee_collector(signer: Pubkey, fee_collector: Pubkey) -> Instruction {
 
 // let [signer_info, mint_info, sender_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
 
-pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64) -> Instruction {
+pub fn deposit(signer: Pubkey, payer: Pubkey, token_program: Pubkey, amount: u64) -> Instruction {
     let mint_address = MINT_ADDRESS;
     let stake_address = stake_pda(signer).0;
-    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
-    let sender_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let stake_tokens_address =
+        get_associated_token_address_with_program_id(&stake_address, &MINT_ADDRESS, &token_program);
+    let sender_address =
+        get_associated_token_address_with_program_id(&signer, &MINT_ADDRESS, &token_program);
     let treasury_address = TREASURY_ADDRESS;
     Instruction {
         program_id: crate::ID,
@@ -451,8 +858,9 @@ pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new(stake_tokens_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
         ],
         data: Deposit {
             amount: amount.to_le_bytes(),
@@ -463,11 +871,13 @@ pub fn deposit(signer: Pubkey, payer: Pubkey, amount: u64) -> Instruction {
 
 // let [signer_info, mint_info, recipient_info, stake_info, stake_tokens_info, treasury_info, system_program, token_program, associated_token_program] =
 
-pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
+pub fn withdraw(signer: Pubkey, token_program: Pubkey, amount: u64) -> Instruction {
     let stake_address = stake_pda(signer).0;
-    let stake_tokens_address = get_associated_token_address(&stake_address, &MINT_ADDRESS);
+    let stake_tokens_address =
+        get_associated_token_address_with_program_id(&stake_address, &MINT_ADDRESS, &token_program);
     let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let recipient_address =
+        get_associated_token_address_with_program_id(&signer, &MINT_ADDRESS, &token_program);
     let treasury_address = TREASURY_ADDRESS;
     Instruction {
         program_id: crate::ID,
@@ -479,8 +889,9 @@ pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new(stake_tokens_address, false),
             AccountMeta::new(treasury_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
         ],
         data: Withdraw {
             amount: amount.to_le_bytes(),
@@ -489,31 +900,70 @@ pub fn withdraw(signer: Pubkey, amount: u64) -> Instruction {
     }
 }
 
-// let [signer_info, automation_info, miner_info, system_program] = accounts else {
+// let [signer_info, authority_info, automation_info, miner_info, system_program, guild_info?] = accounts else {
 
-pub fn reload_sol(signer: Pubkey, authority: Pubkey) -> Instruction {
+/// Reloads the authority's automation with its SOL rewards. `guild` must be the guild that funded
+/// the automation, if it holds guild funds.
+pub fn reload_sol(signer: Pubkey, authority: Pubkey, guild: Option<Pubkey>) -> Instruction {
     let automation_address = automation_pda(authority).0;
     let miner_address = miner_pda(authority).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(authority, false),
+        AccountMeta::new(automation_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if let Some(guild) = guild {
+        accounts.push(AccountMeta::new(guild, false));
+    }
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ReloadSOL {}.to_bytes(),
+    }
+}
+
+// let [signer_info, automation_info, miner_info] = accounts
+
+pub fn set_automation_limits(
+    signer: Pubkey,
+    max_rounds: u64,
+    expires_at: u64,
+    daily_cap: u64,
+    stop_loss: u64,
+    take_profit: u64,
+) -> Instruction {
+    let automation_address = automation_pda(signer).0;
+    let miner_address = miner_pda(signer).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(automation_address, false),
-            AccountMeta::new(miner_address, false),
-            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(miner_address, false),
         ],
-        data: ReloadSOL {}.to_bytes(),
+        data: SetAutomationLimits {
+            max_rounds: max_rounds.to_le_bytes(),
+            expires_at: expires_at.to_le_bytes(),
+            daily_cap: daily_cap.to_le_bytes(),
+            stop_loss: stop_loss.to_le_bytes(),
+            take_profit: take_profit.to_le_bytes(),
+        }
+        .to_bytes(),
     }
 }
 
 // let [signer_info, mint_info, recipient_info, stake_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
 
-pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
+pub fn claim_yield(signer: Pubkey, token_program: Pubkey, amount: u64) -> Instruction {
     let stake_address = stake_pda(signer).0;
     let mint_address = MINT_ADDRESS;
-    let recipient_address = get_associated_token_address(&signer, &MINT_ADDRESS);
+    let recipient_address =
+        get_associated_token_address_with_program_id(&signer, &MINT_ADDRESS, &token_program);
     let treasury_address = TREASURY_ADDRESS;
-    let treasury_tokens_address = treasury_tokens_address();
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -524,8 +974,9 @@ pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
         ],
         data: ClaimYield {
             amount: amount.to_le_bytes(),
@@ -534,14 +985,16 @@ pub fn claim_yield(signer: Pubkey, amount: u64) -> Instruction {
     }
 }
 
+/// Opens an entropy var for a board. The var with id 0 is the board's RNG var (see board_var_pda).
 pub fn new_var(
     signer: Pubkey,
+    board_id: u64,
     provider: Pubkey,
     id: u64,
     commit: [u8; 32],
     samples: u64,
 ) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(board_id).0;
     let config_address = config_pda().0;
     let var_address = entropy_api::state::var_pda(board_address, id).0;
     Instruction {
@@ -564,22 +1017,32 @@ pub fn new_var(
     }
 }
 
-pub fn set_swap_program(signer: Pubkey, new_program: Pubkey) -> Instruction {
+// let [signer_info, config_info, new_swap_program_info, change_info, system_program] = accounts
+
+/// Schedules a swap program change. `change_id` must be the config's current change_count.
+pub fn set_swap_program(signer: Pubkey, new_program: Pubkey, change_id: u64) -> Instruction {
     let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(signer, true),
             AccountMeta::new(config_address, false),
             AccountMeta::new_readonly(new_program, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: SetSwapProgram {}.to_bytes(),
     }
 }
 
-pub fn set_var_address(signer: Pubkey, new_var_address: Pubkey) -> Instruction {
-    let board_address = board_pda().0;
+// let [signer_info, board_info, config_info, new_var_address_info, change_info, system_program] = accounts
+
+/// Schedules a var address change. `change_id` must be the config's current change_count.
+pub fn set_var_address(signer: Pubkey, new_var_address: Pubkey, change_id: u64) -> Instruction {
+    let board_address = board_pda(0).0;
     let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -587,11 +1050,720 @@ pub fn set_var_address(signer: Pubkey, new_var_address: Pubkey) -> Instruction {
             AccountMeta::new(board_address, false),
             AccountMeta::new(config_address, false),
             AccountMeta::new(new_var_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: SetVarAddress {}.to_bytes(),
     }
 }
 
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a change delay change. `change_id` must be the config's current change_count.
+pub fn set_change_delay(signer: Pubkey, delay: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetChangeDelay {
+            delay: delay.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a buyback cap change. `change_id` must be the config's current change_count.
+pub fn set_buyback_cap(signer: Pubkey, buyback_cap: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetBuybackCap {
+            buyback_cap: buyback_cap.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a liq manager change. `change_id` must be the config's current change_count.
+pub fn set_liq_manager(signer: Pubkey, liq_manager: Pubkey, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetLiqManager {
+            liq_manager: liq_manager.to_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a referral fee change. `change_id` must be the config's current change_count.
+pub fn set_referral_fee(signer: Pubkey, referral_fee: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetReferralFee {
+            referral_fee: referral_fee.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a vesting period change. `change_id` must be the config's current change_count.
+pub fn set_vesting_period(signer: Pubkey, vesting_period: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetVestingPeriod {
+            vesting_period: vesting_period.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a motherlode odds change. `change_id` must be the config's current change_count.
+pub fn set_motherlode_odds(signer: Pubkey, motherlode_odds: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetMotherlodeOdds {
+            motherlode_odds: motherlode_odds.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a motherlode rate change. `change_id` must be the config's current change_count.
+pub fn set_motherlode_rate(signer: Pubkey, motherlode_rate: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetMotherlodeRate {
+            motherlode_rate: motherlode_rate.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules an RNG mode change (RNG_MODE_SLOT_HASHES or RNG_MODE_ENTROPY). `change_id` must be
+/// the config's current change_count.
+pub fn set_rng_mode(signer: Pubkey, rng_mode: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetRngMode {
+            rng_mode: rng_mode.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a change of the number of bonus squares per round. `change_id` must be the config's
+/// current change_count.
+pub fn set_bonus_square_count(
+    signer: Pubkey,
+    bonus_square_count: u64,
+    change_id: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetBonusSquareCount {
+            bonus_square_count: bonus_square_count.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a change of the bonus square multiplier (percent). `change_id` must be the config's
+/// current change_count.
+pub fn set_bonus_square_multiplier(
+    signer: Pubkey,
+    bonus_square_multiplier: u64,
+    change_id: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetBonusSquareMultiplier {
+            bonus_square_multiplier: bonus_square_multiplier.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, system_program] = accounts
+
+/// Creates a board. `board_id` must be the config's current board_count.
+pub fn new_board(
+    signer: Pubkey,
+    board_id: u64,
+    min_deploy: u64,
+    deploy_slots: u64,
+    commit_slots: u64,
+    reveal_slots: u64,
+    admin_fee: u64,
+    weight: u64,
+    squares: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let board_address = board_pda(board_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: NewBoard {
+            min_deploy: min_deploy.to_le_bytes(),
+            deploy_slots: deploy_slots.to_le_bytes(),
+            commit_slots: commit_slots.to_le_bytes(),
+            reveal_slots: reveal_slots.to_le_bytes(),
+            admin_fee: admin_fee.to_le_bytes(),
+            weight: weight.to_le_bytes(),
+            squares: squares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, system_program, round_info?] = accounts
+
+/// Updates a board's parameters. Changes to timing take effect from the board's next round.
+/// `round_id` is the board's current round, whose bonus squares are cleared if the geometry
+/// changes.
+pub fn update_board(
+    signer: Pubkey,
+    board_id: u64,
+    round_id: u64,
+    min_deploy: u64,
+    deploy_slots: u64,
+    commit_slots: u64,
+    reveal_slots: u64,
+    squares: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let board_address = board_pda(board_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(board_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(round_pda(board_id, round_id).0, false),
+        ],
+        data: UpdateBoard {
+            min_deploy: min_deploy.to_le_bytes(),
+            deploy_slots: deploy_slots.to_le_bytes(),
+            commit_slots: commit_slots.to_le_bytes(),
+            reveal_slots: reveal_slots.to_le_bytes(),
+            squares: squares.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, season_info, treasury_info, system_program] = accounts
+
+/// Creates a season over rounds `start_round..=end_round` of a board, funding its prize pool
+/// with `prize` lamports from the treasury. `season_id` must be the config's current season_count.
+pub fn new_season(
+    signer: Pubkey,
+    season_id: u64,
+    board_id: u64,
+    start_round: u64,
+    end_round: u64,
+    prize: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let season_address = season_pda(season_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(board_pda(board_id).0, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: NewSeason {
+            board_id: board_id.to_le_bytes(),
+            start_round: start_round.to_le_bytes(),
+            end_round: end_round.to_le_bytes(),
+            prize: prize.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, round_info, season_info] = accounts
+
+/// Claims the signer's share of a finished season's prize pool.
+pub fn claim_season(signer: Pubkey, season_id: u64, board_id: u64, end_round: u64) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let round_address = round_pda(board_id, end_round).0;
+    let season_address = season_pda(season_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new_readonly(round_address, false),
+            AccountMeta::new(season_address, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+        ],
+        data: ClaimSeason {}.to_bytes(),
+    }
+}
+
+// let [signer_info, achievements_info, system_program] = accounts
+
+pub fn open_achievements(signer: Pubkey) -> Instruction {
+    let achievements_address = achievements_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(achievements_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OpenAchievements {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, guild_info, system_program] = accounts
+
+/// Creates a guild managed by the signer. `guild_id` must be the config's current guild_count.
+pub fn new_guild(
+    signer: Pubkey,
+    guild_id: u64,
+    name: [u8; 32],
+    executor: Pubkey,
+    revenue_share: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let guild_address = guild_pda(guild_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(guild_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: NewGuild {
+            name,
+            executor: executor.to_bytes(),
+            revenue_share: revenue_share.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, guild_info] = accounts
+
+pub fn update_guild(
+    signer: Pubkey,
+    guild_id: u64,
+    executor: Pubkey,
+    revenue_share: u64,
+) -> Instruction {
+    let guild_address = guild_pda(guild_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(guild_address, false),
+        ],
+        data: UpdateGuild {
+            executor: executor.to_bytes(),
+            revenue_share: revenue_share.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, guild_info] = accounts
+
+pub fn join_guild(signer: Pubkey, guild_id: u64) -> Instruction {
+    let miner_address = miner_pda(signer).0;
+    let guild_address = guild_pda(guild_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(guild_address, false),
+        ],
+        data: JoinGuild {}.to_bytes(),
+    }
+}
+
+// let [signer_info, miner_info, guild_info, automation_info] = accounts
+
+pub fn leave_guild(signer: Pubkey, guild: Pubkey) -> Instruction {
+    let miner_address = miner_pda(signer).0;
+    let automation_address = automation_pda(signer).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new(guild, false),
+            AccountMeta::new(automation_address, false),
+        ],
+        data: LeaveGuild {}.to_bytes(),
+    }
+}
+
+// let [signer_info, guild_info, system_program] = accounts
+
+pub fn deposit_guild(signer: Pubkey, guild_id: u64, amount: u64) -> Instruction {
+    let guild_address = guild_pda(guild_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(guild_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: DepositGuild {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, guild_info, miner_info, automation_info] = accounts
+
+/// Moves SOL from a guild's treasury into a member's automation.
+pub fn fund_member(signer: Pubkey, guild_id: u64, member: Pubkey, amount: u64) -> Instruction {
+    let guild_address = guild_pda(guild_id).0;
+    let miner_address = miner_pda(member).0;
+    let automation_address = automation_pda(member).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(guild_address, false),
+            AccountMeta::new_readonly(miner_address, false),
+            AccountMeta::new(automation_address, false),
+        ],
+        data: FundMember {
+            amount: amount.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, guild_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+
+/// Claims a guild's ORE rewards to the signer, who must be the guild authority.
+pub fn claim_guild(signer: Pubkey, guild_id: u64, token_program: Pubkey) -> Instruction {
+    let guild_address = guild_pda(guild_id).0;
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
+    let recipient_address =
+        get_associated_token_address_with_program_id(&signer, &MINT_ADDRESS, &token_program);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(guild_address, false),
+            AccountMeta::new(MINT_ADDRESS, false),
+            AccountMeta::new(recipient_address, false),
+            AccountMeta::new(TREASURY_ADDRESS, false),
+            AccountMeta::new(treasury_tokens_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(CONFIG_ADDRESS, false),
+        ],
+        data: ClaimGuild {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, board_info, tournament_info, system_program] = accounts
+
+/// Creates a round-robin tournament for `max_players` on a board, starting at `start_round`.
+/// `tournament_id` must be the config's current tournament_count.
+pub fn new_tournament(
+    signer: Pubkey,
+    tournament_id: u64,
+    board_id: u64,
+    start_round: u64,
+    max_players: u64,
+    entry_fee: u64,
+    prize: u64,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let board_address = board_pda(board_id).0;
+    let tournament_address = tournament_pda(tournament_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(tournament_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: NewTournament {
+            board_id: board_id.to_le_bytes(),
+            start_round: start_round.to_le_bytes(),
+            max_players: max_players.to_le_bytes(),
+            entry_fee: entry_fee.to_le_bytes(),
+            prize: prize.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, tournament_info, system_program] = accounts
+
+pub fn join_tournament(signer: Pubkey, tournament_id: u64, board_id: u64) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let tournament_address = tournament_pda(tournament_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(tournament_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: JoinTournament {}.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, tournament_info, round_info] = accounts
+
+/// Scores a tournament's next matchday, played in round `round_id` of the board.
+pub fn score_tournament(
+    signer: Pubkey,
+    tournament_id: u64,
+    board_id: u64,
+    round_id: u64,
+) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let tournament_address = tournament_pda(tournament_id).0;
+    let round_address = round_pda(board_id, round_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(tournament_address, false),
+            AccountMeta::new_readonly(round_address, false),
+        ],
+        data: ScoreTournament {}.to_bytes(),
+    }
+}
+
+// let [signer_info, board_info, tournament_info, authority_info, player_infos @ ..] = accounts
+
+/// Pays out a tournament. `players` must be the tournament's roster, in order of entry.
+pub fn settle_tournament(
+    signer: Pubkey,
+    tournament_id: u64,
+    board_id: u64,
+    authority: Pubkey,
+    players: &[Pubkey],
+) -> Instruction {
+    let board_address = board_pda(board_id).0;
+    let tournament_address = tournament_pda(tournament_id).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(board_address, false),
+        AccountMeta::new(tournament_address, false),
+        AccountMeta::new(authority, false),
+    ];
+    accounts.extend(
+        players
+            .iter()
+            .map(|player| AccountMeta::new(*player, false)),
+    );
+    accounts.push(AccountMeta::new_readonly(CONFIG_ADDRESS, false));
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: SettleTournament {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, system_program] = accounts
+
+/// Schedules a change of a board's weight. `change_id` must be the config's current
+/// change_count.
+pub fn set_board_weight(signer: Pubkey, board_id: u64, weight: u64, change_id: u64) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: SetBoardWeight {
+            board_id: board_id.to_le_bytes(),
+            weight: weight.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, proposer_info, board_info?] = accounts
+
+/// Executes a scheduled change. Changes to a board take the board, `board_id`.
+pub fn execute_change(
+    signer: Pubkey,
+    change_id: u64,
+    proposer: Pubkey,
+    board_id: Option<u64>,
+) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new(config_address, false),
+        AccountMeta::new(change_address, false),
+        AccountMeta::new(proposer, false),
+    ];
+    if let Some(board_id) = board_id {
+        accounts.push(AccountMeta::new(board_pda(board_id).0, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ExecuteChange {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, change_info, proposer_info] = accounts
+
+pub fn cancel_change(signer: Pubkey, change_id: u64, proposer: Pubkey) -> Instruction {
+    let config_address = config_pda().0;
+    let change_address = change_pda(change_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(change_address, false),
+            AccountMeta::new(proposer, false),
+        ],
+        data: CancelChange {}.to_bytes(),
+    }
+}
+
+// let [signer_info, config_info] = accounts
+
+pub fn set_pause(signer: Pubkey, paused: u64) -> Instruction {
+    let config_address = config_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(config_address, false),
+        ],
+        data: SetPause {
+            paused: paused.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
 // let [signer_info, authority_info, config_info, automation_info, system_program] = accounts
 
 pub fn migrate_automation(signer: Pubkey, authority: Pubkey) -> Instruction {
@@ -612,17 +1784,23 @@ pub fn migrate_automation(signer: Pubkey, authority: Pubkey) -> Instruction {
 
 // let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, rent_sysvar] =
 
+/// Initializes the protocol. With Token-2022, the mint is created with the given metadata;
+/// with spl-token the metadata is ignored.
 pub fn initialize(
     signer: Pubkey,
     admin: Pubkey,
     fee_collector: Pubkey,
     var_address: Pubkey,
+    token_program: Pubkey,
+    name: &str,
+    symbol: &str,
+    uri: &str,
 ) -> Instruction {
-    let board_address = board_pda().0;
+    let board_address = board_pda(0).0;
     let config_address = config_pda().0;
     let mint_address = mint_pda().0;
     let treasury_address = treasury_pda().0;
-    let treasury_tokens_address = get_associated_token_address(&treasury_address, &mint_address);
+    let treasury_tokens_address = treasury_tokens_address(&token_program);
     Instruction {
         program_id: crate::ID,
         accounts: vec![
@@ -633,7 +1811,7 @@ pub fn initialize(
             AccountMeta::new(treasury_address, false),
             AccountMeta::new(treasury_tokens_address, false),
             AccountMeta::new_readonly(system_program::ID, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
             AccountMeta::new_readonly(sysvar::rent::ID, false),
         ],
@@ -641,15 +1819,49 @@ pub fn initialize(
             admin: admin.to_bytes(),
             fee_collector: fee_collector.to_bytes(),
             var_address: var_address.to_bytes(),
+            name: padded_bytes(name),
+            symbol: padded_bytes(symbol),
+            uri: padded_bytes(uri),
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, config_info, mint_info, treasury_info, system_program, token_program] =
+
+pub fn update_metadata_uri(signer: Pubkey, uri: &str) -> Instruction {
+    let config_address = config_pda().0;
+    let mint_address = mint_pda().0;
+    let treasury_address = treasury_pda().0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(config_address, false),
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new_readonly(treasury_address, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token_2022::ID, false),
+        ],
+        data: UpdateMetadataUri {
+            uri: padded_bytes(uri),
         }
         .to_bytes(),
     }
 }
 
+/// Zero-pads a string into a fixed-size instruction field.
+fn padded_bytes<const N: usize>(value: &str) -> [u8; N] {
+    assert!(value.len() <= N, "String exceeds {} bytes", N);
+    let mut bytes = [0; N];
+    bytes[..value.len()].copy_from_slice(value.as_bytes());
+    bytes
+}
+
 /// v0.2: Build a SubmitPrediction instruction.
 /// Allows a miner to predict which square will win the current round.
-pub fn submit_prediction(signer: Pubkey, square: u8) -> Instruction {
-    let board_address = board_pda().0;
+pub fn submit_prediction(signer: Pubkey, board_id: u64, square: u8) -> Instruction {
+    let board_address = board_pda(board_id).0;
     let miner_address = miner_pda(signer).0;
     Instruction {
         program_id: crate::ID,
@@ -661,3 +1873,99 @@ pub fn submit_prediction(signer: Pubkey, square: u8) -> Instruction {
         data: SubmitPrediction { square }.to_bytes(),
     }
 }
+
+// let [signer_info, authority_info, automation_info, miner_info, board_info, round_info] = accounts
+
+/// v0.6: Build a SubmitCommit instruction.
+/// The signer may be the miner authority or its automation executor.
+pub fn submit_commit(
+    signer: Pubkey,
+    authority: Pubkey,
+    board_id: u64,
+    round_id: u64,
+    commitment: [u8; 32],
+) -> Instruction {
+    let automation_address = automation_pda(authority).0;
+    let miner_address = miner_pda(authority).0;
+    let board_address = board_pda(board_id).0;
+    let round_address = round_pda(board_id, round_id).0;
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new(automation_address, false),
+            AccountMeta::new(miner_address, false),
+            AccountMeta::new_readonly(board_address, false),
+            AccountMeta::new(round_address, false),
+        ],
+        data: SubmitCommit { commitment }.to_bytes(),
+    }
+}
+
+// let [signer_info, authority_info, automation_info, miner_info, board_info, round_info] = accounts
+
+/// v0.6: Build a RevealChoice instruction.
+/// The signer may be the miner authority or its automation executor. If `tournament_id` is
+/// given, the reveal is recorded as the authority's pick in that tournament.
+pub fn reveal_choice(
+    signer: Pubkey,
+    authority: Pubkey,
+    board_id: u64,
+    round_id: u64,
+    square: u8,
+    salt: [u8; 16],
+    tournament_id: Option<u64>,
+) -> Instruction {
+    let automation_address = automation_pda(authority).0;
+    let miner_address = miner_pda(authority).0;
+    let board_address = board_pda(board_id).0;
+    let round_address = round_pda(board_id, round_id).0;
+    let mut accounts = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(authority, false),
+        AccountMeta::new_readonly(automation_address, false),
+        AccountMeta::new(miner_address, false),
+        AccountMeta::new_readonly(board_address, false),
+        AccountMeta::new(round_address, false),
+    ];
+    if let Some(tournament_id) = tournament_id {
+        accounts.push(AccountMeta::new(tournament_pda(tournament_id).0, false));
+    }
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: RevealChoice {
+            square,
+            _padding: [0; 15],
+            salt,
+        }
+        .to_bytes(),
+    }
+}
+
+// let [signer_info, automation_info] = accounts
+
+pub fn set_automation_reveal(
+    signer: Pubkey,
+    mode: AutomationReveal,
+    commitments: &[[u8; 32]],
+) -> Instruction {
+    let automation_address = automation_pda(signer).0;
+    let count = commitments.len().min(Automation::MAX_COMMITMENTS);
+    let mut padded = [[0; 32]; Automation::MAX_COMMITMENTS];
+    padded[..count].copy_from_slice(&commitments[..count]);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(automation_address, false),
+        ],
+        data: SetAutomationReveal {
+            mode: mode as u8,
+            count: count as u8,
+            commitments: padded,
+        }
+        .to_bytes(),
+    }
+}
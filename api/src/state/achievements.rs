@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::{consts::DENOMINATOR_BPS, state::achievements_pda};
+
+use super::{Miner, OreAccount, Round};
+
+/// A milestone a miner can unlock. Achievements are soulbound: they are recorded on the miner's
+/// achievements account and cannot be transferred.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum Achievement {
+    /// Won a round.
+    FirstWin = 0,
+
+    /// Predicted the winning square 10 rounds in a row.
+    Streak = 1,
+
+    /// Revealed the winning square 100 times.
+    Reveals = 2,
+
+    /// Revealed a winning square fewer than 5% of reveals picked.
+    Contrarian = 3,
+
+    /// Shared in a motherlode.
+    Motherlode = 4,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 5] = [
+        Self::FirstWin,
+        Self::Streak,
+        Self::Reveals,
+        Self::Contrarian,
+        Self::Motherlode,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FirstWin => "First win",
+            Self::Streak => "10-round streak",
+            Self::Reveals => "100 correct reveals",
+            Self::Contrarian => "Contrarian winner",
+            Self::Motherlode => "Motherlode",
+        }
+    }
+}
+
+/// The achievements a miner has unlocked.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Achievements {
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// Bitmap of unlocked achievements, indexed by Achievement.
+    pub unlocked: u64,
+
+    /// The number of rounds in which the miner revealed the winning square.
+    pub correct_reveals: u64,
+
+    /// The round each achievement was unlocked in.
+    pub unlocked_round: [u64; 16],
+}
+
+impl Achievements {
+    /// The prediction streak needed for the streak achievement.
+    pub const STREAK_TARGET: u16 = 10;
+
+    /// The number of correct reveals needed for the reveals achievement.
+    pub const REVEALS_TARGET: u64 = 100;
+
+    /// The share of reveals under which a winning square counts as contrarian (bps).
+    pub const CONTRARIAN_POPULARITY: u64 = 500;
+
+    pub fn pda(&self) -> (Pubkey, u8) {
+        achievements_pda(self.authority)
+    }
+
+    pub fn has(&self, achievement: Achievement) -> bool {
+        self.unlocked & (1 << achievement as u8) != 0
+    }
+
+    /// Unlocks an achievement in a round. Returns whether it was newly unlocked.
+    pub fn unlock(&mut self, achievement: Achievement, round_id: u64) -> bool {
+        if self.has(achievement) {
+            return false;
+        }
+        self.unlocked |= 1 << achievement as u8;
+        self.unlocked_round[achievement as usize] = round_id;
+        true
+    }
+
+    /// Records a checkpointed round and returns the achievements it unlocked. Must be called
+    /// after the miner's prediction has been evaluated and before its reveal is cleared.
+    pub fn record(
+        &mut self,
+        miner: &Miner,
+        round: &Round,
+        winning_square: u8,
+        won: bool,
+    ) -> Vec<Achievement> {
+        let revealed_winner =
+            miner.has_revealed_for_round(round.id) && miner.revealed_square == winning_square;
+        if revealed_winner {
+            self.correct_reveals += 1;
+        }
+        let popularity = round.revealed_count[winning_square as usize] * DENOMINATOR_BPS;
+        let conditions = [
+            (Achievement::FirstWin, won),
+            (Achievement::Streak, miner.streak >= Self::STREAK_TARGET),
+            (
+                Achievement::Reveals,
+                self.correct_reveals >= Self::REVEALS_TARGET,
+            ),
+            (
+                Achievement::Contrarian,
+                revealed_winner && popularity < Self::CONTRARIAN_POPULARITY * round.total_reveals,
+            ),
+            (Achievement::Motherlode, won && round.motherlode > 0),
+        ];
+        conditions
+            .into_iter()
+            .filter(|&(achievement, met)| met && self.unlock(achievement, round.id))
+            .map(|(achievement, _)| achievement)
+            .collect()
+    }
+}
+
+account!(OreAccount, Achievements);
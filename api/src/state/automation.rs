@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::miner_pda;
+use crate::{consts::ONE_DAY_SLOTS, state::miner_pda};
 
 use super::OreAccount;
 
@@ -27,11 +27,67 @@ pub struct Automation {
     pub strategy: u64,
 
     /// The mask of squares this automation should deploy to if preferred strategy.
-    /// If strategy is Random, first bit is used to determine how many squares to deploy to.
+    /// If strategy is Random, TopK or Contrarian, the first byte is used to determine how many
+    /// squares to deploy to.
     pub mask: u64,
 
     /// Whether or not to auto-reload SOL winnings into the automation balance.
     pub reload: u64,
+
+    /// How the executor takes part in commit-reveal on behalf of the authority.
+    pub reveal_mode: u64,
+
+    /// The index of the next pre-committed hash the executor will submit.
+    pub commit_index: u64,
+
+    /// The number of pre-committed hashes loaded into this automation.
+    pub commit_count: u64,
+
+    /// Pre-committed hashes, submitted by the executor in order, one per round.
+    pub commitments: [[u8; 32]; 8],
+
+    /// The maximum number of rounds this automation may deploy in (0 for no limit).
+    pub max_rounds: u64,
+
+    /// The number of rounds this automation has deployed in.
+    pub rounds: u64,
+
+    /// The slot at which this automation expires (0 for no expiry).
+    pub expires_at: u64,
+
+    /// The maximum amount of SOL this automation may spend per day, including fees (0 for no cap).
+    pub daily_cap: u64,
+
+    /// The day (slot / ONE_DAY_SLOTS) that day_spent is tracked for.
+    pub day: u64,
+
+    /// The amount of SOL spent on the current day, including fees.
+    pub day_spent: u64,
+
+    /// The net SOL loss at which this automation is closed (0 to disable).
+    pub stop_loss: u64,
+
+    /// The net SOL gain at which this automation is closed (0 to disable).
+    pub take_profit: u64,
+
+    /// The cumulative amount of SOL spent by this automation, including fees.
+    pub total_spent: u64,
+
+    /// The cumulative amount of SOL won by the miner since the limits were set.
+    pub total_won: u64,
+
+    /// The miner's lifetime SOL rewards when total_won was last updated.
+    pub rewards_snapshot: u64,
+
+    /// The board the executor deploys to.
+    pub board_id: u64,
+
+    /// The guild that funded part of the balance, or the default address if none.
+    pub guild: Pubkey,
+
+    /// The part of the balance funded by the guild. It is returned to the guild, not the
+    /// authority, when the automation closes, changes executor or the miner leaves the guild.
+    pub guild_balance: u64,
 }
 
 #[repr(u8)]
@@ -39,18 +95,306 @@ pub struct Automation {
 pub enum AutomationStrategy {
     Random = 0,
     Preferred = 1,
+
+    /// Deploy to the square with the most SOL deployed at execution time.
+    FollowCrowd = 2,
+
+    /// Deploy to the K squares with the most SOL deployed at execution time.
+    TopK = 3,
+
+    /// Deploy to the round's bonus squares.
+    Bonus = 4,
+
+    /// Deploy to the K squares with the least SOL deployed, always avoiding the most crowded one.
+    Contrarian = 5,
 }
 
 impl AutomationStrategy {
     pub fn from_u64(value: u64) -> Self {
         Self::try_from(value as u8).unwrap()
     }
+
+    /// Whether the mask's first byte holds a square count rather than a square bitmask.
+    pub fn uses_count(&self) -> bool {
+        matches!(self, Self::Random | Self::TopK | Self::Contrarian)
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum AutomationReveal {
+    /// The executor does not commit or reveal.
+    None = 0,
+
+    /// The executor submits the pre-committed hashes stored on the automation.
+    Committed = 1,
+
+    /// The executor commits and reveals the square the miner deployed the most SOL to.
+    Deployed = 2,
+}
+
+impl AutomationReveal {
+    pub fn from_u64(value: u64) -> Self {
+        Self::try_from(value as u8).unwrap()
+    }
+}
+
+/// The reason an automation was closed, as logged in AutomationClosedEvent.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum AutomationCloseReason {
+    /// The balance cannot cover another square.
+    Depleted = 0,
+
+    /// The automation has deployed in max_rounds rounds.
+    MaxRounds = 1,
+
+    /// The automation has passed its expiry slot.
+    Expired = 2,
+
+    /// The net loss has reached the stop-loss threshold.
+    StopLoss = 3,
+
+    /// The net gain has reached the take-profit threshold.
+    TakeProfit = 4,
+
+    /// The authority closed the automation.
+    Closed = 5,
 }
 
 impl Automation {
+    /// The maximum number of pre-committed hashes an automation can hold.
+    pub const MAX_COMMITMENTS: usize = 8;
+
     pub fn pda(&self) -> (Pubkey, u8) {
         miner_pda(self.authority)
     }
+
+    /// Record SOL won by the miner since the last snapshot of its lifetime rewards.
+    pub fn sync_rewards(&mut self, lifetime_rewards_sol: u64) {
+        self.total_won += lifetime_rewards_sol.saturating_sub(self.rewards_snapshot);
+        self.rewards_snapshot = lifetime_rewards_sol;
+    }
+
+    /// Record SOL spent, rolling over the daily window if a new day has started.
+    pub fn record_spend(&mut self, amount: u64, slot: u64) {
+        let day = slot / ONE_DAY_SLOTS;
+        if day != self.day {
+            self.day = day;
+            self.day_spent = 0;
+        }
+        self.day_spent += amount;
+        self.total_spent += amount;
+    }
+
+    /// The amount of SOL that may still be spent today, or None if there is no daily cap.
+    pub fn daily_remaining(&self, slot: u64) -> Option<u64> {
+        if self.daily_cap == 0 {
+            return None;
+        }
+        if slot / ONE_DAY_SLOTS != self.day {
+            return Some(self.daily_cap);
+        }
+        Some(self.daily_cap.saturating_sub(self.day_spent))
+    }
+
+    /// The amount of SOL that may still be spent before the net loss reaches the stop-loss, or
+    /// None if there is no stop-loss.
+    pub fn stop_loss_remaining(&self) -> Option<u64> {
+        if self.stop_loss == 0 {
+            return None;
+        }
+        Some(
+            self.total_won
+                .saturating_add(self.stop_loss)
+                .saturating_sub(self.total_spent),
+        )
+    }
+
+    /// The amount of SOL that may still be spent under both the daily cap and the stop-loss, or
+    /// None if there is neither.
+    pub fn spend_remaining(&self, slot: u64) -> Option<u64> {
+        match (self.daily_remaining(slot), self.stop_loss_remaining()) {
+            (Some(daily), Some(stop_loss)) => Some(daily.min(stop_loss)),
+            (daily, stop_loss) => daily.or(stop_loss),
+        }
+    }
+
+    /// Returns the limit that has been tripped, if any. The stop-loss trips as soon as what is
+    /// left of it cannot cover another square, so the net loss never goes past it.
+    pub fn tripped_limit(&self, slot: u64) -> Option<AutomationCloseReason> {
+        if self.expires_at > 0 && slot >= self.expires_at {
+            return Some(AutomationCloseReason::Expired);
+        }
+        if self.max_rounds > 0 && self.rounds >= self.max_rounds {
+            return Some(AutomationCloseReason::MaxRounds);
+        }
+        if let Some(remaining) = self.stop_loss_remaining() {
+            if remaining < self.amount + self.fee {
+                return Some(AutomationCloseReason::StopLoss);
+            }
+        }
+        if self.take_profit > 0
+            && self.total_won >= self.total_spent.saturating_add(self.take_profit)
+        {
+            return Some(AutomationCloseReason::TakeProfit);
+        }
+        None
+    }
+
+    /// Credit SOL funded by a guild.
+    pub fn fund_from_guild(&mut self, guild: Pubkey, amount: u64) {
+        self.guild = guild;
+        self.balance += amount;
+        self.guild_balance += amount;
+    }
+
+    /// Deduct SOL spent from the balance. The authority's own funds are spent first, so the guild
+    /// keeps its claim on whatever is left of what it funded.
+    pub fn spend(&mut self, amount: u64) {
+        self.balance -= amount;
+        self.guild_balance = self.guild_balance.min(self.balance);
+    }
+
+    /// Take the guild-funded part of the balance, to be returned to the guild.
+    pub fn take_guild_balance(&mut self) -> u64 {
+        let amount = self.guild_balance;
+        self.balance -= amount;
+        self.guild_balance = 0;
+        self.guild = Pubkey::default();
+        amount
+    }
+
+    /// Take the next pre-committed hash, if any remain.
+    pub fn next_commitment(&mut self) -> Option<[u8; 32]> {
+        if self.commit_index >= self.commit_count {
+            return None;
+        }
+        let commitment = self.commitments[self.commit_index as usize];
+        self.commit_index += 1;
+        Some(commitment)
+    }
 }
 
 account!(OreAccount, Automation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn automation() -> Automation {
+        let mut automation = Automation::zeroed();
+        automation.amount = 100;
+        automation.fee = 10;
+        automation
+    }
+
+    #[test]
+    fn test_tripped_limit_none() {
+        let mut automation = automation();
+        automation.total_spent = 1_000;
+        automation.rounds = 50;
+        assert_eq!(automation.tripped_limit(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_tripped_limit_expired() {
+        let mut automation = automation();
+        automation.expires_at = 1_000;
+        assert_eq!(automation.tripped_limit(999), None);
+        assert_eq!(
+            automation.tripped_limit(1_000),
+            Some(AutomationCloseReason::Expired)
+        );
+    }
+
+    #[test]
+    fn test_tripped_limit_max_rounds() {
+        let mut automation = automation();
+        automation.max_rounds = 3;
+        automation.rounds = 2;
+        assert_eq!(automation.tripped_limit(0), None);
+        automation.rounds = 3;
+        assert_eq!(
+            automation.tripped_limit(0),
+            Some(AutomationCloseReason::MaxRounds)
+        );
+    }
+
+    #[test]
+    fn test_tripped_limit_stop_loss() {
+        let mut automation = automation();
+        automation.stop_loss = 500;
+        automation.total_won = 200;
+        assert_eq!(automation.stop_loss_remaining(), Some(700));
+
+        // Trips once the rest of the stop-loss cannot cover a square and its fee.
+        automation.total_spent = 590;
+        assert_eq!(automation.stop_loss_remaining(), Some(110));
+        assert_eq!(automation.tripped_limit(0), None);
+        automation.total_spent = 591;
+        assert_eq!(
+            automation.tripped_limit(0),
+            Some(AutomationCloseReason::StopLoss)
+        );
+
+        // Winnings synced from the miner raise what may be spent.
+        automation.rewards_snapshot = 1_000;
+        automation.sync_rewards(1_300);
+        assert_eq!(automation.total_won, 500);
+        assert_eq!(automation.tripped_limit(0), None);
+    }
+
+    #[test]
+    fn test_tripped_limit_take_profit() {
+        let mut automation = automation();
+        automation.take_profit = 500;
+        automation.total_spent = 1_000;
+        automation.sync_rewards(1_499);
+        assert_eq!(automation.tripped_limit(0), None);
+        automation.sync_rewards(1_500);
+        assert_eq!(
+            automation.tripped_limit(0),
+            Some(AutomationCloseReason::TakeProfit)
+        );
+    }
+
+    #[test]
+    fn test_spend_remaining() {
+        let mut automation = automation();
+        assert_eq!(automation.spend_remaining(0), None);
+        automation.stop_loss = 500;
+        assert_eq!(automation.spend_remaining(0), Some(500));
+        automation.daily_cap = 300;
+        assert_eq!(automation.spend_remaining(0), Some(300));
+        automation.record_spend(100, 0);
+        assert_eq!(automation.spend_remaining(0), Some(200));
+
+        // A new day resets the daily cap, but not the stop-loss.
+        automation.record_spend(250, ONE_DAY_SLOTS);
+        assert_eq!(automation.spend_remaining(ONE_DAY_SLOTS), Some(50));
+    }
+
+    #[test]
+    fn test_guild_balance() {
+        let guild = Pubkey::new_unique();
+        let mut automation = Automation::zeroed();
+        automation.balance = 300;
+        automation.fund_from_guild(guild, 500);
+        assert_eq!(automation.balance, 800);
+        assert_eq!(automation.guild_balance, 500);
+
+        // The authority's own funds are spent first.
+        automation.spend(200);
+        assert_eq!(automation.guild_balance, 500);
+        automation.spend(400);
+        assert_eq!(automation.balance, 200);
+        assert_eq!(automation.guild_balance, 200);
+
+        // Only the guild's remaining funds are taken back.
+        assert_eq!(automation.take_guild_balance(), 200);
+        assert_eq!(automation.balance, 0);
+        assert_eq!(automation.guild_balance, 0);
+        assert_eq!(automation.guild, Pubkey::default());
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::board_pda;
+use crate::{
+    consts::{DENOMINATOR_BPS, INTERMISSION_SLOTS, MAX_SQUARES, MIN_SQUARES},
+    state::{board_pda, Round},
+};
 
 use super::OreAccount;
 
@@ -16,12 +19,178 @@ pub struct Board {
 
     /// The slot at which the current round ends mining.
     pub end_slot: u64,
+
+    /// The id of this board.
+    pub id: u64,
+
+    /// The minimum amount of SOL that can be deployed to a square.
+    pub min_deploy: u64,
+
+    /// The number of slots in the deploy phase of a round.
+    pub deploy_slots: u64,
+
+    /// The number of slots in the commit phase of a round.
+    pub commit_slots: u64,
+
+    /// The number of slots in the reveal phase of a round.
+    pub reveal_slots: u64,
+
+    /// The fee taken from deployments on this board (bps).
+    pub admin_fee: u64,
+
+    /// This board's weight in the split of round rewards (see Config::total_board_weight).
+    pub weight: u64,
+
+    /// The number of squares on the board, laid out as an N×N grid.
+    pub squares: u64,
+
+    /// The board's latest season, plus one. Zero if the board has never had a season.
+    pub season: u64,
+
+    /// The first round of the board's latest season.
+    pub season_start_round: u64,
+
+    /// The last round of the board's latest season.
+    pub season_end_round: u64,
+
+    /// The amount of ORE in this board's motherlode rewards pool.
+    pub motherlode: u64,
 }
 
 impl Board {
     pub fn pda(&self) -> (Pubkey, u8) {
-        board_pda()
+        board_pda(self.id)
+    }
+
+    /// The admin fee charged on an amount of SOL.
+    pub fn admin_fee(&self, amount: u64) -> u64 {
+        amount * self.admin_fee / DENOMINATOR_BPS
+    }
+
+    /// This board's share of a reward, by weight. Boards without weight get nothing.
+    pub fn reward_share(&self, amount: u64, total_weight: u64) -> u64 {
+        if total_weight == 0 {
+            return 0;
+        }
+        (amount as u128 * self.weight as u128 / total_weight as u128) as u64
+    }
+
+    /// The value of Round::season for one of this board's rounds.
+    pub fn round_season(&self, round_id: u64) -> u64 {
+        if self.season > 0
+            && round_id >= self.season_start_round
+            && round_id <= self.season_end_round
+        {
+            self.season
+        } else {
+            0
+        }
+    }
+
+    /// The number of slots from the start of one of this board's rounds to the next, including
+    /// the intermission.
+    pub fn round_slots(&self) -> u64 {
+        self.deploy_slots + self.commit_slots + self.reveal_slots + INTERMISSION_SLOTS
+    }
+
+    /// This board's emission for one of its rounds, given an emission rate per round of the
+    /// default length. The share by weight is scaled by the length of the board's rounds, so
+    /// boards split emissions by time rather than by the number of rounds they play.
+    pub fn emission(&self, rate: u64, total_weight: u64) -> u64 {
+        let default_round_slots = Round::TOTAL_ROUND_SLOTS + INTERMISSION_SLOTS;
+        (self.reward_share(rate, total_weight) as u128 * self.round_slots() as u128
+            / default_round_slots as u128) as u64
     }
+
+    /// The number of squares along each side of the board.
+    pub fn side(&self) -> u64 {
+        isqrt(self.squares)
+    }
+
+    /// Whether a board may be laid out with this many squares.
+    pub fn is_valid_geometry(squares: u64) -> bool {
+        if squares < MIN_SQUARES || squares > MAX_SQUARES as u64 {
+            return false;
+        }
+        let side = isqrt(squares);
+        side * side == squares
+    }
+}
+
+fn isqrt(n: u64) -> u64 {
+    (1..=n)
+        .take_while(|s| s.checked_mul(*s).is_some_and(|sq| sq <= n))
+        .last()
+        .unwrap_or(0)
 }
 
 account!(OreAccount, Board);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(weight: u64, round_slots: u64) -> Board {
+        let mut board = Board::zeroed();
+        board.weight = weight;
+        board.deploy_slots = round_slots / 2;
+        board.commit_slots = round_slots / 4;
+        board.reveal_slots = round_slots / 4;
+        board
+    }
+
+    #[test]
+    fn test_reward_share() {
+        let main = board(3, Round::TOTAL_ROUND_SLOTS);
+        let side = board(1, Round::TOTAL_ROUND_SLOTS);
+        assert_eq!(main.reward_share(1_000, 4), 750);
+        assert_eq!(side.reward_share(1_000, 4), 250);
+
+        // Without weights, such as a config migrated before its boards, nothing is paid.
+        assert_eq!(main.reward_share(1_000, 0), 0);
+        assert_eq!(board(0, Round::TOTAL_ROUND_SLOTS).reward_share(1_000, 4), 0);
+    }
+
+    #[test]
+    fn test_emission_by_time() {
+        let default_round_slots = Round::TOTAL_ROUND_SLOTS + INTERMISSION_SLOTS;
+
+        // A board with default-length rounds emits its share by weight each round.
+        let main = board(1, Round::TOTAL_ROUND_SLOTS);
+        assert_eq!(main.round_slots(), default_round_slots);
+        assert_eq!(main.emission(1_550, 2), 775);
+
+        // A board with rounds of a different length emits the same over the same time.
+        let fast = board(1, 40);
+        let slots = main.round_slots() * fast.round_slots();
+        let main_total = main.emission(1_550, 2) * (slots / main.round_slots());
+        let fast_total = fast.emission(1_550, 2) * (slots / fast.round_slots());
+        assert_eq!(main_total, fast_total);
+        assert_eq!(fast.emission(1_550, 2), 375);
+    }
+
+    #[test]
+    fn test_is_valid_geometry() {
+        for squares in [4, 9, 16, 25, 36] {
+            assert!(Board::is_valid_geometry(squares));
+        }
+        for squares in [0, 1, 5, 24, 49, u64::MAX] {
+            assert!(!Board::is_valid_geometry(squares));
+        }
+    }
+
+    #[test]
+    fn test_round_season() {
+        let mut board = board(1, Round::TOTAL_ROUND_SLOTS);
+        assert_eq!(board.round_season(5), 0);
+
+        // Only the rounds of the latest season are stamped with it.
+        board.season = 3;
+        board.season_start_round = 10;
+        board.season_end_round = 20;
+        assert_eq!(board.round_season(9), 0);
+        assert_eq!(board.round_season(10), 3);
+        assert_eq!(board.round_season(20), 3);
+        assert_eq!(board.round_season(21), 0);
+    }
+}
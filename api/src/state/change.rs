@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::change_pda;
+
+use super::OreAccount;
+
+/// A config change scheduled by an admin, which can be executed once its effective slot passes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Change {
+    /// The id of this change.
+    pub id: u64,
+
+    /// The kind of change (see ChangeKind).
+    pub kind: u64,
+
+    /// The new value, either an address or a little-endian u64 depending on the kind. Changes to
+    /// a board are followed by the board's id.
+    pub value: [u8; 32],
+
+    /// The address that scheduled the change. Receives the rent when the change is closed.
+    pub proposer: Pubkey,
+
+    /// The slot the change was scheduled at.
+    pub created_at: u64,
+
+    /// The slot at which the change can be executed.
+    pub effective_slot: u64,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+pub enum ChangeKind {
+    /// Sets config.swap_program.
+    SwapProgram = 0,
+
+    /// Sets config.var_address.
+    VarAddress = 1,
+
+    /// Sets config.fee_collector.
+    FeeCollector = 2,
+
+    /// Sets a board's admin_fee.
+    AdminFee = 3,
+
+    /// Sets config.change_delay.
+    ChangeDelay = 4,
+
+    /// Sets config.buyback_cap.
+    BuybackCap = 5,
+
+    /// Sets config.liq_manager.
+    LiqManager = 6,
+
+    /// Sets config.referral_fee.
+    ReferralFee = 7,
+
+    /// Sets a board's weight.
+    BoardWeight = 8,
+
+    /// Sets config.vesting_period.
+    VestingPeriod = 9,
+
+    /// Sets config.motherlode_odds.
+    MotherlodeOdds = 10,
+
+    /// Sets config.motherlode_rate.
+    MotherlodeRate = 11,
+
+    /// Sets config.rng_mode.
+    RngMode = 12,
+
+    /// Sets config.bonus_square_count.
+    BonusSquareCount = 13,
+
+    /// Sets config.bonus_square_multiplier.
+    BonusSquareMultiplier = 14,
+}
+
+impl ChangeKind {
+    pub fn from_u64(value: u64) -> Self {
+        Self::try_from(value as u8).unwrap()
+    }
+
+    /// Whether the change applies to a board rather than the config.
+    pub fn is_board_change(&self) -> bool {
+        matches!(self, Self::AdminFee | Self::BoardWeight)
+    }
+}
+
+impl Change {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        change_pda(self.id)
+    }
+
+    /// The new value as an address.
+    pub fn value_address(&self) -> Pubkey {
+        Pubkey::new_from_array(self.value)
+    }
+
+    /// The new value as a number.
+    pub fn value_u64(&self) -> u64 {
+        u64::from_le_bytes(self.value[..8].try_into().unwrap())
+    }
+
+    /// The board a board change applies to.
+    pub fn value_board_id(&self) -> u64 {
+        u64::from_le_bytes(self.value[8..16].try_into().unwrap())
+    }
+
+    /// Encode a number as a change value.
+    pub fn encode_u64(value: u64) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&value.to_le_bytes());
+        bytes
+    }
+
+    /// Encode a number as the value of a change to a board.
+    pub fn encode_board_u64(board_id: u64, value: u64) -> [u8; 32] {
+        let mut bytes = Self::encode_u64(value);
+        bytes[8..16].copy_from_slice(&board_id.to_le_bytes());
+        bytes
+    }
+}
+
+account!(OreAccount, Change);
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::config_pda;
+use crate::{consts::RNG_MODE_ENTROPY, state::config_pda};
 
 use super::OreAccount;
 
@@ -20,17 +20,118 @@ pub struct Config {
     /// The program to be used for protocol swaps.
     pub swap_program: Pubkey,
 
-    /// The address of the entropy var account.
+    /// The address of the legacy entropy var account. Rounds draw their randomness from their
+    /// board's own var (see board_var_pda).
     pub var_address: Pubkey,
 
-    /// Amount to pay to fee collector (bps)
+    /// Unused. Each board sets its own admin fee (see Board::admin_fee).
     pub admin_fee: u64,
+
+    /// The proposed admin, who must accept before the transfer takes effect.
+    pub pending_admin: Pubkey,
+
+    /// The address with authority to set the fee collector and the boards' admin fees.
+    pub fee_authority: Pubkey,
+
+    /// The address with authority to pause the protocol.
+    pub pause_authority: Pubkey,
+
+    /// The number of slots a scheduled change must wait before it can be executed.
+    pub change_delay: u64,
+
+    /// The number of changes ever scheduled. Used as the id of the next change.
+    pub change_count: u64,
+
+    /// Bitmap of paused instruction groups (see the PAUSE_* flags).
+    pub paused: u64,
+
+    /// The maximum amount of SOL that can be wrapped or swapped in a single buyback.
+    pub buyback_cap: u64,
+
+    /// The address that receives treasury SOL for liquidity. Liq is disabled while unset.
+    pub liq_manager: Pubkey,
+
+    /// Share of a referee's ORE claim fee paid to their referrer (bps).
+    pub referral_fee: u64,
+
+    /// The number of boards ever created. Used as the id of the next board.
+    pub board_count: u64,
+
+    /// The sum of all board weights.
+    pub total_board_weight: u64,
+
+    /// The number of seasons created. The next season's id.
+    pub season_count: u64,
+
+    /// The number of guilds created. The next guild's id.
+    pub guild_count: u64,
+
+    /// The number of tournaments created. The next tournament's id.
+    pub tournament_count: u64,
+
+    /// The number of seconds over which the boosted portion of ORE rewards vests.
+    pub vesting_period: u64,
+
+    /// The odds of a round hitting the motherlode (1 in this many rounds). Zero disables it.
+    pub motherlode_odds: u64,
+
+    /// The amount of ORE added to the motherlode per round, split across boards by weight.
+    pub motherlode_rate: u64,
+
+    /// Where rounds draw their randomness from (RNG_MODE_SLOT_HASHES or RNG_MODE_ENTROPY).
+    pub rng_mode: u64,
+
+    /// The number of bonus squares drawn for each round.
+    pub bonus_square_count: u64,
+
+    /// The reward multiplier when a bonus square wins (percent, 200 = 2x).
+    pub bonus_square_multiplier: u64,
 }
 
 impl Config {
     pub fn pda() -> (Pubkey, u8) {
         config_pda()
     }
+
+    /// The expected ORE (in base units) won from the motherlode per lamport deployed in a round
+    /// with `total_deployed` lamports, given a motherlode of `motherlode` ORE. Deployments are
+    /// assumed to be spread like the round's, so every lamport is equally likely to share in it.
+    pub fn motherlode_ev(&self, motherlode: u64, total_deployed: u64) -> f64 {
+        if self.motherlode_odds == 0 {
+            return 0.0;
+        }
+        motherlode as f64 / self.motherlode_odds as f64 / total_deployed.max(1) as f64
+    }
+
+    /// Whether rounds should draw their randomness from the entropy var.
+    pub fn uses_entropy(&self) -> bool {
+        self.rng_mode == RNG_MODE_ENTROPY
+    }
+
+    /// Whether any of the given pause flags are set.
+    pub fn is_paused(&self, flags: u64) -> bool {
+        self.paused & flags != 0
+    }
 }
 
 account!(OreAccount, Config);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_motherlode_ev() {
+        let mut config = Config::zeroed();
+        assert_eq!(config.motherlode_ev(625_000, 1_000), 0.0);
+
+        config.motherlode_odds = 625;
+        assert_eq!(config.motherlode_ev(625_000, 1_000), 1.0);
+        assert_eq!(config.motherlode_ev(625_000, 2_000), 0.5);
+
+        // Better odds raise the expected value, an empty round is treated as one lamport.
+        config.motherlode_odds = 125;
+        assert_eq!(config.motherlode_ev(625_000, 1_000), 5.0);
+        assert_eq!(config.motherlode_ev(625_000, 0), 5_000.0);
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::{consts::DENOMINATOR_BPS, state::guild_pda};
+
+use super::OreAccount;
+
+/// A team of miners. Members give up a share of their ORE rewards to the guild, and the guild's
+/// SOL treasury can fund members' automations so its executor deploys on their behalf.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Guild {
+    /// The id of this guild.
+    pub id: u64,
+
+    /// The authority that manages the guild and claims its rewards.
+    pub authority: Pubkey,
+
+    /// The executor that deploys for members with guild-funded automations.
+    pub executor: Pubkey,
+
+    /// The name of the guild, zero-padded UTF-8.
+    pub name: [u8; 32],
+
+    /// Share of members' ORE rewards paid to the guild (bps).
+    pub revenue_share: u64,
+
+    /// The number of miners in the guild.
+    pub member_count: u64,
+
+    /// The amount of SOL in the guild treasury.
+    pub balance: u64,
+
+    /// The amount of ORE the guild has earned and not yet claimed.
+    pub rewards_ore: u64,
+
+    /// The total amount of ORE the guild has earned.
+    pub lifetime_rewards_ore: u64,
+
+    /// The number of rounds members have won.
+    pub wins: u64,
+
+    /// The number of rounds members have played.
+    pub rounds: u64,
+
+    /// The total amount of SOL members have deployed.
+    pub volume: u64,
+
+    /// The sum of the reward multipliers of members' wins.
+    pub skill_points: u64,
+}
+
+impl Guild {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        guild_pda(self.id)
+    }
+
+    /// The guild's share of a member's ORE rewards.
+    pub fn revenue_share(&self, amount: u64) -> u64 {
+        (amount as u128 * self.revenue_share as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+
+    /// The guild's name, without padding.
+    pub fn name(&self) -> String {
+        let len = self.name.iter().position(|&b| b == 0).unwrap_or(32);
+        String::from_utf8_lossy(&self.name[..len]).to_string()
+    }
+
+    /// Credits the guild with its share of a member's checkpointed round.
+    pub fn record(&mut self, deployed: u64, win_points: u64, rewards_ore: u64) {
+        self.rounds += 1;
+        self.volume += deployed;
+        if win_points > 0 {
+            self.wins += 1;
+            self.skill_points += win_points;
+        }
+        self.rewards_ore += rewards_ore;
+        self.lifetime_rewards_ore += rewards_ore;
+    }
+
+    /// Takes the guild's unclaimed ORE rewards.
+    pub fn claim_ore(&mut self) -> u64 {
+        let amount = self.rewards_ore;
+        self.rewards_ore = 0;
+        amount
+    }
+}
+
+account!(OreAccount, Guild);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revenue_share() {
+        let mut guild = Guild::zeroed();
+        assert_eq!(guild.revenue_share(1_000), 0);
+        guild.revenue_share = 1_500;
+        assert_eq!(guild.revenue_share(1_000), 150);
+        assert_eq!(guild.revenue_share(999), 149);
+
+        // Large rewards don't overflow.
+        assert_eq!(guild.revenue_share(u64::MAX / 3), u64::MAX / 20);
+    }
+
+    #[test]
+    fn test_record_and_claim() {
+        let mut guild = Guild::zeroed();
+        guild.record(500, 0, 0);
+        guild.record(300, 120, 40);
+        assert_eq!(guild.rounds, 2);
+        assert_eq!(guild.wins, 1);
+        assert_eq!(guild.volume, 800);
+        assert_eq!(guild.skill_points, 120);
+        assert_eq!(guild.rewards_ore, 40);
+
+        // Claims take the unclaimed rewards, but keep the lifetime total.
+        assert_eq!(guild.claim_ore(), 40);
+        guild.record(100, 100, 10);
+        assert_eq!(guild.claim_ore(), 10);
+        assert_eq!(guild.claim_ore(), 0);
+        assert_eq!(guild.lifetime_rewards_ore, 50);
+    }
+
+    #[test]
+    fn test_name() {
+        let mut guild = Guild::zeroed();
+        guild.name[..5].copy_from_slice(b"miner");
+        assert_eq!(guild.name(), "miner");
+        guild.name = [b'a'; 32];
+        assert_eq!(guild.name().len(), 32);
+    }
+}
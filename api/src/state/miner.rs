@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::{miner_pda, Treasury};
+use crate::{
+    consts::{DENOMINATOR_BPS, MAX_SQUARES},
+    state::{miner_pda, widen_square_arrays, Treasury},
+};
 
 use super::OreAccount;
 
@@ -12,10 +15,10 @@ pub struct Miner {
     pub authority: Pubkey,
 
     /// The miner's prospects in the current round.
-    pub deployed: [u64; 25],
+    pub deployed: [u64; MAX_SQUARES],
 
     /// The cumulative amount of SOL deployed on each square prior to this miner's move.
-    pub cumulative: [u64; 25],
+    pub cumulative: [u64; MAX_SQUARES],
 
     /// SOL witheld in reserve to pay for checkpointing.
     pub checkpoint_fee: u64,
@@ -55,7 +58,7 @@ pub struct Miner {
     /// Cumulative skill points earned from correct predictions (never decreases).
     pub skill_score: u64,
 
-    /// Current round prediction: 0-24 for square, 255 for no prediction.
+    /// Current round prediction: the square index, 255 for no prediction.
     pub prediction: u8,
 
     /// Padding for alignment after u8.
@@ -87,20 +90,80 @@ pub struct Miner {
     /// Round ID for current commitment.
     pub commit_round_id: u64,
 
-    /// Revealed square (0-24, or 255 if not revealed yet).
+    /// Revealed square index, or 255 if not revealed yet.
     pub revealed_square: u8,
 
     /// Padding for alignment.
     pub _padding3: [u8; 7],
+
+    // ============ Referral Fields ============
+
+    /// The miner who referred this miner, or the default address if none.
+    pub referrer: Pubkey,
+
+    /// The total amount of ORE this miner has earned from referrals.
+    pub referral_rewards_ore: u64,
+
+    // ============ Board Fields ============
+
+    /// The board of the round this miner last played in.
+    pub board_id: u64,
+
+    // ============ Guild Fields ============
+
+    /// The guild this miner is a member of, or the default address if none.
+    pub guild: Pubkey,
+
+    // ============ Vesting Fields ============
+
+    /// Boosted ORE rewards that have not been released yet.
+    pub vesting_ore: u64,
+
+    /// The last time vested ORE was released.
+    pub vesting_updated_at: i64,
+
+    /// The time at which the vesting balance is fully vested.
+    pub vesting_end_at: i64,
+
+    // ============ Claim Fields ============
+
+    /// The address rewards are claimed to, or the default address to claim to the authority.
+    pub claim_recipient: Pubkey,
 }
 
 impl Miner {
+    /// The offsets of `deployed` and `cumulative` in miner account data with LEGACY_MAX_SQUARES
+    /// squares.
+    const LEGACY_SQUARE_ARRAYS: [usize; 2] = [40, 240];
+
+    /// The length of miner account data in the last layout with LEGACY_MAX_SQUARES squares.
+    const LEGACY_LEN: usize = 688;
+
+    /// Migrate miner account data resized from `old_len` bytes to the current layout. Miners from
+    /// before 6x6 boards have their square arrays widened. Fields added since are zeroed.
+    pub fn migrate_legacy(data: &mut [u8], old_len: usize) {
+        if old_len > Self::LEGACY_LEN {
+            data[old_len..].fill(0);
+            return;
+        }
+        widen_square_arrays(data, old_len, &Self::LEGACY_SQUARE_ARRAYS);
+    }
+
     pub fn pda(&self) -> (Pubkey, u8) {
         miner_pda(self.authority)
     }
 
-    pub fn claim_ore(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
+    /// Claims all vested ORE rewards, returning the amount to transfer and the referrer's share of the
+    /// claim fee. The referral share is given in bps of the fee and should be zero if this miner
+    /// has no referrer to credit.
+    pub fn claim_ore(
+        &mut self,
+        clock: &Clock,
+        treasury: &mut Treasury,
+        referral_fee: u64,
+    ) -> (u64, u64) {
         self.update_rewards(treasury);
+        self.release_vested_ore(clock, treasury);
         let refined_ore = self.refined_ore;
         let rewards_ore = self.rewards_ore;
         let mut amount = refined_ore + rewards_ore;
@@ -110,16 +173,90 @@ impl Miner {
         treasury.total_refined -= refined_ore;
         self.last_claim_ore_at = clock.unix_timestamp;
 
-        // Charge a 10% fee and share with miners who haven't claimed yet.
+        // Charge a 10% fee and share with the referrer and miners who haven't claimed yet.
+        let mut referral_reward = 0;
         if treasury.total_unclaimed > 0 {
             let fee = rewards_ore / 10;
+            referral_reward = fee * referral_fee.min(DENOMINATOR_BPS) / DENOMINATOR_BPS;
             amount -= fee;
-            treasury.miner_rewards_factor += Numeric::from_fraction(fee, treasury.total_unclaimed);
+            treasury.miner_rewards_factor +=
+                Numeric::from_fraction(fee - referral_reward, treasury.total_unclaimed);
             treasury.total_refined += fee;
             self.lifetime_rewards_ore -= fee;
         }
 
-        amount
+        (amount, referral_reward)
+    }
+
+    /// Locks boosted ORE rewards to vest linearly over `period` seconds. The unlock time of an
+    /// existing vesting balance is averaged with the new one, weighted by amount.
+    pub fn vest_ore(&mut self, amount: u64, period: u64, clock: &Clock, treasury: &mut Treasury) {
+        self.release_vested_ore(clock, treasury);
+        if amount == 0 {
+            return;
+        }
+        let now = clock.unix_timestamp;
+        let remaining = (self.vesting_end_at - now).max(0) as u128;
+        let total = self.vesting_ore + amount;
+        let duration = (remaining * self.vesting_ore as u128 + period as u128 * amount as u128)
+            / total as u128;
+        self.vesting_ore = total;
+        self.vesting_updated_at = now;
+        self.vesting_end_at = now + duration as i64;
+        self.release_vested_ore(clock, treasury);
+    }
+
+    /// The amount of the vesting balance that has vested by a given time.
+    pub fn vested_ore(&self, now: i64) -> u64 {
+        if now >= self.vesting_end_at {
+            return self.vesting_ore;
+        }
+        let elapsed = (now - self.vesting_updated_at).max(0) as u128;
+        let duration = (self.vesting_end_at - self.vesting_updated_at) as u128;
+        (self.vesting_ore as u128 * elapsed / duration) as u64
+    }
+
+    /// Moves vested ORE into the claimable rewards, where it is subject to the same claim fee
+    /// as the base rewards. Must be called after update_rewards.
+    pub fn release_vested_ore(&mut self, clock: &Clock, treasury: &mut Treasury) -> u64 {
+        let now = clock.unix_timestamp;
+        let vested = self.vested_ore(now);
+        self.vesting_ore -= vested;
+        self.vesting_updated_at = now;
+        self.rewards_ore += vested;
+        treasury.total_unclaimed += vested;
+        vested
+    }
+
+    /// Credits this miner with ORE earned from a referee's claim fee.
+    pub fn accrue_referral(&mut self, amount: u64) {
+        self.refined_ore += amount;
+        self.referral_rewards_ore += amount;
+        self.lifetime_rewards_ore += amount;
+    }
+
+    /// Whether this miner was referred by another miner.
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+
+    /// Whether this miner's rewards are claimed to an address other than its authority.
+    pub fn has_claim_recipient(&self) -> bool {
+        self.claim_recipient != Pubkey::default()
+    }
+
+    /// The address this miner's rewards are claimed to.
+    pub fn recipient(&self) -> Pubkey {
+        if self.has_claim_recipient() {
+            self.claim_recipient
+        } else {
+            self.authority
+        }
+    }
+
+    /// Whether this miner is a member of a guild.
+    pub fn has_guild(&self) -> bool {
+        self.guild != Pubkey::default()
     }
 
     pub fn claim_sol(&mut self, clock: &Clock) -> u64 {
@@ -247,25 +384,42 @@ impl Miner {
         self.commit_round_id == round_id && self.revealed_square != Self::NO_REVEAL
     }
 
-    /// Verify commitment hash matches the revealed values.
-    pub fn verify_commitment(&self, square: u8, salt: &[u8; 16]) -> bool {
+    /// Compute the commitment hash: keccak256(square || salt || authority).
+    pub fn compute_commitment(square: u8, salt: &[u8; 16], authority: &Pubkey) -> [u8; 32] {
         use solana_program::keccak;
 
         let mut data = Vec::with_capacity(1 + 16 + 32);
         data.push(square);
         data.extend_from_slice(salt);
-        data.extend_from_slice(self.authority.as_ref());
+        data.extend_from_slice(authority.as_ref());
 
-        let hash = keccak::hash(&data);
-        hash.to_bytes() == self.commitment
+        keccak::hash(&data).to_bytes()
+    }
+
+    /// Verify commitment hash matches the revealed values.
+    pub fn verify_commitment(&self, square: u8, salt: &[u8; 16]) -> bool {
+        Self::compute_commitment(square, salt, &self.authority) == self.commitment
+    }
+
+    /// Get the square this miner deployed the most SOL to in its current round.
+    /// Ties are broken toward the lower index.
+    pub fn max_deployed_square(&self) -> u8 {
+        self.deployed
+            .iter()
+            .enumerate()
+            .max_by(|(i1, v1), (i2, v2)| v1.cmp(v2).then_with(|| i2.cmp(i1)))
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
     }
 
     /// Calculate total multiplier including skill, contrarian, and bonus square.
-    /// Returns value in basis points (10000 = 1.0x, 15000 = 1.5x, etc).
+    /// `bonus_multiplier` applies when the winning square is a bonus square (200 = 2x).
+    /// Returns value where 100 = 1.0x, 150 = 1.5x, etc.
     pub fn calculate_total_multiplier(
         &self,
         winning_square: u8,
         round: &super::Round,
+        bonus_multiplier: u64,
     ) -> u64 {
         // 1. Base skill multiplier (100-150)
         let skill_mult = self.calculate_skill_multiplier();
@@ -273,15 +427,15 @@ impl Miner {
         // 2. Contrarian bonus (100-148)
         let contrarian_mult = round.calculate_contrarian_bonus(winning_square);
 
-        // 3. Bonus square multiplier (100 or 200)
+        // 3. Bonus square multiplier (100, or bonus_multiplier on a bonus square)
         let bonus_mult = if round.is_bonus_square(winning_square) {
-            200 // 2x for bonus square
+            bonus_multiplier
         } else {
             100
         };
 
         // Combined: (skill * contrarian * bonus) / 10000
-        // Max theoretical: 150 * 148 * 200 / 10000 = 444 (4.44x)
+        // Max with the default 2x bonus: 150 * 148 * 200 / 10000 = 444 (4.44x)
         (skill_mult * contrarian_mult * bonus_mult) / 10000
     }
 
@@ -294,3 +448,135 @@ impl Miner {
 }
 
 account!(OreAccount, Miner);
+
+#[cfg(test)]
+mod tests {
+    use crate::consts::LEGACY_MAX_SQUARES;
+
+    use super::*;
+
+    fn clock(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_migrate_legacy() {
+        let old_len = Miner::LEGACY_LEN;
+        let mut data = vec![0; 8 + std::mem::size_of::<Miner>()];
+        data[8..40].copy_from_slice(&[1; 32]);
+        for i in 0..LEGACY_MAX_SQUARES {
+            data[40 + 8 * i..48 + 8 * i].copy_from_slice(&(100 + i as u64).to_le_bytes());
+            data[240 + 8 * i..248 + 8 * i].copy_from_slice(&(200 + i as u64).to_le_bytes());
+        }
+        data[440..448].copy_from_slice(&55u64.to_le_bytes());
+        data[old_len - 8..old_len].copy_from_slice(&7u64.to_le_bytes());
+
+        Miner::migrate_legacy(&mut data, old_len);
+        let miner = bytemuck::pod_read_unaligned::<Miner>(&data[8..]);
+        assert_eq!(miner.authority, Pubkey::new_from_array([1; 32]));
+        for i in 0..MAX_SQUARES {
+            let legacy = i < LEGACY_MAX_SQUARES;
+            assert_eq!(miner.deployed[i], if legacy { 100 + i as u64 } else { 0 });
+            assert_eq!(miner.cumulative[i], if legacy { 200 + i as u64 } else { 0 });
+        }
+        assert_eq!(miner.checkpoint_fee, 55);
+        assert_eq!(miner.board_id, 7);
+    }
+
+    #[test]
+    fn test_migrate_before_guilds() {
+        let mut miner = Miner::zeroed();
+        miner.deployed[MAX_SQUARES - 1] = 5;
+        miner.board_id = 3;
+        miner.guild = Pubkey::new_from_array([2; 32]);
+        let mut data = [&[0; 8], bytemuck::bytes_of(&miner)].concat();
+        let old_len = 8 + std::mem::offset_of!(Miner, guild);
+        Miner::migrate_legacy(&mut data, old_len);
+        let migrated = bytemuck::pod_read_unaligned::<Miner>(&data[8..]);
+        assert_eq!(migrated.deployed[MAX_SQUARES - 1], 5);
+        assert_eq!(migrated.board_id, 3);
+        assert_eq!(migrated.guild, Pubkey::default());
+    }
+
+    #[test]
+    fn test_claim_ore_referral_fee() {
+        let mut treasury = Treasury::zeroed();
+        treasury.total_unclaimed = 2_024;
+        let mut miner = Miner::zeroed();
+        miner.rewards_ore = 1_000;
+        miner.lifetime_rewards_ore = 1_000;
+        let mut other = Miner::zeroed();
+        other.rewards_ore = 1_024;
+
+        // The referrer takes 36% of the 10% fee, the rest goes to miners who haven't claimed.
+        let (amount, referral_reward) = miner.claim_ore(&clock(0), &mut treasury, 3_600);
+        assert_eq!(amount, 900);
+        assert_eq!(referral_reward, 36);
+        assert_eq!(miner.lifetime_rewards_ore, 900);
+        assert_eq!(treasury.total_unclaimed, 1_024);
+        other.update_rewards(&treasury);
+        assert_eq!(other.refined_ore, 64);
+
+        // The last miner to claim pays no fee.
+        let (amount, referral_reward) = other.claim_ore(&clock(0), &mut treasury, 3_600);
+        assert_eq!(amount, 1_088);
+        assert_eq!(referral_reward, 0);
+    }
+
+    #[test]
+    fn test_claim_ore_referral_fee_capped() {
+        let mut treasury = Treasury::zeroed();
+        treasury.total_unclaimed = 2_000;
+        let mut miner = Miner::zeroed();
+        miner.rewards_ore = 1_000;
+        miner.lifetime_rewards_ore = 1_000;
+
+        // The referral share cannot exceed the fee.
+        let (amount, referral_reward) =
+            miner.claim_ore(&clock(0), &mut treasury, DENOMINATOR_BPS * 2);
+        assert_eq!(amount, 900);
+        assert_eq!(referral_reward, 100);
+        assert!(treasury.miner_rewards_factor == Numeric::ZERO);
+    }
+
+    #[test]
+    fn test_recipient() {
+        let mut miner = Miner::zeroed();
+        miner.authority = Pubkey::new_unique();
+        assert!(!miner.has_claim_recipient());
+        assert_eq!(miner.recipient(), miner.authority);
+
+        miner.claim_recipient = Pubkey::new_unique();
+        assert!(miner.has_claim_recipient());
+        assert_eq!(miner.recipient(), miner.claim_recipient);
+    }
+
+    #[test]
+    fn test_vest_ore() {
+        let mut miner = Miner::zeroed();
+        let mut treasury = Treasury::zeroed();
+
+        // Nothing vests immediately, then linearly over the period.
+        miner.vest_ore(1_000, 100, &clock(0), &mut treasury);
+        assert_eq!(miner.rewards_ore, 0);
+        assert_eq!(miner.release_vested_ore(&clock(25), &mut treasury), 250);
+        assert_eq!(miner.release_vested_ore(&clock(50), &mut treasury), 250);
+        assert_eq!(treasury.total_unclaimed, 500);
+
+        // New rewards average the unlock time: 500 left over 50s and 500 over 100s.
+        miner.vest_ore(500, 100, &clock(50), &mut treasury);
+        assert_eq!(miner.vesting_end_at, 125);
+        assert_eq!(miner.release_vested_ore(&clock(200), &mut treasury), 1_000);
+        assert_eq!(miner.vesting_ore, 0);
+        assert_eq!(miner.rewards_ore, 1_500);
+
+        // Without a vesting period rewards are released right away.
+        miner.vest_ore(100, 0, &clock(300), &mut treasury);
+        assert_eq!(miner.vesting_ore, 0);
+        assert_eq!(miner.rewards_ore, 1_600);
+        assert_eq!(treasury.total_unclaimed, 1_600);
+    }
+}
@@ -1,17 +1,29 @@
+mod achievements;
 mod automation;
 mod board;
+mod change;
 mod config;
+mod guild;
 mod miner;
 mod round;
+mod season;
+mod season_miner;
 mod stake;
+mod tournament;
 mod treasury;
 
+pub use achievements::*;
 pub use automation::*;
 pub use board::*;
+pub use change::*;
 pub use config::*;
+pub use guild::*;
 pub use miner::*;
 pub use round::*;
+pub use season::*;
+pub use season_miner::*;
 pub use stake::*;
+pub use tournament::*;
 pub use treasury::*;
 
 use crate::consts::*;
@@ -28,32 +40,97 @@ pub enum OreAccount {
     Board = 105,
     Stake = 108,
     Round = 109,
+    Change = 110,
+    Season = 111,
+    SeasonMiner = 112,
+    Guild = 113,
+    Tournament = 114,
+    Achievements = 115,
+}
+
+pub fn achievements_pda(authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ACHIEVEMENTS, &authority.to_bytes()], &crate::ID)
 }
 
 pub fn automation_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUTOMATION, &authority.to_bytes()], &crate::ID)
 }
 
-pub fn board_pda() -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[BOARD], &crate::ID)
+pub fn board_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&board_seeds(&id.to_le_bytes()), &crate::ID)
+}
+
+/// The seeds of a board account. The main board (id 0) predates multiple boards and keeps its
+/// original seeds.
+pub fn board_seeds(id: &[u8; 8]) -> Vec<&[u8]> {
+    if *id == [0; 8] {
+        vec![BOARD]
+    } else {
+        vec![BOARD, id]
+    }
+}
+
+/// The entropy var a board draws its round randomness from in entropy RNG mode. Each board has
+/// its own var, opened by NewVar with id 0 and the board as its authority.
+pub fn board_var_pda(board_address: Pubkey) -> (Pubkey, u8) {
+    entropy_api::state::var_pda(board_address, 0)
+}
+
+pub fn change_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CHANGE, &id.to_le_bytes()], &crate::ID)
 }
 
 pub fn config_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[CONFIG], &crate::ID)
 }
 
+pub fn guild_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GUILD, &id.to_le_bytes()], &crate::ID)
+}
+
 pub fn miner_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[MINER, &authority.to_bytes()], &crate::ID)
 }
 
-pub fn round_pda(id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[ROUND, &id.to_le_bytes()], &crate::ID)
+pub fn round_pda(board_id: u64, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &round_seeds(&board_id.to_le_bytes(), &id.to_le_bytes()),
+        &crate::ID,
+    )
+}
+
+/// The seeds of a round account. Rounds of the main board keep their original seeds.
+pub fn round_seeds<'a>(board_id: &'a [u8; 8], id: &'a [u8; 8]) -> Vec<&'a [u8]> {
+    if *board_id == [0; 8] {
+        vec![ROUND, id]
+    } else {
+        vec![ROUND, board_id, id]
+    }
+}
+
+pub fn season_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEASON, &id.to_le_bytes()], &crate::ID)
+}
+
+pub fn season_miner_pda(season_id: u64, authority: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEASON_MINER,
+            &season_id.to_le_bytes(),
+            &authority.to_bytes(),
+        ],
+        &crate::ID,
+    )
 }
 
 pub fn stake_pda(authority: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[STAKE, &authority.to_bytes()], &crate::ID)
 }
 
+pub fn tournament_pda(id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TOURNAMENT, &id.to_le_bytes()], &crate::ID)
+}
+
 pub fn treasury_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[TREASURY], &crate::ID)
 }
@@ -62,6 +139,34 @@ pub fn mint_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[MINT], &crate::ID)
 }
 
-pub fn treasury_tokens_address() -> Pubkey {
-    spl_associated_token_account::get_associated_token_address(&TREASURY_ADDRESS, &MINT_ADDRESS)
+/// The treasury's SKILL token account, given the token program that owns the mint.
+pub fn treasury_tokens_address(token_program: &Pubkey) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address_with_program_id(
+        &TREASURY_ADDRESS,
+        &MINT_ADDRESS,
+        token_program,
+    )
+}
+
+/// Moves account data from a layout whose square arrays have LEGACY_MAX_SQUARES entries to the
+/// current layout. `data` is the resized account data, of which the first `old_len` bytes hold
+/// the legacy account, and `offsets` are the legacy offsets of its square arrays in ascending
+/// order. Arrays past `old_len` did not exist yet. New entries and fields are zeroed.
+pub(crate) fn widen_square_arrays(data: &mut [u8], old_len: usize, offsets: &[usize]) {
+    let legacy_len = LEGACY_MAX_SQUARES * 8;
+    let growth = (MAX_SQUARES - LEGACY_MAX_SQUARES) * 8;
+    let count = offsets
+        .iter()
+        .take_while(|&&offset| offset + legacy_len <= old_len)
+        .count();
+
+    // Shift the data after each array right, starting from the end, and zero the new entries.
+    let mut end = old_len;
+    for (i, &offset) in offsets[..count].iter().enumerate().rev() {
+        let start = offset + legacy_len;
+        data.copy_within(start..end, start + (i + 1) * growth);
+        data[start + i * growth..start + (i + 1) * growth].fill(0);
+        end = start;
+    }
+    data[old_len + count * growth..].fill(0);
 }
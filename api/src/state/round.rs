@@ -1,7 +1,11 @@
+use entropy_api::state::Var;
 use serde::{Deserialize, Serialize};
 use steel::*;
 
-use crate::state::round_pda;
+use crate::{
+    consts::{LEGACY_MAX_SQUARES, MAX_BONUS_SQUARES, MAX_SQUARES, NO_BONUS_SQUARE},
+    state::{round_pda, widen_square_arrays},
+};
 
 use super::OreAccount;
 
@@ -12,14 +16,14 @@ pub struct Round {
     pub id: u64,
 
     /// The amount of SOL deployed in each square.
-    pub deployed: [u64; 25],
+    pub deployed: [u64; MAX_SQUARES],
 
     /// The hash of the end slot from Solana, used for RNG (split, motherlode, top_miner).
     /// Sampled from SlotHashes sysvar at round end for unpredictability.
     pub slot_hash: [u8; 32],
 
     /// The count of miners on each square.
-    pub count: [u64; 25],
+    pub count: [u64; MAX_SQUARES],
 
     /// The slot at which claims for this round account end.
     pub expires_at: u64,
@@ -45,16 +49,13 @@ pub struct Round {
     /// The total amount of SOL won by miners for the round.
     pub total_winnings: u64,
 
-    /// The winning square index (0-24) determined by Schelling Point (argmax).
+    /// The winning square index determined by Schelling Point (argmax).
     /// Stored directly to avoid square 0 bug with slot_hash == [0;32].
     pub winning_square: u8,
 
-    /// Bonus squares for this round (RNG from previous round's slot_hash).
-    /// 3 bonus squares that provide 2x multiplier if they win.
-    pub bonus_squares: [u8; 3],
-
-    /// Padding for alignment (4 bytes to align to 8-byte boundary).
-    pub _padding: [u8; 4],
+    /// Bonus squares for this round (RNG from previous round's slot_hash), which multiply
+    /// rewards if they win. Unused entries are NO_BONUS_SQUARE.
+    pub bonus_squares: [u8; MAX_BONUS_SQUARES],
 
     // ============ v0.6 Commit-Reveal Fields ============
 
@@ -65,15 +66,63 @@ pub struct Round {
     pub reveal_start_slot: u64,
 
     /// Count of revealed choices per square (for popularity/contrarian calculation).
-    pub revealed_count: [u64; 25],
+    pub revealed_count: [u64; MAX_SQUARES],
 
     /// Total number of reveals submitted.
     pub total_reveals: u64,
+
+    /// The season this round counts toward, plus one. Zero if the round is not part of a
+    /// season.
+    pub season: u64,
 }
 
 impl Round {
-    pub fn pda(&self) -> (Pubkey, u8) {
-        round_pda(self.id)
+    /// The offsets of `deployed`, `count` and `revealed_count` in round account data with
+    /// LEGACY_MAX_SQUARES squares.
+    const LEGACY_SQUARE_ARRAYS: [usize; 3] = [16, 248, 584];
+
+    /// The length of round account data in the last layout with LEGACY_MAX_SQUARES squares.
+    const LEGACY_LEN: usize = 792;
+
+    /// The offset of `bonus_squares` in legacy round account data. Rounds from before v0.6 end
+    /// here.
+    const LEGACY_BONUS_SQUARES: usize = 561;
+
+    pub fn pda(&self, board_id: u64) -> (Pubkey, u8) {
+        round_pda(board_id, self.id)
+    }
+
+    /// Migrate round account data resized from `old_len` bytes to the current layout. Rounds from
+    /// before 6x6 boards have their square arrays widened, and those from before v0.6 get no
+    /// bonus squares. Fields added since are zeroed.
+    pub fn migrate_legacy(data: &mut [u8], old_len: usize) {
+        if old_len > Self::LEGACY_LEN {
+            data[old_len..].fill(0);
+            return;
+        }
+        widen_square_arrays(data, old_len, &Self::LEGACY_SQUARE_ARRAYS);
+        if old_len <= Self::LEGACY_BONUS_SQUARES {
+            let growth = (MAX_SQUARES - LEGACY_MAX_SQUARES) * 8;
+            let offset = Self::LEGACY_BONUS_SQUARES + 2 * growth;
+            data[offset..offset + MAX_BONUS_SQUARES].fill(NO_BONUS_SQUARE);
+        }
+    }
+
+    /// Read round account data in the current or the legacy layout, without migrating it.
+    pub fn read_any_layout(data: &[u8]) -> Self {
+        let size = 8 + std::mem::size_of::<Self>();
+        let mut buf = vec![0; size];
+        let len = data.len().min(size);
+        buf[..len].copy_from_slice(&data[..len]);
+        if len < size {
+            Self::migrate_legacy(&mut buf, len);
+        }
+        bytemuck::pod_read_unaligned(&buf[8..])
+    }
+
+    /// The id of the season this round counts toward, if any.
+    pub fn season_id(&self) -> Option<u64> {
+        self.season.checked_sub(1)
     }
 
     /// Get the winning square directly (Schelling Point design).
@@ -136,8 +185,25 @@ impl Round {
         r % 2 == 0
     }
 
-    pub fn did_hit_motherlode(&self, rng: u64) -> bool {
-        rng.reverse_bits() % 625 == 0
+    /// Whether the round hit the motherlode, with odds of 1 in `odds`. Zero odds never hit.
+    pub fn did_hit_motherlode(&self, rng: u64, odds: u64) -> bool {
+        odds > 0 && rng.reverse_bits() % odds == 0
+    }
+
+    /// The slot hash an entropy var supplies for a round ending at `end_slot`. The var must
+    /// belong to the round's board (`authority`), have been armed for that end slot, and had its
+    /// sample revealed by the provider. Otherwise returns None, and the reset waits for the var
+    /// until ENTROPY_TIMEOUT_SLOTS have passed.
+    pub fn entropy_slot_hash(var: &Var, authority: &Pubkey, end_slot: u64) -> Option<[u8; 32]> {
+        if var.authority != *authority
+            || var.end_at != end_slot
+            || var.slot_hash == [0; 32]
+            || var.seed == [0; 32]
+            || var.value == [0; 32]
+        {
+            return None;
+        }
+        Some(var.value)
     }
 
     // ============ v0.6 Commit-Reveal Methods ============
@@ -185,30 +251,78 @@ impl Round {
         }
     }
 
-    /// Generate bonus squares from previous round's slot_hash.
-    /// Returns 3 unique bonus squares that give 2x multiplier.
-    pub fn generate_bonus_squares(slot_hash: &[u8; 32]) -> [u8; 3] {
-        let s1 = slot_hash[0] % 25;
-        let mut s2 = slot_hash[8] % 25;
-        let mut s3 = slot_hash[16] % 25;
+    /// Get the indices of a board's squares ordered from most to least SOL deployed.
+    /// Ties are broken toward the lower index, matching the winning square selection.
+    pub fn squares_by_deployed(&self, squares: usize) -> Vec<usize> {
+        let mut order = (0..squares.min(MAX_SQUARES)).collect::<Vec<_>>();
+        order.sort_unstable_by(|&a, &b| {
+            self.deployed[b]
+                .cmp(&self.deployed[a])
+                .then_with(|| a.cmp(&b))
+        });
+        order
+    }
 
-        // Ensure unique squares
-        if s2 == s1 {
-            s2 = (s2 + 1) % 25;
+    /// Generate `count` distinct bonus squares on a board of the given size from the previous
+    /// round's slot_hash, so every square is equally likely to be a bonus square.
+    pub fn generate_bonus_squares(
+        slot_hash: &[u8; 32],
+        squares: u8,
+        count: u64,
+    ) -> [u8; MAX_BONUS_SQUARES] {
+        let count = (count as usize).min(MAX_BONUS_SQUARES);
+        let mut bonus_squares = [NO_BONUS_SQUARE; MAX_BONUS_SQUARES];
+        for (i, square) in Self::sample_squares(slot_hash, squares as usize, count)
+            .into_iter()
+            .enumerate()
+        {
+            bonus_squares[i] = square;
         }
-        if s3 == s1 || s3 == s2 {
-            s3 = (s3 + 1) % 25;
+        bonus_squares
+    }
+
+    /// Draw `count` distinct squares of a board of the given size from `seed`. A partial
+    /// Fisher-Yates shuffle picks each square uniformly from those left.
+    pub fn sample_squares(seed: &[u8; 32], squares: usize, count: usize) -> Vec<u8> {
+        let squares = squares.min(MAX_SQUARES);
+        let count = count.min(squares);
+        let mut deck: [u8; MAX_SQUARES] = core::array::from_fn(|i| i as u8);
+        let mut nonce = 0;
+        for i in 0..count {
+            let j = i + Self::sample_uniform(seed, &mut nonce, (squares - i) as u64) as usize;
+            deck.swap(i, j);
         }
-        if s3 == s1 || s3 == s2 {
-            s3 = (s3 + 1) % 25;
+        deck[..count].to_vec()
+    }
+
+    /// Draw a number uniformly from `0..n` out of a keccak stream over `seed`. Draws from the
+    /// incomplete last block of the u64 range are rejected, so no remainder is favored.
+    fn sample_uniform(seed: &[u8; 32], nonce: &mut u64, n: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % n;
+        loop {
+            let hash = solana_program::keccak::hashv(&[seed, &nonce.to_le_bytes()]).to_bytes();
+            *nonce += 1;
+            let draw = u64::from_le_bytes(hash[0..8].try_into().unwrap());
+            if draw < limit {
+                return draw % n;
+            }
         }
+    }
 
-        [s1, s2, s3]
+    /// The round's bonus squares. Rounds from before the bonus square count was configurable
+    /// hold 3 squares followed by zeroed padding.
+    pub fn active_bonus_squares(&self) -> impl Iterator<Item = u8> + '_ {
+        let legacy = self.bonus_squares[3..] == [0; MAX_BONUS_SQUARES - 3];
+        let len = if legacy { 3 } else { MAX_BONUS_SQUARES };
+        self.bonus_squares[..len]
+            .iter()
+            .copied()
+            .filter(|&s| s != NO_BONUS_SQUARE)
     }
 
     /// Check if a square is a bonus square.
     pub fn is_bonus_square(&self, square: u8) -> bool {
-        self.bonus_squares.contains(&square)
+        self.active_bonus_squares().any(|s| s == square)
     }
 
     /// Calculate contrarian bonus (100-148 range) based on popularity.
@@ -232,7 +346,7 @@ account!(OreAccount, Round);
 
 #[cfg(test)]
 mod tests {
-    use solana_program::rent::Rent;
+    use solana_program::{keccak::hashv, rent::Rent};
 
     use super::*;
 
@@ -243,4 +357,213 @@ mod tests {
         println!("required_rent: {}", required_rent);
         assert!(false);
     }
+
+    /// Round account data in the legacy layout, `old_len` bytes long, resized to the current one.
+    fn legacy_round(old_len: usize) -> Vec<u8> {
+        let mut data = vec![0; 8 + std::mem::size_of::<Round>()];
+        data[8..16].copy_from_slice(&7u64.to_le_bytes());
+        for i in 0..LEGACY_MAX_SQUARES {
+            data[16 + 8 * i..24 + 8 * i].copy_from_slice(&(100 + i as u64).to_le_bytes());
+            data[248 + 8 * i..256 + 8 * i].copy_from_slice(&(200 + i as u64).to_le_bytes());
+        }
+        data[216..248].copy_from_slice(&[9; 32]);
+        data[448..456].copy_from_slice(&77u64.to_le_bytes());
+        if old_len > 560 {
+            data[560] = 4;
+            data[561..568].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+            for i in 0..LEGACY_MAX_SQUARES {
+                data[584 + 8 * i..592 + 8 * i].copy_from_slice(&(300 + i as u64).to_le_bytes());
+            }
+            data[784..792].copy_from_slice(&5u64.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_migrate_legacy() {
+        let mut data = legacy_round(792);
+        Round::migrate_legacy(&mut data, 792);
+        let round = bytemuck::pod_read_unaligned::<Round>(&data[8..]);
+        assert_eq!(round.id, 7);
+        for i in 0..MAX_SQUARES {
+            let legacy = i < LEGACY_MAX_SQUARES;
+            assert_eq!(round.deployed[i], if legacy { 100 + i as u64 } else { 0 });
+            assert_eq!(round.count[i], if legacy { 200 + i as u64 } else { 0 });
+            assert_eq!(
+                round.revealed_count[i],
+                if legacy { 300 + i as u64 } else { 0 }
+            );
+        }
+        assert_eq!(round.slot_hash, [9; 32]);
+        assert_eq!(round.expires_at, 77);
+        assert_eq!(round.winning_square, 4);
+        assert_eq!(round.bonus_squares, [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(round.total_reveals, 5);
+        assert_eq!(Round::read_any_layout(&legacy_round(792)[..792]), round);
+        assert_eq!(Round::read_any_layout(&data), round);
+    }
+
+    #[test]
+    fn test_migrate_legacy_before_bonus_squares() {
+        let mut data = legacy_round(560);
+        Round::migrate_legacy(&mut data, 560);
+        let round = bytemuck::pod_read_unaligned::<Round>(&data[8..]);
+        assert_eq!(round.deployed[LEGACY_MAX_SQUARES - 1], 124);
+        assert_eq!(round.count[LEGACY_MAX_SQUARES - 1], 224);
+        assert_eq!(round.expires_at, 77);
+        assert_eq!(round.bonus_squares, [NO_BONUS_SQUARE; MAX_BONUS_SQUARES]);
+        assert_eq!(round.revealed_count, [0; MAX_SQUARES]);
+        assert_eq!(round.total_reveals, 0);
+    }
+
+    #[test]
+    fn test_migrate_before_seasons() {
+        let mut round = Round::zeroed();
+        round.deployed[MAX_SQUARES - 1] = 5;
+        round.total_reveals = 3;
+        round.season = 2;
+        let mut data = [&[0; 8], bytemuck::bytes_of(&round)].concat();
+        let old_len = data.len() - 8;
+        Round::migrate_legacy(&mut data, old_len);
+        let migrated = bytemuck::pod_read_unaligned::<Round>(&data[8..]);
+        assert_eq!(migrated.deployed[MAX_SQUARES - 1], 5);
+        assert_eq!(migrated.total_reveals, 3);
+        assert_eq!(migrated.season, 0);
+    }
+
+    #[test]
+    fn test_motherlode_odds() {
+        let round = Round::zeroed();
+        assert!(round.did_hit_motherlode(0, 625));
+        assert!(!round.did_hit_motherlode(1u64.reverse_bits(), 625));
+        assert!(round.did_hit_motherlode(12345, 1));
+        assert!(!round.did_hit_motherlode(0, 0));
+    }
+
+    #[test]
+    fn test_motherlode_configured_odds() {
+        // Over `odds * 10` evenly spread draws, exactly 10 hit.
+        let round = Round::zeroed();
+        for odds in [1, 2, 625, 10_000] {
+            let hits = (0..odds * 10)
+                .filter(|&i: &u64| round.did_hit_motherlode(i.reverse_bits(), odds))
+                .count();
+            assert_eq!(hits, 10);
+        }
+        assert!((0..10_000u64).all(|i| !round.did_hit_motherlode(i, 0)));
+    }
+
+    #[test]
+    fn test_squares_by_deployed() {
+        let mut round = Round::zeroed();
+        round.deployed[7] = 300;
+        round.deployed[3] = 100;
+        round.deployed[12] = 300;
+        let order = round.squares_by_deployed(25);
+        assert_eq!(order[0], 7);
+        assert_eq!(order[1], 12);
+        assert_eq!(order[2], 3);
+        assert_eq!(order[3], 0);
+        assert_eq!(order[24], 24);
+    }
+
+    #[test]
+    fn test_squares_by_deployed_small_board() {
+        let mut round = Round::zeroed();
+        round.deployed[5] = 100;
+        let order = round.squares_by_deployed(9);
+        assert_eq!(order.len(), 9);
+        assert_eq!(order[0], 5);
+        assert_eq!(order[8], 8);
+    }
+
+    #[test]
+    fn test_bonus_squares_fit_geometry() {
+        for squares in [4u8, 9, 16, 25, 36] {
+            for count in 0..=MAX_BONUS_SQUARES as u64 + 1 {
+                let expected = count.min(squares as u64).min(MAX_BONUS_SQUARES as u64);
+                for seed in 0..=255u8 {
+                    let mut round = Round::zeroed();
+                    round.bonus_squares =
+                        Round::generate_bonus_squares(&[seed; 32], squares, count);
+                    let bonus = round.active_bonus_squares().collect::<Vec<_>>();
+                    assert_eq!(bonus.len() as u64, expected);
+                    assert!(bonus.iter().all(|&s| s < squares));
+                    for (i, s) in bonus.iter().enumerate() {
+                        assert!(!bonus[i + 1..].contains(s));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_squares() {
+        for count in 0..=MAX_SQUARES + 1 {
+            let seed = hashv(&[&count.to_le_bytes()]).to_bytes();
+            let sample = Round::sample_squares(&seed, MAX_SQUARES, count);
+            assert_eq!(sample.len(), count.min(MAX_SQUARES));
+            assert!(sample.iter().all(|&s| (s as usize) < MAX_SQUARES));
+            for (i, s) in sample.iter().enumerate() {
+                assert!(!sample[i + 1..].contains(s));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bonus_squares_legacy() {
+        let mut round = Round::zeroed();
+        round.bonus_squares = [4, 9, 17, 0, 0, 0, 0];
+        assert_eq!(
+            round.active_bonus_squares().collect::<Vec<_>>(),
+            vec![4, 9, 17]
+        );
+        assert!(!round.is_bonus_square(0));
+        round.bonus_squares = [
+            4,
+            9,
+            17,
+            0,
+            NO_BONUS_SQUARE,
+            NO_BONUS_SQUARE,
+            NO_BONUS_SQUARE,
+        ];
+        assert!(round.is_bonus_square(0));
+    }
+
+    #[test]
+    fn test_bonus_squares_uniform() {
+        // Chi-squared goodness of fit of how often each square is drawn, and how often it is
+        // drawn first, against the uniform distribution. The critical values are those of a
+        // 0.1% significance level.
+        const ROUNDS: u64 = 20_000;
+        for (squares, critical) in [(9u8, 26.12), (25u8, 51.18), (36u8, 66.62)] {
+            let mut any = [0u64; MAX_SQUARES];
+            let mut first = [0u64; MAX_SQUARES];
+            for i in 0..ROUNDS {
+                let slot_hash = hashv(&[&i.to_le_bytes()]).to_bytes();
+                let bonus = Round::generate_bonus_squares(&slot_hash, squares, 3);
+                first[bonus[0] as usize] += 1;
+                for &s in &bonus[..3] {
+                    any[s as usize] += 1;
+                }
+            }
+            let chi_squared = |counts: &[u64], expected: f64| {
+                counts
+                    .iter()
+                    .map(|&c| (c as f64 - expected).powi(2) / expected)
+                    .sum::<f64>()
+            };
+            let n = squares as usize;
+            let any_chi = chi_squared(&any[..n], (ROUNDS * 3) as f64 / n as f64);
+            let first_chi = chi_squared(&first[..n], ROUNDS as f64 / n as f64);
+            assert!(any_chi < critical, "squares {}: chi^2 {}", squares, any_chi);
+            assert!(
+                first_chi < critical,
+                "squares {}: chi^2 {}",
+                squares,
+                first_chi
+            );
+        }
+    }
 }
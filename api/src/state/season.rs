@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::{consts::SEASON_WINNERS, state::season_pda};
+
+use super::OreAccount;
+
+/// A competitive season over a range of rounds on one board. Miners earn points for each round
+/// they win, and the top miners share the season's prize pool once the season is over.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Season {
+    /// The id of this season.
+    pub id: u64,
+
+    /// The board the season is played on.
+    pub board_id: u64,
+
+    /// The first round of the season.
+    pub start_round: u64,
+
+    /// The last round of the season.
+    pub end_round: u64,
+
+    /// The amount of SOL shared by the top miners at the end of the season.
+    pub prize: u64,
+
+    /// Bitmask of leaderboard positions that have claimed their share of the prize.
+    pub claimed: u64,
+
+    /// The miners with the most points, in no particular order.
+    pub leaders: [Pubkey; SEASON_WINNERS],
+
+    /// The points of each leader.
+    pub leader_points: [u64; SEASON_WINNERS],
+}
+
+impl Season {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        season_pda(self.id)
+    }
+
+    /// Whether a round of a board counts toward this season.
+    pub fn includes(&self, board_id: u64, round_id: u64) -> bool {
+        self.board_id == board_id && round_id >= self.start_round && round_id <= self.end_round
+    }
+
+    /// Records a miner's season points on the leaderboard, replacing the lowest leader if the
+    /// miner is not already on it. A leader's points are never lowered.
+    pub fn record(&mut self, authority: Pubkey, points: u64) {
+        let i = match self.leaders.iter().position(|l| *l == authority) {
+            Some(i) => i,
+            None => {
+                let (i, &lowest) = self
+                    .leader_points
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, &p)| p)
+                    .unwrap();
+                if points <= lowest {
+                    return;
+                }
+                self.leaders[i] = authority;
+                i
+            }
+        };
+        self.leader_points[i] = self.leader_points[i].max(points);
+    }
+
+    /// A leader's share of the prize, in proportion to their points.
+    pub fn prize_share(&self, i: usize) -> u64 {
+        let total_points = self.leader_points.iter().sum::<u64>();
+        if total_points == 0 {
+            return 0;
+        }
+        (self.prize as u128 * self.leader_points[i] as u128 / total_points as u128) as u64
+    }
+}
+
+account!(OreAccount, Season);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_replaces_lowest_leader() {
+        let mut season = Season::zeroed();
+        for (i, points) in (1..=SEASON_WINNERS as u64).enumerate() {
+            season.leaders[i] = Pubkey::new_unique();
+            season.leader_points[i] = points;
+        }
+        let miner = Pubkey::new_unique();
+        season.record(miner, 1);
+        assert!(!season.leaders.contains(&miner));
+        season.record(miner, 2);
+        assert_eq!(season.leaders[0], miner);
+        assert_eq!(season.leader_points[0], 2);
+    }
+
+    #[test]
+    fn test_record_never_lowers_points() {
+        let mut season = Season::zeroed();
+        let miner = Pubkey::new_unique();
+        season.record(miner, 10);
+        season.record(miner, 4);
+        assert_eq!(season.leader_points[0], 10);
+        season.record(miner, 12);
+        assert_eq!(season.leader_points[0], 12);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::state::season_miner_pda;
+
+use super::OreAccount;
+
+/// A miner's standing in one season. Each season keeps its own points, so seasons running on
+/// different boards at the same time don't reset each other.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct SeasonMiner {
+    /// The season the points belong to.
+    pub season_id: u64,
+
+    /// The authority of the miner.
+    pub authority: Pubkey,
+
+    /// The points the miner has earned in the season.
+    pub points: u64,
+}
+
+impl SeasonMiner {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        season_miner_pda(self.season_id, self.authority)
+    }
+}
+
+account!(OreAccount, SeasonMiner);
@@ -55,10 +55,10 @@ impl Stake {
         amount: u64,
         clock: &Clock,
         treasury: &mut Treasury,
-        sender: &TokenAccount,
+        sender_balance: u64,
     ) -> u64 {
         self.update_rewards(treasury);
-        let amount = sender.amount().min(amount);
+        let amount = sender_balance.min(amount);
         self.balance += amount;
         self.last_deposit_at = clock.unix_timestamp;
         treasury.total_staked += amount;
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+use steel::*;
+
+use crate::{
+    consts::{DENOMINATOR_BPS, MAX_TOURNAMENT_PLAYERS, TOURNAMENT_PAYOUTS},
+    state::tournament_pda,
+};
+
+use super::OreAccount;
+
+/// Pick value of a player who did not reveal in a matchday.
+pub const NO_PICK: u8 = u8::MAX;
+
+/// A head-to-head round-robin tournament played on the rounds of a board. Each matchday is one
+/// board round, in which every player is paired against one opponent. A player hits when their
+/// revealed square is the round's winning square, and beats an opponent who missed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable, Serialize, Deserialize)]
+pub struct Tournament {
+    /// The id of this tournament.
+    pub id: u64,
+
+    /// The board the tournament is played on.
+    pub board_id: u64,
+
+    /// The creator of the tournament, who receives the sponsored prize back if it is cancelled.
+    pub authority: Pubkey,
+
+    /// The board round of the first matchday.
+    pub start_round: u64,
+
+    /// The amount of SOL each player pays to enter.
+    pub entry_fee: u64,
+
+    /// The amount of SOL escrowed for prizes, including entry fees.
+    pub prize: u64,
+
+    /// The size of the roster.
+    pub max_players: u64,
+
+    /// The number of players who have entered.
+    pub player_count: u64,
+
+    /// Bitmask of matchdays that have been scored.
+    pub scored: u64,
+
+    /// Whether the prizes have been paid out.
+    pub settled: u64,
+
+    /// The roster, in order of entry.
+    pub players: [Pubkey; MAX_TOURNAMENT_PLAYERS],
+
+    /// Each player's points: 2 for a win, 1 for a draw.
+    pub points: [u64; MAX_TOURNAMENT_PLAYERS],
+
+    /// The number of matchdays each player hit the winning square.
+    pub hits: [u64; MAX_TOURNAMENT_PLAYERS],
+
+    /// Each player's revealed square per matchday, or NO_PICK.
+    pub picks: [[u8; MAX_TOURNAMENT_PLAYERS]; MAX_TOURNAMENT_PLAYERS],
+}
+
+impl Tournament {
+    pub fn pda(&self) -> (Pubkey, u8) {
+        tournament_pda(self.id)
+    }
+
+    /// Whether a roster size can be scheduled as a round-robin.
+    pub fn is_valid_roster(max_players: u64) -> bool {
+        (2..=MAX_TOURNAMENT_PLAYERS as u64).contains(&max_players) && max_players % 2 == 0
+    }
+
+    /// The number of matchdays, one per board round.
+    pub fn matchdays(&self) -> u64 {
+        self.max_players - 1
+    }
+
+    /// The board round of the tournament's last matchday.
+    pub fn end_round(&self) -> u64 {
+        self.start_round + self.matchdays() - 1
+    }
+
+    /// Whether every player slot has been taken.
+    pub fn is_full(&self) -> bool {
+        self.player_count == self.max_players
+    }
+
+    /// Whether every matchday has been scored.
+    pub fn is_complete(&self) -> bool {
+        self.scored == (1 << self.matchdays()) - 1
+    }
+
+    /// The matchday played in a round of a board, if any.
+    pub fn matchday(&self, board_id: u64, round_id: u64) -> Option<usize> {
+        if board_id != self.board_id || round_id < self.start_round || round_id > self.end_round() {
+            return None;
+        }
+        Some((round_id - self.start_round) as usize)
+    }
+
+    /// The roster position of a player.
+    pub fn player_index(&self, authority: &Pubkey) -> Option<usize> {
+        self.players[..self.player_count as usize]
+            .iter()
+            .position(|p| p == authority)
+    }
+
+    /// A player's opponent on a matchday, using the circle method: the last player stays fixed
+    /// while the others rotate, so everyone meets everyone exactly once.
+    pub fn opponent(&self, player: usize, matchday: usize) -> usize {
+        let n = self.max_players as usize - 1;
+        if player == n {
+            matchday
+        } else if player == matchday {
+            n
+        } else {
+            (2 * matchday + n - player) % n
+        }
+    }
+
+    /// Records a player's revealed square for the matchday played in a round. Returns whether the
+    /// pick was recorded.
+    pub fn record_pick(
+        &mut self,
+        authority: &Pubkey,
+        board_id: u64,
+        round_id: u64,
+        square: u8,
+    ) -> bool {
+        let (Some(player), Some(matchday)) = (
+            self.player_index(authority),
+            self.matchday(board_id, round_id),
+        ) else {
+            return false;
+        };
+        self.picks[player][matchday] = square;
+        true
+    }
+
+    /// Scores a matchday given the round's winning square, or None if the round had no winner.
+    pub fn score(&mut self, matchday: usize, winning_square: Option<u8>) {
+        let players = self.max_players as usize;
+        let hit = |t: &Self, p: usize| winning_square.is_some_and(|w| t.picks[p][matchday] == w);
+        for player in 0..players {
+            let opponent = self.opponent(player, matchday);
+            let (won, lost) = (hit(self, player), hit(self, opponent));
+            if won {
+                self.hits[player] += 1;
+            }
+            self.points[player] += match (won, lost) {
+                (true, false) => 2,
+                (false, true) => 0,
+                _ => 1,
+            };
+        }
+        self.scored |= 1 << matchday;
+    }
+
+    /// Roster positions ranked by points, then hits, then order of entry.
+    pub fn standings(&self) -> Vec<usize> {
+        let mut standings = (0..self.player_count as usize).collect::<Vec<_>>();
+        standings.sort_by_key(|&p| {
+            (
+                std::cmp::Reverse(self.points[p]),
+                std::cmp::Reverse(self.hits[p]),
+            )
+        });
+        standings
+    }
+
+    /// The prize paid to a finishing place (0 for first).
+    pub fn prize_share(&self, place: usize) -> u64 {
+        let places = TOURNAMENT_PAYOUTS.len().min(self.max_players as usize);
+        if place >= places {
+            return 0;
+        }
+        let total = TOURNAMENT_PAYOUTS[..places].iter().sum::<u64>();
+        let share = TOURNAMENT_PAYOUTS[place] * DENOMINATOR_BPS / total;
+        (self.prize as u128 * share as u128 / DENOMINATOR_BPS as u128) as u64
+    }
+}
+
+account!(OreAccount, Tournament);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tournament(max_players: u64) -> Tournament {
+        let mut t = Tournament::zeroed();
+        t.max_players = max_players;
+        t.player_count = max_players;
+        t.picks = [[NO_PICK; MAX_TOURNAMENT_PLAYERS]; MAX_TOURNAMENT_PLAYERS];
+        t
+    }
+
+    #[test]
+    fn test_round_robin_schedule() {
+        for max_players in [2, 4, 6, 8] {
+            let t = tournament(max_players);
+            let n = max_players as usize;
+            let mut met = vec![vec![false; n]; n];
+            for matchday in 0..t.matchdays() as usize {
+                for player in 0..n {
+                    let opponent = t.opponent(player, matchday);
+                    assert_ne!(player, opponent);
+                    assert_eq!(t.opponent(opponent, matchday), player);
+                    assert!(!met[player][opponent]);
+                    met[player][opponent] = true;
+                }
+            }
+            for row in met {
+                assert_eq!(row.iter().filter(|&&m| m).count(), n - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_score_and_payouts() {
+        let mut t = tournament(4);
+        t.prize = 1_000;
+        // Matchday 0 pairs 0 with 3 and 1 with 2.
+        t.picks[0][0] = 5;
+        t.picks[1][0] = 5;
+        t.picks[2][0] = 5;
+        t.score(0, Some(5));
+        assert_eq!(t.points[..4], [2, 1, 1, 0]);
+        assert_eq!(t.hits[..4], [1, 1, 1, 0]);
+        assert_eq!(t.standings()[0], 0);
+        assert!(!t.is_complete());
+
+        // A round without a winner is a draw for everyone.
+        t.score(1, None);
+        t.score(2, None);
+        assert!(t.is_complete());
+        assert_eq!(t.points[..4], [4, 3, 3, 2]);
+
+        let paid = (0..4).map(|p| t.prize_share(p)).sum::<u64>();
+        assert_eq!(t.prize_share(0), 500);
+        assert!(paid <= t.prize);
+        assert_eq!(tournament(2).prize_share(2), 0);
+    }
+}
@@ -11,7 +11,8 @@ pub struct Treasury {
     // The amount of SOL collected for buy-bury operations.
     pub balance: u64,
 
-    /// The amount of ORE in the motherlode rewards pool.
+    /// The amount of ORE left in the shared motherlode rewards pool from before each board had its
+    /// own (see Board::motherlode). It is paid out with the main board's next motherlode.
     pub motherlode: u64,
 
     /// The cumulative ORE distributed to miners, divided by the total unclaimed ORE at the time of distribution.
@@ -0,0 +1,60 @@
+use dioxus::prelude::*;
+use crate::hooks::{use_achievements, ACHIEVEMENT_NAMES};
+
+#[component]
+pub fn Achievements() -> Element {
+    let achievements = use_achievements();
+    let achievements_read = achievements.read();
+
+    rsx! {
+        div { class: "card",
+            h3 { class: "text-lg font-semibold text-skill-400 mb-4", "Achievements" }
+
+            if achievements_read.loading {
+                div { class: "animate-pulse space-y-3",
+                    div { class: "h-4 bg-gray-700 rounded w-3/4" }
+                    div { class: "h-4 bg-gray-700 rounded w-1/2" }
+                }
+            } else if !achievements_read.opened {
+                p { class: "text-gray-500 text-center py-4", "Achievements are not being tracked yet" }
+            } else {
+                div { class: "space-y-3",
+                    for (i, name) in ACHIEVEMENT_NAMES.iter().enumerate() {
+                        AchievementRow {
+                            name: *name,
+                            unlocked: achievements_read.has(i),
+                            round: achievements_read.unlocked_round[i],
+                        }
+                    }
+                    div { class: "flex justify-between items-center",
+                        span { class: "text-gray-500", "Correct reveals" }
+                        span { class: "font-mono text-gray-300", "{achievements_read.correct_reveals}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct AchievementRowProps {
+    name: &'static str,
+    unlocked: bool,
+    round: u64,
+}
+
+#[component]
+fn AchievementRow(props: AchievementRowProps) -> Element {
+    let (name_class, status) = if props.unlocked {
+        ("text-skill-400 font-semibold", format!("Round {}", props.round))
+    } else {
+        ("text-gray-500", "Locked".to_string())
+    };
+
+    rsx! {
+        div { class: "flex justify-between items-center",
+            span { class: "{name_class}", "{props.name}" }
+            span { class: "font-mono text-gray-300", "{status}" }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use crate::hooks::use_automation;
+
+#[component]
+pub fn AutomationStats() -> Element {
+    let automation = use_automation();
+    let automation_read = automation.read();
+
+    rsx! {
+        div { class: "card",
+            h3 { class: "text-lg font-semibold text-skill-400 mb-4", "Automation" }
+
+            if automation_read.loading {
+                div { class: "animate-pulse space-y-3",
+                    div { class: "h-4 bg-gray-700 rounded w-3/4" }
+                    div { class: "h-4 bg-gray-700 rounded w-1/2" }
+                }
+            } else if !automation_read.active {
+                p { class: "text-gray-500 text-center py-4", "No automation configured" }
+            } else {
+                div { class: "space-y-3",
+                    AutomationRow {
+                        label: "Strategy",
+                        value: automation_read.strategy.describe(automation_read.mask),
+                    }
+                    AutomationRow {
+                        label: "Per square",
+                        value: format!("{:.4} SOL", automation_read.amount_sol()),
+                    }
+                    AutomationRow {
+                        label: "Balance",
+                        value: format!("{:.4} SOL", automation_read.balance_sol()),
+                    }
+                    AutomationRow {
+                        label: "Auto-reload",
+                        value: if automation_read.reload { "On".to_string() } else { "Off".to_string() },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct AutomationRowProps {
+    label: &'static str,
+    value: String,
+}
+
+#[component]
+fn AutomationRow(props: AutomationRowProps) -> Element {
+    rsx! {
+        div { class: "flex justify-between items-center",
+            span { class: "text-gray-500", "{props.label}" }
+            span { class: "font-mono text-gray-300", "{props.value}" }
+        }
+    }
+}
@@ -11,6 +11,9 @@ pub struct BoardProps {
     /// Winning square (if round ended)
     #[props(default)]
     pub winning_square: Option<u8>,
+    /// Number of squares on the board, laid out as an N×N grid
+    #[props(default = 25)]
+    pub squares: u8,
     /// SOL deployed per square (in lamports)
     #[props(default)]
     pub deployed: [u64; 25],
@@ -29,9 +32,9 @@ pub struct BoardProps {
     /// Current round phase for animations
     #[props(default)]
     pub phase: RoundPhase,
-    /// Bonus squares (highlighted with star)
+    /// Bonus squares (highlighted with star), unused entries are 255
     #[props(default)]
-    pub bonus_squares: [u8; 3],
+    pub bonus_squares: [u8; 7],
 }
 
 #[component]
@@ -45,11 +48,16 @@ pub fn Board(props: BoardProps) -> Element {
         .map(|(i, &v)| (i as u8, v))
         .unwrap_or((0, 0));
 
+    // Side length of the N×N grid
+    let side = (1..=props.squares).take_while(|s| s * s <= props.squares).last().unwrap_or(1);
+
     rsx! {
         div { class: "space-y-3",
-            // 5x5 Grid - ORE style with heat map
-            div { class: "grid grid-cols-5 gap-1.5",
-                for i in 0..25u8 {
+            // N×N Grid - ORE style with heat map
+            div {
+                class: "grid gap-1.5",
+                style: "grid-template-columns: repeat({side}, minmax(0, 1fr));",
+                for i in 0..props.squares {
                     Square {
                         index: i,
                         selected: props.selected.contains(&i),
@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use crate::hooks::use_guild;
+
+#[component]
+pub fn GuildStats() -> Element {
+    let guild = use_guild();
+    let guild_read = guild.read();
+
+    let win_rate = if guild_read.rounds > 0 {
+        (guild_read.wins as f64 / guild_read.rounds as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    rsx! {
+        div { class: "card",
+            h3 { class: "text-lg font-semibold text-skill-400 mb-4", "Guild" }
+
+            if guild_read.loading {
+                div { class: "animate-pulse space-y-3",
+                    div { class: "h-4 bg-gray-700 rounded w-3/4" }
+                    div { class: "h-4 bg-gray-700 rounded w-1/2" }
+                }
+            } else if !guild_read.member {
+                p { class: "text-gray-500 text-center py-4", "Not in a guild" }
+            } else {
+                div { class: "space-y-3",
+                    GuildRow {
+                        label: "Name",
+                        value: guild_read.name.clone(),
+                    }
+                    GuildRow {
+                        label: "Members",
+                        value: format!("{}", guild_read.member_count),
+                    }
+                    GuildRow {
+                        label: "Revenue share",
+                        value: format!("{:.2}%", guild_read.revenue_share as f64 / 100.0),
+                    }
+                    GuildRow {
+                        label: "Wins",
+                        value: format!("{}/{} ({:.1}%)", guild_read.wins, guild_read.rounds, win_rate),
+                    }
+                    GuildRow {
+                        label: "Skill points",
+                        value: format!("{}", guild_read.skill_points),
+                    }
+                    GuildRow {
+                        label: "Volume",
+                        value: format!("{:.4} SOL", guild_read.volume_sol()),
+                    }
+                    GuildRow {
+                        label: "Treasury",
+                        value: format!("{:.4} SOL", guild_read.balance_sol()),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct GuildRowProps {
+    label: &'static str,
+    value: String,
+}
+
+#[component]
+fn GuildRow(props: GuildRowProps) -> Element {
+    rsx! {
+        div { class: "flex justify-between items-center",
+            span { class: "text-gray-500", "{props.label}" }
+            span { class: "font-mono text-gray-300", "{props.value}" }
+        }
+    }
+}
@@ -1,10 +1,16 @@
 mod layout;
 mod board;
 mod skill_stats;
+mod automation_stats;
+mod guild_stats;
+mod achievements;
 mod wallet_button;
 
 pub use layout::Layout;
 pub use board::Board;
 pub use skill_stats::SkillStats;
+pub use automation_stats::AutomationStats;
+pub use guild_stats::GuildStats;
+pub use achievements::Achievements;
 pub use wallet_button::WalletButton;
 pub use wallet_button::sign_and_send_transaction;
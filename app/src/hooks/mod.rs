@@ -2,10 +2,16 @@ mod use_board;
 mod use_miner;
 mod use_leaderboard;
 mod use_deploy;
+mod use_automation;
+mod use_guild;
+mod use_achievements;
 mod rpc;
 
 pub use use_board::use_board;
 pub use use_miner::use_miner;
 pub use use_leaderboard::use_leaderboard;
+pub use use_automation::use_automation;
+pub use use_guild::use_guild;
+pub use use_achievements::{use_achievements, ACHIEVEMENT_NAMES};
 pub use use_deploy::{deploy_transaction, play_transaction, check_round_needs_reset, claim_sol_transaction, claim_ore_transaction};
 pub use rpc::*;
@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+
+use crate::{WalletState, PROGRAM_ID, RPC_URL};
+use super::rpc::{derive_pda, fetch_account};
+
+/// Achievement names, indexed by bit (matching api/src/state/achievements.rs).
+pub const ACHIEVEMENT_NAMES: [&str; 5] = [
+    "First win",
+    "10-round streak",
+    "100 correct reveals",
+    "Contrarian winner",
+    "Motherlode",
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AchievementsState {
+    pub opened: bool,
+    pub unlocked: u64,
+    pub correct_reveals: u64,
+    pub unlocked_round: [u64; 16],
+    pub loading: bool,
+}
+
+impl AchievementsState {
+    pub fn has(&self, index: usize) -> bool {
+        self.unlocked & (1 << index) != 0
+    }
+}
+
+pub fn use_achievements() -> Signal<AchievementsState> {
+    let wallet = use_context::<Signal<WalletState>>();
+    let mut state = use_signal(|| AchievementsState {
+        loading: true,
+        ..Default::default()
+    });
+
+    let _resource = use_resource(move || {
+        let pubkey = wallet.read().pubkey.clone();
+        async move {
+            let Some(authority) = pubkey else {
+                state.write().loading = false;
+                return;
+            };
+            match fetch_achievements(&authority).await {
+                Ok(achievements) => state.set(achievements),
+                Err(e) => {
+                    tracing::error!("Failed to fetch achievements: {}", e);
+                    state.write().loading = false;
+                }
+            }
+        }
+    });
+
+    state
+}
+
+fn achievements_pda(authority: &str) -> String {
+    let auth_bytes = bs58::decode(authority).into_vec().unwrap_or_default();
+    derive_pda(&[b"achievements", &auth_bytes], PROGRAM_ID)
+}
+
+async fn fetch_achievements(authority: &str) -> Result<AchievementsState, String> {
+    let pda = achievements_pda(authority);
+    let data = fetch_account(RPC_URL, &pda).await?;
+
+    // Achievements layout (after 8-byte discriminator):
+    // authority: Pubkey (32) - offset 8
+    // unlocked: u64 - offset 40
+    // correct_reveals: u64 - offset 48
+    // unlocked_round: [u64; 16] - offset 56
+    let Some(bytes) = data else {
+        return Ok(AchievementsState::default());
+    };
+    if bytes.len() < 184 {
+        return Ok(AchievementsState::default());
+    }
+
+    let read_u64 = |offset: usize| {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap_or_default())
+    };
+    let mut unlocked_round = [0u64; 16];
+    for (i, round) in unlocked_round.iter_mut().enumerate() {
+        *round = read_u64(56 + i * 8);
+    }
+
+    Ok(AchievementsState {
+        opened: true,
+        unlocked: read_u64(40),
+        correct_reveals: read_u64(48),
+        unlocked_round,
+        loading: false,
+    })
+}
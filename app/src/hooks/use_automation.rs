@@ -0,0 +1,139 @@
+use dioxus::prelude::*;
+
+use crate::{WalletState, PROGRAM_ID, RPC_URL};
+use super::rpc::{derive_pda, fetch_account};
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Automation strategies (matching api/src/state/automation.rs).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AutomationStrategy {
+    #[default]
+    Random,
+    Preferred,
+    FollowCrowd,
+    TopK,
+    Bonus,
+    Contrarian,
+    Unknown(u64),
+}
+
+impl AutomationStrategy {
+    pub fn from_u64(value: u64) -> Self {
+        match value {
+            0 => Self::Random,
+            1 => Self::Preferred,
+            2 => Self::FollowCrowd,
+            3 => Self::TopK,
+            4 => Self::Bonus,
+            5 => Self::Contrarian,
+            v => Self::Unknown(v),
+        }
+    }
+
+    /// Human readable description of the strategy, given the automation mask.
+    pub fn describe(&self, mask: u64) -> String {
+        let count = mask & 0xFF;
+        match self {
+            Self::Random => format!("Random ({} squares)", count),
+            Self::Preferred => format!("Preferred ({} squares)", mask.count_ones()),
+            Self::FollowCrowd => "Follow the crowd".to_string(),
+            Self::TopK => format!("Top {} squares", count),
+            Self::Bonus => "Bonus squares".to_string(),
+            Self::Contrarian => format!("Contrarian ({} squares)", count),
+            Self::Unknown(v) => format!("Unknown ({})", v),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AutomationState {
+    pub active: bool,
+    pub amount: u64,
+    pub balance: u64,
+    pub executor: String,
+    pub fee: u64,
+    pub strategy: AutomationStrategy,
+    pub mask: u64,
+    pub reload: bool,
+    pub loading: bool,
+}
+
+impl AutomationState {
+    pub fn balance_sol(&self) -> f64 {
+        self.balance as f64 / LAMPORTS_PER_SOL
+    }
+
+    pub fn amount_sol(&self) -> f64 {
+        self.amount as f64 / LAMPORTS_PER_SOL
+    }
+}
+
+pub fn use_automation() -> Signal<AutomationState> {
+    let wallet = use_context::<Signal<WalletState>>();
+    let mut state = use_signal(|| AutomationState {
+        loading: true,
+        ..Default::default()
+    });
+
+    let _resource = use_resource(move || {
+        let pubkey = wallet.read().pubkey.clone();
+        async move {
+            let Some(authority) = pubkey else {
+                state.write().loading = false;
+                return;
+            };
+            match fetch_automation(&authority).await {
+                Ok(automation) => state.set(automation),
+                Err(e) => {
+                    tracing::error!("Failed to fetch automation: {}", e);
+                    state.write().loading = false;
+                }
+            }
+        }
+    });
+
+    state
+}
+
+fn automation_pda(authority: &str) -> String {
+    let auth_bytes = bs58::decode(authority).into_vec().unwrap_or_default();
+    derive_pda(&[b"automation", &auth_bytes], PROGRAM_ID)
+}
+
+async fn fetch_automation(authority: &str) -> Result<AutomationState, String> {
+    let pda = automation_pda(authority);
+    let data = fetch_account(RPC_URL, &pda).await?;
+
+    // Automation layout (after 8-byte discriminator):
+    // amount: u64 - offset 8
+    // authority: Pubkey (32) - offset 16
+    // balance: u64 - offset 48
+    // executor: Pubkey (32) - offset 56
+    // fee: u64 - offset 88
+    // strategy: u64 - offset 96
+    // mask: u64 - offset 104
+    // reload: u64 - offset 112
+    let Some(bytes) = data else {
+        return Ok(AutomationState::default());
+    };
+    if bytes.len() < 120 {
+        return Ok(AutomationState::default());
+    }
+
+    let read_u64 = |offset: usize| {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap_or_default())
+    };
+
+    Ok(AutomationState {
+        active: true,
+        amount: read_u64(8),
+        balance: read_u64(48),
+        executor: bs58::encode(&bytes[56..88]).into_string(),
+        fee: read_u64(88),
+        strategy: AutomationStrategy::from_u64(read_u64(96)),
+        mask: read_u64(104),
+        reload: read_u64(112) > 0,
+        loading: false,
+    })
+}
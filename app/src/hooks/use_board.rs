@@ -3,6 +3,7 @@ use std::cell::Cell;
 use std::rc::Rc;
 use crate::{BoardState, RoundPhase, RPC_URL};
 use super::rpc::{fetch_account, fetch_slot, board_pda, round_pda};
+use super::use_deploy::{config_pda, treasury_pda};
 
 pub fn use_board() -> Signal<BoardState> {
     let board = use_context::<Signal<BoardState>>();
@@ -52,6 +53,7 @@ async fn fetch_and_update_board_safe(mut board: Signal<BoardState>) -> Result<()
     board_mut.round_id = data.round_id;
     board_mut.start_slot = data.start_slot;
     board_mut.end_slot = data.end_slot;
+    board_mut.squares = data.squares;
     board_mut.deployed = data.deployed;
     board_mut.count = data.count;
     board_mut.total_deployed = data.total_deployed;
@@ -61,6 +63,8 @@ async fn fetch_and_update_board_safe(mut board: Signal<BoardState>) -> Result<()
     board_mut.bonus_squares = data.bonus_squares;
     board_mut.commit_start_slot = data.commit_start_slot;
     board_mut.reveal_start_slot = data.reveal_start_slot;
+    board_mut.motherlode = data.motherlode;
+    board_mut.motherlode_ev = data.motherlode_ev;
     board_mut.loading = false;
     Ok(())
 }
@@ -72,6 +76,7 @@ async fn fetch_and_update_board(mut board: Signal<BoardState>) {
             board_mut.round_id = data.round_id;
             board_mut.start_slot = data.start_slot;
             board_mut.end_slot = data.end_slot;
+            board_mut.squares = data.squares;
             board_mut.deployed = data.deployed;
             board_mut.count = data.count;
             board_mut.total_deployed = data.total_deployed;
@@ -92,15 +97,18 @@ struct BoardData {
     round_id: u64,
     start_slot: u64,
     end_slot: u64,
+    squares: u8,
     deployed: [u64; 25],
     count: [u64; 25],
     total_deployed: u64,
     current_slot: u64,
     winning_square: Option<u8>,
     phase: RoundPhase,
-    bonus_squares: [u8; 3],
+    bonus_squares: [u8; 7],
     commit_start_slot: u64,
     reveal_start_slot: u64,
+    motherlode: u64,
+    motherlode_ev: f64,
 }
 
 async fn fetch_board_and_round() -> Result<BoardData, String> {
@@ -116,6 +124,14 @@ async fn fetch_board_and_round() -> Result<BoardData, String> {
             data.start_slot = u64::from_le_bytes(bytes[16..24].try_into().unwrap_or_default());
             data.end_slot = u64::from_le_bytes(bytes[24..32].try_into().unwrap_or_default());
         }
+        // Board layout: ..., weight (80-88), squares (88-96). Older boards are 5x5.
+        data.squares = 25;
+        if bytes.len() >= 96 {
+            let squares = u64::from_le_bytes(bytes[88..96].try_into().unwrap_or_default());
+            if squares > 0 && squares <= 25 {
+                data.squares = squares as u8;
+            }
+        }
     }
 
     // Fetch current slot for timer calculation
@@ -141,8 +157,7 @@ async fn fetch_board_and_round() -> Result<BoardData, String> {
         // total_vaulted: u64 - offset 544
         // total_winnings: u64 - offset 552
         // winning_square: u8 - offset 560
-        // bonus_squares: [u8; 3] - offset 561 (v0.6)
-        // _padding: [u8; 4] - offset 564
+        // bonus_squares: [u8; 7] - offset 561, unused entries are 255
         // commit_start_slot: u64 - offset 568 (v0.6)
         // reveal_start_slot: u64 - offset 576 (v0.6)
         // revealed_count: [u64; 25] (200 bytes) - offset 584 (v0.6)
@@ -168,13 +183,13 @@ async fn fetch_board_and_round() -> Result<BoardData, String> {
                 data.winning_square = Some(round_bytes[560]);
             }
 
-            // v0.6: Parse bonus_squares [u8; 3] at offset 561
-            if round_bytes.len() >= 564 {
-                data.bonus_squares = [
-                    round_bytes[561],
-                    round_bytes[562],
-                    round_bytes[563],
-                ];
+            // Parse bonus_squares [u8; 7] at offset 561. Older rounds hold 3 squares
+            // followed by zeroed padding.
+            if round_bytes.len() >= 568 {
+                data.bonus_squares.copy_from_slice(&round_bytes[561..568]);
+                if data.bonus_squares[3..] == [0; 4] {
+                    data.bonus_squares[3..].fill(u8::MAX);
+                }
             }
 
             // v0.6: Parse commit/reveal slots at offsets 568, 576
@@ -199,6 +214,25 @@ async fn fetch_board_and_round() -> Result<BoardData, String> {
         }
     }
 
+    // Fetch the motherlode pool and its odds to estimate the expected payout.
+    // Treasury layout: balance (8-16), motherlode (16-24).
+    // Config layout: ..., motherlode_odds (392-400).
+    if let Ok(Some(treasury_bytes)) = fetch_account(RPC_URL, &treasury_pda()).await {
+        if treasury_bytes.len() >= 24 {
+            data.motherlode = u64::from_le_bytes(treasury_bytes[16..24].try_into().unwrap_or_default());
+        }
+    }
+    if let Ok(Some(config_bytes)) = fetch_account(RPC_URL, &config_pda()).await {
+        if config_bytes.len() >= 400 {
+            let odds = u64::from_le_bytes(config_bytes[392..400].try_into().unwrap_or_default());
+            // Same as Config::motherlode_ev, scaled from base units per lamport to ORE per SOL.
+            if odds > 0 {
+                let ev = data.motherlode as f64 / odds as f64 / data.total_deployed.max(1) as f64;
+                data.motherlode_ev = ev * 1e9 / 1e11;
+            }
+        }
+    }
+
     // Calculate round phase based on commit-reveal timing
     // Flow: Deploying → Committing → Revealing → Ended
     data.phase = if data.winning_square.is_some() {
@@ -4,10 +4,13 @@ use js_sys::{Object, Reflect, Promise, Uint8Array, Array};
 
 use crate::RPC_URL;
 use super::rpc::{board_pda, round_pda, miner_pda, derive_pda, fetch_account, RpcRequest, RpcResponse};
+use super::use_guild::fetch_miner_guild;
 
 // Program IDs
 pub const PROGRAM_ID: &str = "3vzFzHFytiu7zkctgwX2JJhXq3XdN8J7U2WFongrejoU";
 pub const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+// The program that owns the SKILL mint. Deployments initialized with Token-2022 must use
+// TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb instead.
 pub const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 pub const SLOT_HASHES_SYSVAR: &str = "SysvarS1otHashes111111111111111111111111111";
 
@@ -38,10 +41,11 @@ pub async fn deploy_transaction(
     let squares_mask: u32 = selected_squares.iter().fold(0u32, |acc, &sq| acc | (1 << sq));
 
     // 3. Build instruction data
-    // [discriminator (1 byte)] [amount (8 bytes)] [squares (4 bytes)]
+    // [discriminator (1 byte)] [amount (8 bytes)] [squares (4 bytes)] [referrer (32 bytes)]
     let mut ix_data = vec![DEPLOY_DISCRIMINATOR];
     ix_data.extend_from_slice(&amount_lamports.to_le_bytes());
     ix_data.extend_from_slice(&squares_mask.to_le_bytes());
+    ix_data.extend_from_slice(&[0; 32]); // no referrer
 
     // 4. Get recent blockhash
     let blockhash = fetch_recent_blockhash(RPC_URL).await?;
@@ -166,7 +170,9 @@ async fn build_and_send_tx_js(
     // 4: miner (writable)
     // 5: round (writable)
     // 6: system_program (readonly)
+    // 7: config (readonly) - checked for the pause bitmap
 
+    let config = config_pda();
     let accounts = vec![
         (authority, true, true),      // signer, writable
         (authority, true, false),     // authority, writable (same as signer)
@@ -175,6 +181,7 @@ async fn build_and_send_tx_js(
         (miner, true, false),         // miner, writable
         (round, true, false),         // round, writable
         (SYSTEM_PROGRAM, false, false), // system_program, readonly
+        (&config as &str, false, false), // config, readonly
     ];
 
     // Build serialized transaction message
@@ -349,12 +356,12 @@ pub async fn deploy_transaction(
 // ============ PLAY TRANSACTION (v0.5 - Auto Reset) ============
 
 /// Pre-computed PDAs for SKILL protocol
-fn config_pda() -> String {
+pub fn config_pda() -> String {
     // Pre-computed: J1MkbQ4Yu4zHhcj3B34XHfcqufpBpyjQoAxYwy1KsAXj
     "J1MkbQ4Yu4zHhcj3B34XHfcqufpBpyjQoAxYwy1KsAXj".to_string()
 }
 
-fn treasury_pda() -> String {
+pub fn treasury_pda() -> String {
     // Pre-computed: 75mND1dHyZcXntj2m4iFdT9ZwwDTbFCMjDDNQdyz2t2c
     "75mND1dHyZcXntj2m4iFdT9ZwwDTbFCMjDDNQdyz2t2c".to_string()
 }
@@ -365,8 +372,7 @@ fn mint_pda() -> String {
 }
 
 fn treasury_tokens_pda() -> String {
-    // Pre-computed: FyDJZfkXcL6LWfS8dZyvUQAUrTp44ewNYXA3R69bwR4q
-    "FyDJZfkXcL6LWfS8dZyvUQAUrTp44ewNYXA3R69bwR4q".to_string()
+    derive_associated_token_account(&treasury_pda(), &mint_pda())
 }
 
 /// Check if round has ended and needs reset
@@ -454,6 +460,9 @@ async fn send_play_tx_with_reset(
         return Err("Phantom wallet not connected".to_string());
     }
 
+    // Guild members checkpoint with their guild
+    let guild = fetch_miner_guild(authority).await?;
+
     // Build transaction with two instructions: reset + deploy
     let tx_bytes = build_play_transaction_bytes(
         authority,
@@ -462,6 +471,7 @@ async fn send_play_tx_with_reset(
         amount_lamports,
         selected_squares,
         blockhash,
+        guild,
     )?;
 
     // Convert to Uint8Array
@@ -502,6 +512,7 @@ fn build_play_transaction_bytes(
     amount_lamports: u64,
     selected_squares: &[u8],
     blockhash: &str,
+    guild: Option<String>,
 ) -> Result<Vec<u8>, String> {
     // Calculate all PDAs
     let board = board_pda();
@@ -523,6 +534,7 @@ fn build_play_transaction_bytes(
     let mut deploy_data = vec![DEPLOY_DISCRIMINATOR];
     deploy_data.extend_from_slice(&amount_lamports.to_le_bytes());
     deploy_data.extend_from_slice(&squares_mask.to_le_bytes());
+    deploy_data.extend_from_slice(&[0; 32]); // no referrer
 
     // Build unique accounts list
     // Order matters for Solana transaction format
@@ -545,18 +557,22 @@ fn build_play_transaction_bytes(
         TOKEN_PROGRAM.to_string(),  // token_program
         PROGRAM_ID.to_string(),     // ore_program (skill)
         SLOT_HASHES_SYSVAR.to_string(), // slot_hashes
+        config.clone(),             // config (pause check)
     ];
 
     // Checkpoint accounts (from sdk.rs):
-    // signer, board, miner, round, treasury, system
-    let checkpoint_accounts = vec![
+    // signer, board, miner, round, treasury, system, config, [guild]
+    let mut checkpoint_accounts = vec![
         authority.to_string(),      // signer
         board.clone(),              // board
         miner.clone(),              // miner
         round.clone(),              // round (current, to checkpoint)
         treasury.clone(),           // treasury
         SYSTEM_PROGRAM.to_string(), // system_program
+        config.clone(),             // config (vesting period)
     ];
+    checkpoint_accounts.extend(guild); // guild (members only)
+    checkpoint_accounts.push(config.clone()); // config (pause check)
 
     // Deploy accounts (from sdk.rs):
     // signer, authority, automation, board, miner, round, system
@@ -568,6 +584,7 @@ fn build_play_transaction_bytes(
         miner.clone(),              // miner
         round_next.clone(),         // round (next round after reset)
         SYSTEM_PROGRAM.to_string(), // system_program
+        config.clone(),             // config (pause check)
     ];
 
     // Build unique accounts, tracking indices
@@ -664,13 +681,19 @@ const CLAIM_ORE_DISCRIMINATOR: u8 = 4;
 #[cfg(feature = "web")]
 pub async fn claim_sol_transaction(authority: &str) -> Result<String, String> {
     let miner = miner_pda(authority);
+    let config = config_pda();
+    let claim_recipient = fetch_claim_recipient(authority).await?;
     let blockhash = fetch_recent_blockhash(RPC_URL).await?;
 
-    let accounts = vec![
+    let mut accounts = vec![
         (authority, true, true),      // signer, writable
         (&miner as &str, true, false), // miner, writable
         (SYSTEM_PROGRAM, false, false), // system_program, readonly
     ];
+    if let Some(claim_recipient) = &claim_recipient {
+        accounts.push((claim_recipient.as_str(), true, false)); // claim recipient, writable
+    }
+    accounts.push((&config as &str, false, false)); // config, readonly (pause check)
 
     let ix_data = vec![CLAIM_SOL_DISCRIMINATOR];
 
@@ -692,15 +715,21 @@ pub async fn claim_ore_transaction(authority: &str) -> Result<String, String> {
     let treasury = treasury_pda();
     let mint = mint_pda();
     let treasury_tokens = treasury_tokens_pda();
+    let config = config_pda();
 
     // Derive recipient's associated token account
-    let recipient_ata = derive_associated_token_account(authority, &mint);
+    let claim_recipient = fetch_claim_recipient(authority).await?;
+    let owner = claim_recipient.as_deref().unwrap_or(authority);
+    let recipient_ata = derive_associated_token_account(owner, &mint);
+
+    // The referrer's miner is credited with part of the claim fee
+    let referrer_miner = fetch_referrer_miner(authority).await?;
 
     let blockhash = fetch_recent_blockhash(RPC_URL).await?;
 
     // Accounts from sdk.rs claim_ore:
-    // signer, miner, mint, recipient, treasury, treasury_tokens, system, token_program, ata_program
-    let accounts = vec![
+    // signer, miner, mint, recipient, treasury, treasury_tokens, system, token_program, ata_program, config, referrer_miner, [claim_recipient]
+    let mut accounts = vec![
         (authority, true, true),                   // signer
         (&miner as &str, true, false),             // miner
         (&mint as &str, false, false),             // mint (readonly)
@@ -710,7 +739,13 @@ pub async fn claim_ore_transaction(authority: &str) -> Result<String, String> {
         (SYSTEM_PROGRAM, false, false),            // system_program
         (TOKEN_PROGRAM, false, false),             // token_program
         (ASSOCIATED_TOKEN_PROGRAM, false, false),  // ata_program
+        (&config as &str, false, false),           // config
+        (&referrer_miner as &str, true, false),    // referrer miner
     ];
+    if let Some(claim_recipient) = &claim_recipient {
+        accounts.push((claim_recipient.as_str(), false, false)); // claim recipient (readonly)
+    }
+    accounts.push((&config as &str, false, false)); // config (pause check)
 
     let ix_data = vec![CLAIM_ORE_DISCRIMINATOR];
 
@@ -725,6 +760,40 @@ pub async fn claim_ore_transaction(authority: &str) -> Result<String, String> {
     send_transaction_phantom(&tx_bytes).await
 }
 
+// Offset of Miner.referrer in the miner account, including the discriminator
+const MINER_REFERRER_OFFSET: usize = 640;
+
+/// Derive the miner address of the authority's referrer (the default address if none)
+#[cfg(feature = "web")]
+async fn fetch_referrer_miner(authority: &str) -> Result<String, String> {
+    let miner = miner_pda(authority);
+    let referrer = fetch_account(RPC_URL, &miner)
+        .await?
+        .and_then(|bytes| {
+            bytes
+                .get(MINER_REFERRER_OFFSET..MINER_REFERRER_OFFSET + 32)
+                .map(|b| b.to_vec())
+        })
+        .unwrap_or(vec![0; 32]);
+    Ok(miner_pda(&bs58::encode(referrer).into_string()))
+}
+
+// Offset of Miner.claim_recipient in the miner account, including the discriminator
+const MINER_CLAIM_RECIPIENT_OFFSET: usize = 760;
+
+/// Fetch the address the authority's rewards are claimed to, if it is not the authority
+#[cfg(feature = "web")]
+async fn fetch_claim_recipient(authority: &str) -> Result<Option<String>, String> {
+    let miner = miner_pda(authority);
+    let claim_recipient = fetch_account(RPC_URL, &miner).await?.and_then(|bytes| {
+        bytes
+            .get(MINER_CLAIM_RECIPIENT_OFFSET..MINER_CLAIM_RECIPIENT_OFFSET + 32)
+            .filter(|b| b.iter().any(|&x| x != 0))
+            .map(|b| bs58::encode(b).into_string())
+    });
+    Ok(claim_recipient)
+}
+
 const ASSOCIATED_TOKEN_PROGRAM: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 
 /// Derive associated token account address
@@ -0,0 +1,124 @@
+use dioxus::prelude::*;
+
+use crate::{WalletState, RPC_URL};
+use super::rpc::{fetch_account, miner_pda};
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+// Offset of Miner.guild in the miner account, including the discriminator
+const MINER_GUILD_OFFSET: usize = 704;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuildState {
+    pub member: bool,
+    pub address: String,
+    pub name: String,
+    pub revenue_share: u64,
+    pub member_count: u64,
+    pub balance: u64,
+    pub wins: u64,
+    pub rounds: u64,
+    pub volume: u64,
+    pub skill_points: u64,
+    pub loading: bool,
+}
+
+impl GuildState {
+    pub fn balance_sol(&self) -> f64 {
+        self.balance as f64 / LAMPORTS_PER_SOL
+    }
+
+    pub fn volume_sol(&self) -> f64 {
+        self.volume as f64 / LAMPORTS_PER_SOL
+    }
+}
+
+pub fn use_guild() -> Signal<GuildState> {
+    let wallet = use_context::<Signal<WalletState>>();
+    let mut state = use_signal(|| GuildState {
+        loading: true,
+        ..Default::default()
+    });
+
+    let _resource = use_resource(move || {
+        let pubkey = wallet.read().pubkey.clone();
+        async move {
+            let Some(authority) = pubkey else {
+                state.write().loading = false;
+                return;
+            };
+            match fetch_guild(&authority).await {
+                Ok(guild) => state.set(guild),
+                Err(e) => {
+                    tracing::error!("Failed to fetch guild: {}", e);
+                    state.write().loading = false;
+                }
+            }
+        }
+    });
+
+    state
+}
+
+/// The address of the authority's guild, if they are a member of one
+pub(super) async fn fetch_miner_guild(authority: &str) -> Result<Option<String>, String> {
+    let miner = miner_pda(authority);
+    let guild = fetch_account(RPC_URL, &miner)
+        .await?
+        .and_then(|bytes| {
+            bytes
+                .get(MINER_GUILD_OFFSET..MINER_GUILD_OFFSET + 32)
+                .map(|b| b.to_vec())
+        })
+        .filter(|b| b.iter().any(|&x| x != 0))
+        .map(|b| bs58::encode(b).into_string());
+    Ok(guild)
+}
+
+async fn fetch_guild(authority: &str) -> Result<GuildState, String> {
+    let Some(address) = fetch_miner_guild(authority).await? else {
+        return Ok(GuildState::default());
+    };
+    let data = fetch_account(RPC_URL, &address).await?;
+
+    // Guild layout (after 8-byte discriminator):
+    // id: u64 - offset 8
+    // authority: Pubkey (32) - offset 16
+    // executor: Pubkey (32) - offset 48
+    // name: [u8; 32] - offset 80
+    // revenue_share: u64 - offset 112
+    // member_count: u64 - offset 120
+    // balance: u64 - offset 128
+    // rewards_ore: u64 - offset 136
+    // lifetime_rewards_ore: u64 - offset 144
+    // wins: u64 - offset 152
+    // rounds: u64 - offset 160
+    // volume: u64 - offset 168
+    // skill_points: u64 - offset 176
+    let Some(bytes) = data else {
+        return Ok(GuildState::default());
+    };
+    if bytes.len() < 184 {
+        return Ok(GuildState::default());
+    }
+
+    let read_u64 = |offset: usize| {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap_or_default())
+    };
+    let name = &bytes[80..112];
+    let name_len = name.iter().position(|&b| b == 0).unwrap_or(32);
+
+    Ok(GuildState {
+        member: true,
+        address,
+        name: String::from_utf8_lossy(&name[..name_len]).to_string(),
+        revenue_share: read_u64(112),
+        member_count: read_u64(120),
+        balance: read_u64(128),
+        wins: read_u64(152),
+        rounds: read_u64(160),
+        volume: read_u64(168),
+        skill_points: read_u64(176),
+        loading: false,
+    })
+}
@@ -64,15 +64,18 @@ pub struct BoardState {
     pub round_id: u64,
     pub start_slot: u64,
     pub end_slot: u64,
+    pub squares: u8,                // Number of squares, laid out as an N×N grid
     pub deployed: [u64; 25],        // SOL deployed per square (lamports)
     pub count: [u64; 25],           // Miner count per square
     pub total_deployed: u64,        // Total SOL in round
     pub current_slot: u64,          // Current slot for timer calculation
     pub winning_square: Option<u8>, // Set when round ends
     pub phase: RoundPhase,          // Current round phase
-    pub bonus_squares: [u8; 3],     // v0.6: Bonus squares with 2x multiplier
+    pub bonus_squares: [u8; 7],     // Bonus squares, unused entries are 255
     pub commit_start_slot: u64,     // v0.6: Start of commit phase
     pub reveal_start_slot: u64,     // v0.6: Start of reveal phase
+    pub motherlode: u64,            // Motherlode pool (ORE base units)
+    pub motherlode_ev: f64,         // Expected motherlode ORE per SOL deployed
     pub loading: bool,
 }
 
@@ -82,15 +85,18 @@ impl Default for BoardState {
             round_id: 0,
             start_slot: 0,
             end_slot: u64::MAX,
+            squares: 25,
             deployed: [0; 25],
             count: [0; 25],
             total_deployed: 0,
             current_slot: 0,
             winning_square: None,
             phase: RoundPhase::Deploying,
-            bonus_squares: [0, 0, 0],
+            bonus_squares: [u8::MAX; 7],
             commit_start_slot: 0,
             reveal_start_slot: 0,
+            motherlode: 0,
+            motherlode_ev: 0.0,
             loading: true,
         }
     }
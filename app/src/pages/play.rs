@@ -36,11 +36,12 @@ pub fn Play() -> Element {
 
     // Select all squares
     let select_all = move |_| {
+        let num_squares = board.read().squares;
         let mut squares = selected_squares.write();
-        if squares.len() == 25 {
+        if squares.len() == num_squares as usize {
             squares.clear();
         } else {
-            *squares = (0..25).collect();
+            *squares = (0..num_squares).collect();
         }
     };
 
@@ -49,6 +50,7 @@ pub fn Play() -> Element {
     let round_id = board_state.round_id;
     let end_slot = board_state.end_slot;
     let start_slot = board_state.start_slot;
+    let squares = board_state.squares;
     let deployed = board_state.deployed;
     let count = board_state.count;
     let total_deployed = board_state.total_deployed;
@@ -58,6 +60,8 @@ pub fn Play() -> Element {
     let bonus_squares = board_state.bonus_squares;
     let commit_start_slot = board_state.commit_start_slot;
     let reveal_start_slot = board_state.reveal_start_slot;
+    let motherlode = board_state.motherlode;
+    let motherlode_ev = board_state.motherlode_ev;
     let is_loading = board_state.loading;
     drop(board_state);
 
@@ -148,6 +152,7 @@ pub fn Play() -> Element {
                     Board {
                         selected: selected_squares.read().clone(),
                         winning_square: winning_square,
+                        squares: squares,
                         deployed: deployed,
                         count: count,
                         // Allow selection when round needs reset (player can start next round)
@@ -204,6 +209,18 @@ pub fn Play() -> Element {
                                     }
                                 }
                             }
+                            div { class: "flex justify-between",
+                                span { class: "text-low text-sm", "Motherlode" }
+                                span { class: "text-high font-mono",
+                                    {format!("{:.2} SKILL", motherlode as f64 / 100_000_000_000.0)}
+                                }
+                            }
+                            div { class: "flex justify-between",
+                                span { class: "text-low text-sm", "Motherlode EV" }
+                                span { class: "text-high font-mono",
+                                    {format!("{:.4} SKILL/SOL", motherlode_ev)}
+                                }
+                            }
                         }
                     }
 
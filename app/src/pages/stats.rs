@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use crate::components::SkillStats;
+use crate::components::{Achievements, AutomationStats, GuildStats, SkillStats};
 use crate::hooks::use_miner;
 use crate::{WalletState, MinerState};
 
@@ -47,6 +47,18 @@ pub fn Stats() -> Element {
                     }
                 }
 
+                div { class: "mt-6",
+                    AutomationStats {}
+                }
+
+                div { class: "mt-6",
+                    GuildStats {}
+                }
+
+                div { class: "mt-6",
+                    Achievements {}
+                }
+
                 // Prediction history (placeholder)
                 div { class: "card mt-6",
                     h3 { class: "text-lg font-semibold text-skill-400 mb-4", "Recent Predictions" }
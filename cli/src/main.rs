@@ -1,23 +1,16 @@
 use std::{collections::HashMap, str::FromStr};
 
-// Entropy API only needed for legacy admin commands (new_var)
-use entropy_api::state as entropy_state;
-use jup_swap::{
-    quote::QuoteRequest,
-    swap::SwapRequest,
-    transaction_config::{DynamicSlippageSettings, TransactionConfig},
-    JupiterSwapApiClient,
-};
 use skill_api::prelude::*;
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::{
     client_error::{reqwest::StatusCode, ClientErrorKind},
     nonblocking::rpc_client::RpcClient,
-    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig},
     rpc_filter::{Memcmp, RpcFilterType},
 };
 use solana_sdk::{
     address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    commitment_config::CommitmentConfig,
     compute_budget::ComputeBudgetInstruction,
     message::{v0::Message, VersionedMessage},
     native_token::LAMPORTS_PER_SOL,
@@ -27,9 +20,15 @@ use solana_sdk::{
     transaction::{Transaction, VersionedTransaction},
 };
 use solana_sdk::{keccak, pubkey};
-use spl_associated_token_account::get_associated_token_address;
+use solana_transaction_status::UiTransactionEncoding;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
 use spl_token::amount_to_ui_amount;
 use steel::{AccountDeserialize, AccountMeta, Clock, Discriminator, Instruction};
+use swap::{JupiterProvider, MockAmmProvider, SwapProvider};
+
+mod swap;
 
 #[tokio::main]
 async fn main() {
@@ -52,6 +51,12 @@ async fn main() {
         "claim" => {
             claim(&rpc, &payer).await.unwrap();
         }
+        "set_claim_recipient" => {
+            set_claim_recipient(&rpc, &payer).await.unwrap();
+        }
+        "sweep" => {
+            sweep(&rpc, &payer).await.unwrap();
+        }
         "board" => {
             log_board(&rpc).await.unwrap();
         }
@@ -61,6 +66,9 @@ async fn main() {
         "buyback" => {
             buyback(&rpc, &payer).await.unwrap();
         }
+        "fund_mock_amm" => {
+            fund_mock_amm(&rpc, &payer).await.unwrap();
+        }
         "reset" => {
             reset(&rpc, &payer).await.unwrap();
         }
@@ -70,6 +78,15 @@ async fn main() {
         "miner" => {
             log_miner(&rpc, &payer).await.unwrap();
         }
+        "referrals" => {
+            log_referrals(&rpc, &payer).await.unwrap();
+        }
+        "achievements" => {
+            log_achievements(&rpc, &payer).await.unwrap();
+        }
+        "open_achievements" => {
+            open_achievements(&rpc, &payer).await.unwrap();
+        }
         // "pool" => {
         //     log_meteora_pool(&rpc).await.unwrap();
         // }
@@ -91,6 +108,126 @@ async fn main() {
         "set_admin" => {
             set_admin(&rpc, &payer).await.unwrap();
         }
+        "accept_admin" => {
+            accept_admin(&rpc, &payer).await.unwrap();
+        }
+        "set_bury_authority" => {
+            set_bury_authority(&rpc, &payer).await.unwrap();
+        }
+        "set_fee_authority" => {
+            set_fee_authority(&rpc, &payer).await.unwrap();
+        }
+        "set_pause_authority" => {
+            set_pause_authority(&rpc, &payer).await.unwrap();
+        }
+        "migrate_config" => {
+            migrate_config(&rpc, &payer).await.unwrap();
+        }
+        "set_buyback_cap" => {
+            set_buyback_cap(&rpc, &payer).await.unwrap();
+        }
+        "set_liq_manager" => {
+            set_liq_manager(&rpc, &payer).await.unwrap();
+        }
+        "set_referral_fee" => {
+            set_referral_fee(&rpc, &payer).await.unwrap();
+        }
+        "set_vesting_period" => {
+            set_vesting_period(&rpc, &payer).await.unwrap();
+        }
+        "set_motherlode_odds" => {
+            set_motherlode_odds(&rpc, &payer).await.unwrap();
+        }
+        "set_motherlode_rate" => {
+            set_motherlode_rate(&rpc, &payer).await.unwrap();
+        }
+        "set_rng_mode" => {
+            set_rng_mode(&rpc, &payer).await.unwrap();
+        }
+        "arm_entropy" => {
+            arm_entropy(&rpc, &payer).await.unwrap();
+        }
+        "set_bonus_square_count" => {
+            set_bonus_square_count(&rpc, &payer).await.unwrap();
+        }
+        "set_bonus_square_multiplier" => {
+            set_bonus_square_multiplier(&rpc, &payer).await.unwrap();
+        }
+        "new_board" => {
+            new_board(&rpc, &payer).await.unwrap();
+        }
+        "update_board" => {
+            update_board(&rpc, &payer).await.unwrap();
+        }
+        "set_board_weight" => {
+            set_board_weight(&rpc, &payer).await.unwrap();
+        }
+        "new_season" => {
+            new_season(&rpc, &payer).await.unwrap();
+        }
+        "claim_season" => {
+            claim_season(&rpc, &payer).await.unwrap();
+        }
+        "season" => {
+            log_season(&rpc).await.unwrap();
+        }
+        "new_guild" => {
+            new_guild(&rpc, &payer).await.unwrap();
+        }
+        "update_guild" => {
+            update_guild(&rpc, &payer).await.unwrap();
+        }
+        "join_guild" => {
+            join_guild(&rpc, &payer).await.unwrap();
+        }
+        "leave_guild" => {
+            leave_guild(&rpc, &payer).await.unwrap();
+        }
+        "deposit_guild" => {
+            deposit_guild(&rpc, &payer).await.unwrap();
+        }
+        "fund_member" => {
+            fund_member(&rpc, &payer).await.unwrap();
+        }
+        "claim_guild" => {
+            claim_guild(&rpc, &payer).await.unwrap();
+        }
+        "guild" => {
+            log_guild(&rpc).await.unwrap();
+        }
+        "new_tournament" => {
+            new_tournament(&rpc, &payer).await.unwrap();
+        }
+        "join_tournament" => {
+            join_tournament(&rpc, &payer).await.unwrap();
+        }
+        "score_tournament" => {
+            score_tournament(&rpc, &payer).await.unwrap();
+        }
+        "settle_tournament" => {
+            settle_tournament(&rpc, &payer).await.unwrap();
+        }
+        "tournament" => {
+            log_tournament(&rpc).await.unwrap();
+        }
+        "update_metadata_uri" => {
+            update_metadata_uri(&rpc, &payer).await.unwrap();
+        }
+        "pause" => {
+            set_pause(&rpc, &payer).await.unwrap();
+        }
+        "changes" => {
+            log_changes(&rpc).await.unwrap();
+        }
+        "set_change_delay" => {
+            set_change_delay(&rpc, &payer).await.unwrap();
+        }
+        "execute_change" => {
+            execute_change(&rpc, &payer).await.unwrap();
+        }
+        "cancel_change" => {
+            cancel_change(&rpc, &payer).await.unwrap();
+        }
         "set_fee_collector" => {
             set_fee_collector(&rpc, &payer).await.unwrap();
         }
@@ -103,6 +240,12 @@ async fn main() {
         "checkpoint_all" => {
             checkpoint_all(&rpc, &payer).await.unwrap();
         }
+        "checkpoint_units" => {
+            checkpoint_units(&rpc, &payer).await.unwrap();
+        }
+        "compute_units" => {
+            compute_units(&rpc).await.unwrap();
+        }
         "close_all" => {
             close_all(&rpc, &payer).await.unwrap();
         }
@@ -136,6 +279,18 @@ async fn main() {
         "automation" => {
             log_automation(&rpc).await.unwrap();
         }
+        "automate" => {
+            automate(&rpc, &payer).await.unwrap();
+        }
+        "automate_reveal" => {
+            automate_reveal(&rpc, &payer).await.unwrap();
+        }
+        "executor" => {
+            executor(&rpc, &payer).await.unwrap();
+        }
+        "automate_limits" => {
+            automate_limits(&rpc, &payer).await.unwrap();
+        }
         "init" => {
             init(&rpc, &payer).await.unwrap();
         }
@@ -167,12 +322,34 @@ async fn init(
         .map(|s| Pubkey::from_str(&s).expect("Invalid VAR_ADDRESS"))
         .unwrap_or(Pubkey::default());
 
+    // Token-2022 mints are created with metadata; spl-token mints are not.
+    let token_program = match std::env::var("TOKEN_PROGRAM")
+        .unwrap_or("spl-token".to_string())
+        .as_str()
+    {
+        "spl-token" => spl_token::ID,
+        "token-2022" => spl_token_2022::ID,
+        other => panic!("Invalid TOKEN_PROGRAM: {}", other),
+    };
+    let name = std::env::var("NAME").unwrap_or("Skill".to_string());
+    let symbol = std::env::var("SYMBOL").unwrap_or("SKILL".to_string());
+    let uri = std::env::var("URI").unwrap_or_default();
+
     // Build and submit initialize instruction.
-    let ix = skill_api::sdk::initialize(payer.pubkey(), admin, fee_collector, var_address);
+    let ix = skill_api::sdk::initialize(
+        payer.pubkey(),
+        admin,
+        fee_collector,
+        var_address,
+        token_program,
+        &name,
+        &symbol,
+        &uri,
+    );
     let sig = submit_transaction(rpc, payer, &[ix]).await?;
 
     // Output created addresses.
-    let board_address = skill_api::state::board_pda().0;
+    let board_address = skill_api::state::board_pda(0).0;
     let config_address = skill_api::state::config_pda().0;
     let treasury_address = skill_api::state::treasury_pda().0;
     let mint_address = skill_api::state::mint_pda().0;
@@ -201,9 +378,11 @@ async fn liq(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let manager = pubkey!("DJqfQWB8tZE6fzqWa8okncDh7ciTuD8QQKp1ssNETWee");
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let config = get_config(rpc).await?;
     let wrap_ix = skill_api::sdk::wrap(payer.pubkey());
-    let liq_ix = skill_api::sdk::liq(payer.pubkey(), manager);
+    let liq_ix = skill_api::sdk::liq(payer.pubkey(), config.liq_manager, amount);
     submit_transaction(rpc, payer, &[wrap_ix, liq_ix]).await?;
     Ok(())
 }
@@ -212,11 +391,31 @@ async fn migrate_automation(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let authorities = [
-        pubkey!("HSB6HB184xHLsEBia2VR3rdqrme9MWZR9tVPLT3Ndda2"),
-        pubkey!("3SrTpJEsTonUf9Ew7eGSi1xhNN6gqaKbZUc9ncFcGz7b"),
-        pubkey!("Bwyuj9ybgSTtPkhvCFxL1A7uV9SiA75nb55qBF6pFMKz"),
-    ];
+    // Find automation accounts created before the account was extended.
+    let filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        0,
+        &(OreAccount::Automation as u64).to_le_bytes(),
+    ))];
+    let accounts = rpc
+        .get_program_accounts_with_config(
+            &skill_api::ID,
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    let size = 8 + std::mem::size_of::<Automation>();
+    let authorities = accounts
+        .iter()
+        .filter(|(_, account)| account.data.len() < size)
+        .map(|(_, account)| Pubkey::try_from(&account.data[16..48]).unwrap())
+        .collect::<Vec<_>>();
+    println!("Migrating {} automation accounts", authorities.len());
     for authority in authorities {
         let ix = skill_api::sdk::migrate_automation(payer.pubkey(), authority);
         if let Err(e) = submit_transaction_no_confirm(rpc, payer, &[ix]).await {
@@ -289,7 +488,30 @@ async fn set_admin_fee(
 ) -> Result<(), anyhow::Error> {
     let admin_fee = std::env::var("ADMIN_FEE").expect("Missing ADMIN_FEE env var");
     let admin_fee = u64::from_str(&admin_fee).expect("Invalid ADMIN_FEE");
-    let ix = skill_api::sdk::set_admin_fee(payer.pubkey(), admin_fee);
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_admin_fee(
+        payer.pubkey(),
+        get_board_id(),
+        admin_fee,
+        config.change_count,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_board_weight(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let weight = std::env::var("WEIGHT").expect("Missing WEIGHT env var");
+    let weight = u64::from_str(&weight).expect("Invalid WEIGHT");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_board_weight(
+        payer.pubkey(),
+        get_board_id(),
+        weight,
+        config.change_count,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -300,7 +522,8 @@ async fn set_var_address(
 ) -> Result<(), anyhow::Error> {
     let new_var_address = std::env::var("VAR").expect("Missing VAR env var");
     let new_var_address = Pubkey::from_str(&new_var_address).expect("Invalid VAR");
-    let ix = skill_api::sdk::set_var_address(payer.pubkey(), new_var_address);
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_var_address(payer.pubkey(), new_var_address, config.change_count);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -315,10 +538,17 @@ async fn new_var(
     let commit = keccak::Hash::from_str(&commit).expect("Invalid COMMIT");
     let samples = std::env::var("SAMPLES").expect("Missing SAMPLES env var");
     let samples = u64::from_str(&samples).expect("Invalid SAMPLES");
-    let board_address = board_pda().0;
-    let var_address = entropy_state::var_pda(board_address, 0).0;
+    let board_id = get_board_id();
+    let var_address = board_var_pda(board_pda(board_id).0).0;
     println!("Var address: {}", var_address);
-    let ix = skill_api::sdk::new_var(payer.pubkey(), provider, 0, commit.to_bytes(), samples);
+    let ix = skill_api::sdk::new_var(
+        payer.pubkey(),
+        board_id,
+        provider,
+        0,
+        commit.to_bytes(),
+        samples,
+    );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
@@ -390,10 +620,10 @@ async fn ata(
 async fn keys() -> Result<(), anyhow::Error> {
     let treasury_address = skill_api::state::treasury_pda().0;
     let config_address = skill_api::state::config_pda().0;
-    let board_address = skill_api::state::board_pda().0;
+    let board_address = skill_api::state::board_pda(0).0;
     let address = pubkey!("pqspJ298ryBjazPAr95J9sULCVpZe3HbZTWkbC1zrkS");
     let miner_address = skill_api::state::miner_pda(address).0;
-    let round = round_pda(31460).0;
+    let round = round_pda(0, 31460).0;
     println!("Round: {}", round);
     println!("Treasury: {}", treasury_address);
     println!("Config: {}", config_address);
@@ -406,74 +636,138 @@ async fn claim(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix_sol = skill_api::sdk::claim_sol(payer.pubkey());
-    let ix_ore = skill_api::sdk::claim_ore(payer.pubkey());
+    let token_program = get_token_program(rpc).await?;
+    let (referrer, claim_recipient) = get_miner(rpc, payer.pubkey())
+        .await
+        .map(|miner| (miner.referrer, claim_recipient_of(&miner)))
+        .unwrap_or_default();
+    let ix_sol = skill_api::sdk::claim_sol(payer.pubkey(), claim_recipient);
+    let ix_ore =
+        skill_api::sdk::claim_ore(payer.pubkey(), token_program, referrer, claim_recipient);
     submit_transaction(rpc, payer, &[ix_sol, ix_ore]).await?;
     Ok(())
 }
 
-async fn buyback(
+async fn set_claim_recipient(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    // Get swap amount.
-    let treasury = get_treasury(rpc).await?;
-    let amount = treasury.balance.min(10 * LAMPORTS_PER_SOL);
-
-    // Build quote request.
-    const INPUT_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
-    const OUTPUT_MINT: Pubkey = pubkey!("oreoU2P8bN6jkk3jbaiVxYnG1dCXcYxwhwyK9jSybcp");
-    let api_base_url =
-        std::env::var("API_BASE_URL").unwrap_or("https://lite-api.jup.ag/swap/v1".into());
-    let jupiter_swap_api_client = JupiterSwapApiClient::new(api_base_url);
-    let quote_request = QuoteRequest {
-        amount,
-        input_mint: INPUT_MINT,
-        output_mint: OUTPUT_MINT,
-        max_accounts: Some(55),
-        ..QuoteRequest::default()
+    let claim_recipient = std::env::var("RECIPIENT")
+        .map(|r| Pubkey::from_str(&r).expect("Invalid RECIPIENT"))
+        .unwrap_or_default();
+    let ix = skill_api::sdk::set_claim_recipient(payer.pubkey(), claim_recipient);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Sweeps the rewards of the AUTHORITY's miner to its claim recipient, or of every miner with a
+/// claim recipient and unclaimed rewards if no AUTHORITY is given.
+async fn sweep(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let token_program = get_token_program(rpc).await?;
+    let miners = match std::env::var("AUTHORITY") {
+        Ok(authority) => {
+            let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+            vec![get_miner(rpc, authority).await?]
+        }
+        Err(_) => get_miners(rpc)
+            .await?
+            .into_iter()
+            .map(|(_, miner)| miner)
+            .filter(|m| {
+                m.rewards_sol > 0 || m.rewards_ore > 0 || m.refined_ore > 0 || m.vesting_ore > 0
+            })
+            .collect(),
     };
+    let mut ixs = vec![];
+    for miner in miners {
+        let Some(claim_recipient) = claim_recipient_of(&miner) else {
+            continue;
+        };
+        println!("Sweep miner: {} -> {}", miner.authority, claim_recipient);
+        ixs.push(skill_api::sdk::sweep(
+            payer.pubkey(),
+            miner.authority,
+            claim_recipient,
+            token_program,
+            miner.referrer,
+        ));
+    }
+    submit_transaction_batches(rpc, payer, ixs, 2).await?;
+    Ok(())
+}
+
+async fn buyback(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let provider = std::env::var("SWAP_PROVIDER").unwrap_or("jupiter".to_string());
+    match provider.as_str() {
+        "jupiter" => {
+            let api_base_url =
+                std::env::var("API_BASE_URL").unwrap_or("https://lite-api.jup.ag/swap/v1".into());
+            buyback_with(rpc, payer, &JupiterProvider::new(api_base_url)).await
+        }
+        "mock" => buyback_with(rpc, payer, &MockAmmProvider::new(rpc)).await,
+        _ => panic!("Invalid SWAP_PROVIDER: {}", provider),
+    }
+}
 
-    // GET /quote
-    let quote_response = match jupiter_swap_api_client.quote(&quote_request).await {
-        Ok(quote_response) => quote_response,
+async fn buyback_with<P: SwapProvider>(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    provider: &P,
+) -> Result<(), anyhow::Error> {
+    // Get swap amount and slippage.
+    let config = get_config(rpc).await?;
+    let treasury = get_treasury(rpc).await?;
+    let amount = treasury.balance.min(config.buyback_cap);
+    let slippage_bps = std::env::var("SLIPPAGE_BPS").unwrap_or("50".to_string());
+    let slippage_bps = u16::from_str(&slippage_bps).expect("Invalid SLIPPAGE_BPS");
+    assert!(slippage_bps <= 10_000, "Invalid SLIPPAGE_BPS");
+
+    // Get quote.
+    let quote = match provider
+        .quote(amount, SOL_MINT, MINT_ADDRESS, slippage_bps)
+        .await
+    {
+        Ok(quote) => quote,
         Err(e) => {
-            println!("quote failed: {e:#?}");
-            return Err(anyhow::anyhow!("quote failed: {e:#?}"));
+            println!("{e:#?}");
+            return Err(e);
         }
     };
 
-    // GET /swap/instructions
-    let treasury_address = skill_api::state::treasury_pda().0;
-    let response = jupiter_swap_api_client
-        .swap_instructions(&SwapRequest {
-            user_public_key: treasury_address,
-            quote_response,
-            config: TransactionConfig {
-                skip_user_accounts_rpc_calls: true,
-                wrap_and_unwrap_sol: false,
-                dynamic_compute_unit_limit: true,
-                dynamic_slippage: Some(DynamicSlippageSettings {
-                    min_bps: Some(50),
-                    max_bps: Some(1000),
-                }),
-                ..TransactionConfig::default()
-            },
-        })
-        .await
-        .unwrap();
+    // Derive the minimum output from the quote.
+    let out_amount = P::out_amount(&quote);
+    let min_ore_out = (out_amount as u128 * (10_000 - slippage_bps as u128) / 10_000) as u64;
+    println!(
+        "Quote: {} SOL -> {} ORE (min {} ORE at {} bps slippage)",
+        amount as f64 / LAMPORTS_PER_SOL as f64,
+        amount_to_ui_amount(out_amount, TOKEN_DECIMALS),
+        amount_to_ui_amount(min_ore_out, TOKEN_DECIMALS),
+        slippage_bps
+    );
 
+    // Build swap route for the treasury.
+    let treasury_address = skill_api::state::treasury_pda().0;
+    let route = provider
+        .swap_route(quote, treasury_address, slippage_bps)
+        .await?;
     let address_lookup_table_accounts =
-        get_address_lookup_table_accounts(rpc, response.address_lookup_table_addresses)
-            .await
-            .unwrap();
+        get_address_lookup_table_accounts(rpc, route.address_lookup_tables).await?;
 
     // Build transaction.
     let wrap_ix = skill_api::sdk::wrap(payer.pubkey());
+    let token_program = get_token_program(rpc).await?;
     let buyback_ix = skill_api::sdk::buyback(
         payer.pubkey(),
-        &response.swap_instruction.accounts,
-        &response.swap_instruction.data,
+        token_program,
+        min_ore_out,
+        &route.accounts,
+        &route.data,
     );
     simulate_transaction_with_address_lookup_tables(
         rpc,
@@ -486,7 +780,68 @@ async fn buyback(
     Ok(())
 }
 
-#[allow(dead_code)]
+async fn fund_mock_amm(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let sol_amount = std::env::var("SOL_AMOUNT").expect("Missing SOL_AMOUNT env var");
+    let sol_amount = u64::from_str(&sol_amount).expect("Invalid SOL_AMOUNT");
+    let ore_amount = std::env::var("ORE_AMOUNT").expect("Missing ORE_AMOUNT env var");
+    let ore_amount = u64::from_str(&ore_amount).expect("Invalid ORE_AMOUNT");
+
+    // Open pool vaults. The SKILL mint may belong to Token-2022, wrapped SOL never does.
+    let token_program = get_token_program(rpc).await?;
+    let pool_address = mock_amm::pool_pda().0;
+    let pool_sol_address = get_associated_token_address(&pool_address, &SOL_MINT);
+    let pool_ore_address =
+        get_associated_token_address_with_program_id(&pool_address, &MINT_ADDRESS, &token_program);
+    let mut ixs = vec![];
+    for (mint, mint_token_program) in [(SOL_MINT, spl_token::ID), (MINT_ADDRESS, token_program)] {
+        ixs.push(
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &pool_address,
+                &mint,
+                &mint_token_program,
+            ),
+        );
+    }
+
+    // Deposit liquidity.
+    if sol_amount > 0 {
+        ixs.push(solana_sdk::system_instruction::transfer(
+            &payer.pubkey(),
+            &pool_sol_address,
+            sol_amount,
+        ));
+        ixs.push(spl_token::instruction::sync_native(
+            &spl_token::ID,
+            &pool_sol_address,
+        )?);
+    }
+    if ore_amount > 0 {
+        ixs.push(spl_token_2022::instruction::transfer_checked(
+            &token_program,
+            &get_associated_token_address_with_program_id(
+                &payer.pubkey(),
+                &MINT_ADDRESS,
+                &token_program,
+            ),
+            &MINT_ADDRESS,
+            &pool_ore_address,
+            &payer.pubkey(),
+            &[],
+            ore_amount,
+            TOKEN_DECIMALS,
+        )?);
+    }
+    submit_transaction(rpc, payer, &ixs).await?;
+    println!("Mock AMM pool: {}", pool_address);
+    println!("  sol_vault: {}", pool_sol_address);
+    println!("  ore_vault: {}", pool_ore_address);
+    Ok(())
+}
+
 pub async fn get_address_lookup_table_accounts(
     rpc_client: &RpcClient,
     addresses: Vec<Pubkey>,
@@ -506,16 +861,17 @@ pub async fn get_address_lookup_table_accounts(
     Ok(accounts)
 }
 
-/// Schelling Point: Reset determines winner by majority vote (no entropy needed)
+/// Schelling Point: Reset determines winner by majority vote. In entropy RNG mode the config's
+/// entropy var supplies the round's randomness.
 async fn reset(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let board = get_board(rpc).await?;
+    let board = get_board(rpc, get_board_id()).await?;
     let config = get_config(rpc).await?;
 
     // Get current round to show what will be the winning square
-    if let Ok(round) = get_round(rpc, board.round_id).await {
+    if let Ok(round) = get_round(rpc, board.id, board.round_id).await {
         // Find the winning square (argmax of deployed)
         let (winning_square, max_deployed) = round
             .deployed
@@ -532,11 +888,15 @@ async fn reset(
         println!("  Miners on winner: {}", round.count[winning_square]);
     }
 
+    let token_program = get_token_program(rpc).await?;
     let reset_ix = skill_api::sdk::reset(
         payer.pubkey(),
+        board.id,
         config.fee_collector,
+        token_program,
         board.round_id,
         Pubkey::default(),
+        config.uses_entropy(),
     );
     let sig = submit_transaction(rpc, payer, &[reset_ix]).await?;
     println!("Reset transaction: {}", sig);
@@ -552,15 +912,20 @@ async fn deploy(
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
     let square_id = std::env::var("SQUARE").expect("Missing SQUARE env var");
     let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
-    let board = get_board(rpc).await?;
-    let mut squares = [false; 25];
-    squares[square_id as usize] = true;
+    let board = get_board(rpc, get_board_id()).await?;
+    if square_id >= board.squares {
+        return Err(anyhow::anyhow!("SQUARE must be 0-{}", board.squares - 1));
+    }
+    let squares = single_square(square_id as u8);
     let ix = skill_api::sdk::deploy(
         payer.pubkey(),
         payer.pubkey(),
+        board.id,
         amount,
         board.round_id,
         squares,
+        get_referrer(),
+        None,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
@@ -580,7 +945,7 @@ async fn play(
     let square_id = u64::from_str(&square_id).expect("Invalid SQUARE");
 
     // Get current state
-    let board = get_board(rpc).await?;
+    let board = get_board(rpc, get_board_id()).await?;
     let config = get_config(rpc).await?;
     let clock = get_clock(rpc).await?;
 
@@ -588,8 +953,10 @@ async fn play(
     let round_ended = board.end_slot != u64::MAX
         && clock.slot >= board.end_slot + INTERMISSION_SLOTS;
 
-    let mut squares = [false; 25];
-    squares[square_id as usize] = true;
+    if square_id >= board.squares {
+        return Err(anyhow::anyhow!("SQUARE must be 0-{}", board.squares - 1));
+    }
+    let squares = single_square(square_id as u8);
 
     if round_ended {
         println!("═══════════════════════════════════════════════════════════");
@@ -597,7 +964,7 @@ async fn play(
         println!("═══════════════════════════════════════════════════════════");
 
         // Show what will happen
-        if let Ok(round) = get_round(rpc, board.round_id).await {
+        if let Ok(round) = get_round(rpc, board.id, board.round_id).await {
             let (winning_square, max_deployed) = round
                 .deployed
                 .iter()
@@ -628,20 +995,29 @@ async fn play(
     }
 
     // Build and submit transaction (reset + deploy if needed)
+    let token_program = get_token_program(rpc).await?;
+    let guild = get_miner(rpc, payer.pubkey())
+        .await
+        .ok()
+        .and_then(|miner| guild_of(&miner));
     let instructions = skill_api::sdk::play(
         payer.pubkey(),
+        board.id,
         amount,
         squares,
         config.fee_collector,
+        token_program,
         board.round_id,
         round_ended,
+        get_referrer(),
+        guild,
     );
 
     let sig = submit_transaction(rpc, payer, &instructions).await?;
     println!("Transaction: {}", sig);
 
     // Show updated state
-    let new_board = get_board(rpc).await?;
+    let new_board = get_board(rpc, board.id).await?;
     println!("\nBoard state after play:");
     println!("  Round ID: {}", new_board.round_id);
     if new_board.end_slot != u64::MAX {
@@ -659,14 +1035,18 @@ async fn deploy_all(
 ) -> Result<(), anyhow::Error> {
     let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
     let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
-    let board = get_board(rpc).await?;
-    let squares = [true; 25];
+    let board = get_board(rpc, get_board_id()).await?;
+    let mut squares = [false; MAX_SQUARES];
+    squares[..board.squares as usize].fill(true);
     let ix = skill_api::sdk::deploy(
         payer.pubkey(),
         payer.pubkey(),
+        board.id,
         board.round_id,
         amount,
         squares,
+        get_referrer(),
+        None,
     );
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
@@ -676,126 +1056,1278 @@ async fn set_admin(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let ix = skill_api::sdk::set_admin(payer.pubkey(), payer.pubkey());
+    let admin = std::env::var("ADMIN").expect("Missing ADMIN env var");
+    let admin = Pubkey::from_str(&admin).expect("Invalid ADMIN");
+    let ix = skill_api::sdk::set_admin(payer.pubkey(), admin);
     submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Proposed admin {}. The new admin must run accept_admin.",
+        admin
+    );
     Ok(())
 }
 
-async fn set_swap_program(
+async fn accept_admin(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let swap_program = std::env::var("SWAP_PROGRAM").expect("Missing SWAP_PROGRAM env var");
-    let swap_program = Pubkey::from_str(&swap_program).expect("Invalid SWAP_PROGRAM");
-    let ix = skill_api::sdk::set_swap_program(payer.pubkey(), swap_program);
+    let ix = skill_api::sdk::accept_admin(payer.pubkey());
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn set_fee_collector(
+async fn set_bury_authority(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let fee_collector = std::env::var("FEE_COLLECTOR").expect("Missing FEE_COLLECTOR env var");
-    let fee_collector = Pubkey::from_str(&fee_collector).expect("Invalid FEE_COLLECTOR");
-    let ix = skill_api::sdk::set_fee_collector(payer.pubkey(), fee_collector);
+    let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let ix = skill_api::sdk::set_bury_authority(payer.pubkey(), authority);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn checkpoint(
+async fn set_fee_authority(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
-    let miner = get_miner(rpc, authority).await?;
-    let ix = skill_api::sdk::checkpoint(payer.pubkey(), authority, miner.round_id);
+    let ix = skill_api::sdk::set_fee_authority(payer.pubkey(), authority);
     submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn checkpoint_all(
+async fn set_pause_authority(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let clock = get_clock(rpc).await?;
-    let miners = get_miners(rpc).await?;
-    let mut expiry_slots = HashMap::new();
-    let mut ixs = vec![];
-    for (i, (_address, miner)) in miners.iter().enumerate() {
-        if miner.checkpoint_id < miner.round_id {
-            // Log the expiry slot for the round.
-            if !expiry_slots.contains_key(&miner.round_id) {
-                if let Ok(round) = get_round(rpc, miner.round_id).await {
-                    expiry_slots.insert(miner.round_id, round.expires_at);
-                }
-            }
-
-            // Get the expiry slot for the round.
-            let Some(expires_at) = expiry_slots.get(&miner.round_id) else {
-                continue;
-            };
-
-            // If we are in fee collection period, checkpoint the miner.
-            if clock.slot >= expires_at - TWELVE_HOURS_SLOTS {
-                println!(
-                    "[{}/{}] Checkpoint miner: {} ({} s)",
-                    i + 1,
-                    miners.len(),
-                    miner.authority,
-                    (expires_at - clock.slot) as f64 * 0.4
-                );
-                ixs.push(skill_api::sdk::checkpoint(
-                    payer.pubkey(),
-                    miner.authority,
-                    miner.round_id,
-                ));
-            }
-        }
-    }
+    let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let ix = skill_api::sdk::set_pause_authority(payer.pubkey(), authority);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
 
-    // Batch and submit the instructions.
-    while !ixs.is_empty() {
-        let batch = ixs
-            .drain(..std::cmp::min(10, ixs.len()))
-            .collect::<Vec<Instruction>>();
-        submit_transaction(rpc, payer, &batch).await?;
-    }
+async fn set_change_delay(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let delay = std::env::var("DELAY").expect("Missing DELAY env var");
+    let delay = u64::from_str(&delay).expect("Invalid DELAY");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_change_delay(payer.pubkey(), delay, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
 
+async fn set_buyback_cap(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let buyback_cap = std::env::var("BUYBACK_CAP").expect("Missing BUYBACK_CAP env var");
+    let buyback_cap = u64::from_str(&buyback_cap).expect("Invalid BUYBACK_CAP");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_buyback_cap(payer.pubkey(), buyback_cap, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-async fn close_all(
+async fn set_liq_manager(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
-    let rounds = get_rounds(rpc).await?;
-    let mut ixs = vec![];
-    let clock = get_clock(rpc).await?;
-    for (_i, (_address, round)) in rounds.iter().enumerate() {
-        if clock.slot >= round.expires_at {
-            ixs.push(skill_api::sdk::close(
-                payer.pubkey(),
-                round.id,
-                round.rent_payer,
-            ));
-        }
-    }
+    let liq_manager = std::env::var("LIQ_MANAGER").expect("Missing LIQ_MANAGER env var");
+    let liq_manager = Pubkey::from_str(&liq_manager).expect("Invalid LIQ_MANAGER");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_liq_manager(payer.pubkey(), liq_manager, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
 
-    // Batch and submit the instructions.
-    while !ixs.is_empty() {
-        let batch = ixs
-            .drain(..std::cmp::min(12, ixs.len()))
-            .collect::<Vec<Instruction>>();
-        // simulate_transaction(rpc, payer, &batch).await;
-        submit_transaction(rpc, payer, &batch).await?;
-    }
+async fn set_referral_fee(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let referral_fee = std::env::var("REFERRAL_FEE").expect("Missing REFERRAL_FEE env var");
+    let referral_fee = u64::from_str(&referral_fee).expect("Invalid REFERRAL_FEE");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_referral_fee(payer.pubkey(), referral_fee, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
 
+async fn set_vesting_period(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let vesting_period = std::env::var("VESTING_PERIOD").expect("Missing VESTING_PERIOD env var");
+    let vesting_period = u64::from_str(&vesting_period).expect("Invalid VESTING_PERIOD");
+    let config = get_config(rpc).await?;
+    let ix =
+        skill_api::sdk::set_vesting_period(payer.pubkey(), vesting_period, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
     Ok(())
 }
 
-// async fn log_meteora_pool(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+async fn set_motherlode_odds(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let odds = std::env::var("MOTHERLODE_ODDS").expect("Missing MOTHERLODE_ODDS env var");
+    let odds = u64::from_str(&odds).expect("Invalid MOTHERLODE_ODDS");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_motherlode_odds(payer.pubkey(), odds, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_motherlode_rate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let rate = std::env::var("MOTHERLODE_RATE").expect("Missing MOTHERLODE_RATE env var");
+    let rate = u64::from_str(&rate).expect("Invalid MOTHERLODE_RATE");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_motherlode_rate(payer.pubkey(), rate, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Schedules an RNG mode change, read from the RNG_MODE env var ("slot_hashes" or "entropy").
+async fn set_rng_mode(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let rng_mode = std::env::var("RNG_MODE").expect("Missing RNG_MODE env var");
+    let rng_mode = match rng_mode.as_str() {
+        "slot_hashes" => RNG_MODE_SLOT_HASHES,
+        "entropy" => RNG_MODE_ENTROPY,
+        _ => panic!("Invalid RNG_MODE"),
+    };
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_rng_mode(payer.pubkey(), rng_mode, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_bonus_square_count(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let count = std::env::var("BONUS_SQUARES").expect("Missing BONUS_SQUARES env var");
+    let count = u64::from_str(&count).expect("Invalid BONUS_SQUARES");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_bonus_square_count(payer.pubkey(), count, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_bonus_square_multiplier(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let multiplier = std::env::var("BONUS_MULTIPLIER").expect("Missing BONUS_MULTIPLIER env var");
+    let multiplier = u64::from_str(&multiplier).expect("Invalid BONUS_MULTIPLIER");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_bonus_square_multiplier(
+        payer.pubkey(),
+        multiplier,
+        config.change_count,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// Arms the board's entropy var for the end of the board's current round.
+async fn arm_entropy(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc, get_board_id()).await?;
+    let var_address = board_var_pda(board_pda(board.id).0).0;
+    let ix = skill_api::sdk::arm_entropy(payer.pubkey(), board.id);
+    let sig = submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Armed {} for round #{} (slot {}): {}",
+        var_address, board.round_id, board.end_slot, sig
+    );
+    Ok(())
+}
+
+/// The board parameters, read from the MIN_DEPLOY, DEPLOY_SLOTS, COMMIT_SLOTS, REVEAL_SLOTS,
+/// ADMIN_FEE, WEIGHT and SQUARES env vars.
+fn get_board_params() -> (u64, u64, u64, u64, u64, u64, u64) {
+    let param = |name: &str, default: u64| {
+        std::env::var(name)
+            .map(|s| u64::from_str(&s).expect(&format!("Invalid {}", name)))
+            .unwrap_or(default)
+    };
+    (
+        param("MIN_DEPLOY", 0),
+        param("DEPLOY_SLOTS", Round::DEPLOY_PHASE_SLOTS),
+        param("COMMIT_SLOTS", Round::COMMIT_PHASE_SLOTS),
+        param("REVEAL_SLOTS", Round::REVEAL_PHASE_SLOTS),
+        param("ADMIN_FEE", DEFAULT_BOARD_ADMIN_FEE),
+        param("WEIGHT", DEFAULT_BOARD_WEIGHT),
+        param("SQUARES", MAX_SQUARES as u64),
+    )
+}
+
+async fn new_board(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let (min_deploy, deploy_slots, commit_slots, reveal_slots, admin_fee, weight, squares) =
+        get_board_params();
+    let ix = skill_api::sdk::new_board(
+        payer.pubkey(),
+        config.board_count,
+        min_deploy,
+        deploy_slots,
+        commit_slots,
+        reveal_slots,
+        admin_fee,
+        weight,
+        squares,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Board #{}: {}",
+        config.board_count,
+        board_pda(config.board_count).0
+    );
+    Ok(())
+}
+
+async fn update_board(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let (min_deploy, deploy_slots, commit_slots, reveal_slots, _, _, squares) = get_board_params();
+    // Read the round id directly, as boards from before multiple boards have an older layout.
+    let board_id = get_board_id();
+    let data = rpc.get_account_data(&board_pda(board_id).0).await?;
+    let round_id = u64::from_le_bytes(data[8..16].try_into()?);
+    let ix = skill_api::sdk::update_board(
+        payer.pubkey(),
+        board_id,
+        round_id,
+        min_deploy,
+        deploy_slots,
+        commit_slots,
+        reveal_slots,
+        squares,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn new_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let start_round = std::env::var("START_ROUND").expect("Missing START_ROUND env var");
+    let start_round = u64::from_str(&start_round).expect("Invalid START_ROUND");
+    let end_round = std::env::var("END_ROUND").expect("Missing END_ROUND env var");
+    let end_round = u64::from_str(&end_round).expect("Invalid END_ROUND");
+    let prize = std::env::var("PRIZE").unwrap_or("0".to_string());
+    let prize = u64::from_str(&prize).expect("Invalid PRIZE");
+    let ix = skill_api::sdk::new_season(
+        payer.pubkey(),
+        config.season_count,
+        get_board_id(),
+        start_round,
+        end_round,
+        prize,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Season #{}: {}",
+        config.season_count,
+        season_pda(config.season_count).0
+    );
+    Ok(())
+}
+
+async fn claim_season(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("SEASON").expect("Missing SEASON env var");
+    let id = u64::from_str(&id).expect("Invalid SEASON");
+    let season = get_season(rpc, id).await?;
+    let ix = skill_api::sdk::claim_season(payer.pubkey(), id, season.board_id, season.end_round);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn new_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let name = std::env::var("NAME").expect("Missing NAME env var");
+    if name.len() > 32 {
+        return Err(anyhow::anyhow!("NAME must be at most 32 bytes"));
+    }
+    let mut name_bytes = [0u8; 32];
+    name_bytes[..name.len()].copy_from_slice(name.as_bytes());
+    let executor = std::env::var("EXECUTOR").unwrap_or(payer.pubkey().to_string());
+    let executor = Pubkey::from_str(&executor).expect("Invalid EXECUTOR");
+    let revenue_share = std::env::var("REVENUE_SHARE").unwrap_or("0".to_string());
+    let revenue_share = u64::from_str(&revenue_share).expect("Invalid REVENUE_SHARE");
+    let ix = skill_api::sdk::new_guild(
+        payer.pubkey(),
+        config.guild_count,
+        name_bytes,
+        executor,
+        revenue_share,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Guild #{}: {}",
+        config.guild_count,
+        guild_pda(config.guild_count).0
+    );
+    Ok(())
+}
+
+async fn update_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_guild_id();
+    let guild = get_guild(rpc, id).await?;
+    let executor = std::env::var("EXECUTOR").unwrap_or(guild.executor.to_string());
+    let executor = Pubkey::from_str(&executor).expect("Invalid EXECUTOR");
+    let revenue_share = std::env::var("REVENUE_SHARE").unwrap_or(guild.revenue_share.to_string());
+    let revenue_share = u64::from_str(&revenue_share).expect("Invalid REVENUE_SHARE");
+    let ix = skill_api::sdk::update_guild(payer.pubkey(), id, executor, revenue_share);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn join_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = skill_api::sdk::join_guild(payer.pubkey(), get_guild_id());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn leave_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let miner = get_miner(rpc, payer.pubkey()).await?;
+    let Some(guild) = guild_of(&miner) else {
+        return Err(anyhow::anyhow!("Miner is not in a guild"));
+    };
+    let ix = skill_api::sdk::leave_guild(payer.pubkey(), guild);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn deposit_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = skill_api::sdk::deposit_guild(payer.pubkey(), get_guild_id(), amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn fund_member(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let member = std::env::var("MEMBER").expect("Missing MEMBER env var");
+    let member = Pubkey::from_str(&member).expect("Invalid MEMBER");
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let ix = skill_api::sdk::fund_member(payer.pubkey(), get_guild_id(), member, amount);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn claim_guild(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let token_program = get_token_program(rpc).await?;
+    let ix = skill_api::sdk::claim_guild(payer.pubkey(), get_guild_id(), token_program);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn new_tournament(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let start_round = std::env::var("START_ROUND").expect("Missing START_ROUND env var");
+    let start_round = u64::from_str(&start_round).expect("Invalid START_ROUND");
+    let players = std::env::var("PLAYERS").unwrap_or("4".to_string());
+    let players = u64::from_str(&players).expect("Invalid PLAYERS");
+    let entry_fee = std::env::var("ENTRY_FEE").unwrap_or("0".to_string());
+    let entry_fee = u64::from_str(&entry_fee).expect("Invalid ENTRY_FEE");
+    let prize = std::env::var("PRIZE").unwrap_or("0".to_string());
+    let prize = u64::from_str(&prize).expect("Invalid PRIZE");
+    let ix = skill_api::sdk::new_tournament(
+        payer.pubkey(),
+        config.tournament_count,
+        get_board_id(),
+        start_round,
+        players,
+        entry_fee,
+        prize,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    println!(
+        "Tournament #{}: {}",
+        config.tournament_count,
+        tournament_pda(config.tournament_count).0
+    );
+    Ok(())
+}
+
+async fn join_tournament(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_tournament_id();
+    let tournament = get_tournament(rpc, id).await?;
+    let ix = skill_api::sdk::join_tournament(payer.pubkey(), id, tournament.board_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn score_tournament(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_tournament_id();
+    let tournament = get_tournament(rpc, id).await?;
+    let board = get_board(rpc, tournament.board_id).await?;
+
+    // Score every matchday whose round has been reset.
+    let mut ixs = vec![];
+    let scored = tournament.scored.count_ones() as u64;
+    for matchday in scored..tournament.matchdays() {
+        let round_id = tournament.start_round + matchday;
+        if round_id >= board.round_id {
+            break;
+        }
+        ixs.push(skill_api::sdk::score_tournament(
+            payer.pubkey(),
+            id,
+            tournament.board_id,
+            round_id,
+        ));
+    }
+    if ixs.is_empty() {
+        println!("No matchdays ready to score");
+        return Ok(());
+    }
+    submit_transaction(rpc, payer, &ixs).await?;
+    Ok(())
+}
+
+async fn settle_tournament(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = get_tournament_id();
+    let tournament = get_tournament(rpc, id).await?;
+    let ix = skill_api::sdk::settle_tournament(
+        payer.pubkey(),
+        id,
+        tournament.board_id,
+        tournament.authority,
+        &tournament.players[..tournament.player_count as usize],
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn update_metadata_uri(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let uri = std::env::var("URI").expect("Missing URI env var");
+    let ix = skill_api::sdk::update_metadata_uri(payer.pubkey(), &uri);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn execute_change(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let change = get_change(rpc, id).await?;
+    let board_id = ChangeKind::from_u64(change.kind)
+        .is_board_change()
+        .then(|| change.value_board_id());
+    let ix = skill_api::sdk::execute_change(payer.pubkey(), id, change.proposer, board_id);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn cancel_change(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let id = std::env::var("ID").expect("Missing ID env var");
+    let id = u64::from_str(&id).expect("Invalid ID");
+    let change = get_change(rpc, id).await?;
+    let ix = skill_api::sdk::cancel_change(payer.pubkey(), id, change.proposer);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_pause(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let pause = std::env::var("PAUSE").expect("Missing PAUSE env var");
+    let paused = pause
+        .split(',')
+        .map(|flag| match flag.trim() {
+            "none" => 0,
+            "all" => PAUSE_ALL,
+            "deploy" => PAUSE_DEPLOY,
+            "reset" => PAUSE_RESET,
+            "checkpoint" => PAUSE_CHECKPOINT,
+            "claim" => PAUSE_CLAIM,
+            "stake" => PAUSE_STAKE,
+            "withdraw" => PAUSE_WITHDRAW,
+            other => u64::from_str(other).expect("Invalid PAUSE"),
+        })
+        .fold(0, |acc, flag| acc | flag);
+    let ix = skill_api::sdk::set_pause(payer.pubkey(), paused);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn migrate_config(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = skill_api::sdk::migrate_config(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_swap_program(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let swap_program = std::env::var("SWAP_PROGRAM").expect("Missing SWAP_PROGRAM env var");
+    let swap_program = Pubkey::from_str(&swap_program).expect("Invalid SWAP_PROGRAM");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_swap_program(payer.pubkey(), swap_program, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn set_fee_collector(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let fee_collector = std::env::var("FEE_COLLECTOR").expect("Missing FEE_COLLECTOR env var");
+    let fee_collector = Pubkey::from_str(&fee_collector).expect("Invalid FEE_COLLECTOR");
+    let config = get_config(rpc).await?;
+    let ix = skill_api::sdk::set_fee_collector(payer.pubkey(), fee_collector, config.change_count);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn checkpoint(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let miner = get_miner(rpc, authority).await?;
+    let seasons = get_seasons(rpc).await?;
+    let ix = skill_api::sdk::checkpoint(
+        payer.pubkey(),
+        authority,
+        miner.board_id,
+        miner.round_id,
+        guild_of(&miner),
+        season_for_round(&seasons, miner.board_id, miner.round_id),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn checkpoint_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let clock = get_clock(rpc).await?;
+    let miners = get_miners(rpc).await?;
+    let seasons = get_seasons(rpc).await?;
+    let mut expiry_slots = HashMap::new();
+    let mut rounds: HashMap<(u64, u64), Vec<CheckpointMiner>> = HashMap::new();
+    for (i, (_address, miner)) in miners.iter().enumerate() {
+        if miner.checkpoint_id < miner.round_id {
+            // Log the expiry slot for the round.
+            let key = (miner.board_id, miner.round_id);
+            if !expiry_slots.contains_key(&key) {
+                if let Ok(round) = get_round(rpc, miner.board_id, miner.round_id).await {
+                    expiry_slots.insert(key, round.expires_at);
+                }
+            }
+
+            // Get the expiry slot for the round.
+            let Some(expires_at) = expiry_slots.get(&key) else {
+                continue;
+            };
+
+            // If we are in fee collection period, checkpoint the miner.
+            if clock.slot >= expires_at - TWELVE_HOURS_SLOTS {
+                println!(
+                    "[{}/{}] Checkpoint miner: {} ({} s)",
+                    i + 1,
+                    miners.len(),
+                    miner.authority,
+                    (expires_at - clock.slot) as f64 * 0.4
+                );
+                rounds
+                    .entry(key)
+                    .or_default()
+                    .push(checkpoint_miner_of(miner));
+            }
+        }
+    }
+
+    // Checkpoint the miners of each round in batches.
+    for ((board_id, round_id), miners) in rounds {
+        let season_id = season_for_round(&seasons, board_id, round_id);
+        for batch in skill_api::sdk::checkpoint_many_batches(&miners, season_id.is_some()) {
+            let ix = skill_api::sdk::checkpoint_many(
+                payer.pubkey(),
+                board_id,
+                round_id,
+                batch,
+                season_id,
+            );
+            submit_transaction(rpc, payer, &[ix]).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Simulates checkpoint_many for growing batches of the miners left to checkpoint in a ROUND of
+/// the BOARD (default: the previous round), logging the compute units and transaction size of
+/// each batch size.
+async fn checkpoint_units(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let board = get_board(rpc, get_board_id()).await?;
+    let round_id = std::env::var("ROUND")
+        .map(|r| u64::from_str(&r).expect("Invalid ROUND"))
+        .unwrap_or(board.round_id.saturating_sub(1));
+    let seasons = get_seasons(rpc).await?;
+    let miners = get_miners_participating(rpc, round_id)
+        .await?
+        .into_iter()
+        .filter(|(_, m)| m.board_id == board.id && m.checkpoint_id < m.round_id)
+        .map(|(_, m)| checkpoint_miner_of(&m))
+        .collect::<Vec<_>>();
+    let season_id = season_for_round(&seasons, board.id, round_id);
+    println!("Round #{}: {} miners to checkpoint", round_id, miners.len());
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let mut accounts = 0;
+    for n in 1..=miners.len() {
+        accounts += miners[n - 1].account_count(season_id.is_some());
+        let ixs = [
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            skill_api::sdk::checkpoint_many(
+                payer.pubkey(),
+                board.id,
+                round_id,
+                &miners[..n],
+                season_id,
+            ),
+        ];
+        let tx =
+            Transaction::new_signed_with_payer(&ixs, Some(&payer.pubkey()), &[payer], blockhash);
+        let size = bincode::serialize(&tx)?.len();
+        if size > solana_sdk::packet::PACKET_DATA_SIZE {
+            println!(
+                "  {} miners ({} accounts): {} bytes, too large",
+                n, accounts, size
+            );
+            break;
+        }
+        let result = rpc.simulate_transaction(&tx).await?.value;
+        println!(
+            "  {} miners ({} accounts): {} bytes, {} CU{}",
+            n,
+            accounts,
+            size,
+            result.units_consumed.unwrap_or_default(),
+            result
+                .err
+                .map(|e| format!(" ({:?})", e))
+                .unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// Reports the compute units consumed by each program instruction across recent successful
+/// transactions, read from the "consumed" line the runtime logs for every top-level invocation.
+/// The measurements are saved to OUTPUT as JSON, and compared against a BASELINE saved from
+/// another build if one is given.
+async fn compute_units(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let limit = std::env::var("LIMIT")
+        .map(|l| usize::from_str(&l).expect("Invalid LIMIT"))
+        .unwrap_or(100);
+    let signatures = rpc.get_signatures_for_address(&skill_api::ID).await?;
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+    let consumed_prefix = format!("{} consumed ", skill_api::ID);
+    let mut units: HashMap<String, Vec<u64>> = HashMap::new();
+    for status in signatures.iter().filter(|s| s.err.is_none()).take(limit) {
+        let signature = Signature::from_str(&status.signature)?;
+        let tx = rpc.get_transaction_with_config(&signature, config).await?;
+        let Some(meta) = tx.transaction.meta else {
+            continue;
+        };
+        if meta.err.is_some() {
+            continue;
+        }
+        let Some(tx) = tx.transaction.transaction.decode() else {
+            continue;
+        };
+        let keys = tx.message.static_account_keys();
+        let instructions = tx.message.instructions();
+
+        // Walk the logs, tracking the invoke depth to attribute each top-level "consumed" line
+        // to its instruction.
+        let mut index = None;
+        let mut depth = 0;
+        let logs = Option::<Vec<String>>::from(meta.log_messages).unwrap_or_default();
+        for line in logs.iter() {
+            let Some(rest) = line.strip_prefix("Program ") else {
+                continue;
+            };
+            // Skip "Program log:", "Program data:" and "Program return:" lines.
+            let Some((program, tail)) = rest.split_once(' ') else {
+                continue;
+            };
+            if program.ends_with(':') {
+                continue;
+            }
+            if tail.starts_with("invoke [") {
+                depth += 1;
+                if depth == 1 {
+                    index = Some(index.map_or(0, |i| i + 1));
+                }
+            } else if tail == "success" || tail.starts_with("failed") {
+                depth -= 1;
+            } else if depth == 1 {
+                let Some(consumed) = rest.strip_prefix(&consumed_prefix) else {
+                    continue;
+                };
+                let Some(ix) = index.and_then(|i| instructions.get(i)) else {
+                    continue;
+                };
+                if keys[ix.program_id_index as usize] != skill_api::ID {
+                    continue;
+                }
+                let name = ix
+                    .data
+                    .first()
+                    .and_then(|d| OreInstruction::try_from(*d).ok())
+                    .map(|i| format!("{:?}", i))
+                    .unwrap_or("Unknown".to_string());
+                let cu = consumed.split(' ').next().unwrap_or_default();
+                units.entry(name).or_default().push(u64::from_str(cu)?);
+            }
+        }
+    }
+
+    // Summarize the measurements, with the change in average from the baseline.
+    let baseline: HashMap<String, ComputeUnits> = match std::env::var("BASELINE") {
+        Ok(path) => serde_json::from_str(&std::fs::read_to_string(path)?)?,
+        Err(_) => HashMap::new(),
+    };
+    let summary = units
+        .iter()
+        .map(|(name, samples)| (name.clone(), ComputeUnits::from_samples(samples)))
+        .collect::<HashMap<_, _>>();
+    let mut names = summary.keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    println!(
+        "{:<28} {:>6} {:>10} {:>10} {:>10}",
+        "Instruction", "Count", "Avg CU", "Max CU", "Avg diff"
+    );
+    for name in names {
+        let cu = &summary[&name];
+        let diff = baseline
+            .get(&name)
+            .map(|b| format!("{:+}", cu.avg as i64 - b.avg as i64))
+            .unwrap_or_default();
+        println!(
+            "{:<28} {:>6} {:>10} {:>10} {:>10}",
+            name, cu.count, cu.avg, cu.max, diff
+        );
+    }
+    if let Ok(path) = std::env::var("OUTPUT") {
+        std::fs::write(&path, serde_json::to_string_pretty(&summary)?)?;
+        println!("Saved to {}", path);
+    }
+    Ok(())
+}
+
+/// The compute units consumed by one instruction across a set of transactions.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ComputeUnits {
+    count: usize,
+    avg: u64,
+    max: u64,
+}
+
+impl ComputeUnits {
+    fn from_samples(samples: &[u64]) -> Self {
+        Self {
+            count: samples.len(),
+            avg: samples.iter().sum::<u64>() / samples.len() as u64,
+            max: samples.iter().copied().max().unwrap_or_default(),
+        }
+    }
+}
+
+/// Estimated lamports paid per executor transaction: the base signature fee plus the priority
+/// fee set in submit_transaction (1.4M compute units at 1 lamport per compute unit).
+const EXECUTOR_TX_COST: u64 = 5_000 + 1_400_000;
+
+/// The number of automations serviced per executor transaction.
+const EXECUTOR_BATCH_SIZE: usize = 3;
+
+/// Fees earned and transaction costs paid while executing an automation.
+#[derive(Default)]
+struct ExecutorStats {
+    deploys: u64,
+    fees: u64,
+    costs: u64,
+}
+
+impl ExecutorStats {
+    fn profit(&self) -> i64 {
+        self.fees as i64 - self.costs as i64
+    }
+}
+
+/// Runs as an automation executor, deploying for every automation on the BOARD naming the payer
+/// as its executor once per round. Automations with a reveal mode are also committed in the
+/// commit phase and revealed in the reveal phase.
+async fn executor(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let interval = std::env::var("INTERVAL").unwrap_or("2000".to_string());
+    let interval = u64::from_str(&interval).expect("Invalid INTERVAL");
+    let mut stats: HashMap<Pubkey, ExecutorStats> = HashMap::new();
+    let mut last_round_id = None;
+    let mut last_commit_round_id = None;
+    let mut last_reveal_round_id = None;
+    loop {
+        match execute_round(rpc, payer, &mut stats, last_round_id).await {
+            Ok(round_id) => last_round_id = round_id.or(last_round_id),
+            Err(e) => println!("Executor error: {:?}", e),
+        }
+        match execute_commits(rpc, payer, last_commit_round_id).await {
+            Ok(round_id) => last_commit_round_id = round_id.or(last_commit_round_id),
+            Err(e) => println!("Executor commit error: {:?}", e),
+        }
+        match execute_reveals(rpc, payer, last_reveal_round_id).await {
+            Ok(round_id) => last_reveal_round_id = round_id.or(last_reveal_round_id),
+            Err(e) => println!("Executor reveal error: {:?}", e),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(interval)).await;
+    }
+}
+
+/// Deploys for all automations of the current round, if it has not been serviced yet.
+/// Returns the round id once the round has been serviced, which takes at least one deploy to
+/// land, so the round is retried while it accepts deploys if all of them failed.
+async fn execute_round(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    stats: &mut HashMap<Pubkey, ExecutorStats>,
+    last_round_id: Option<u64>,
+) -> Result<Option<u64>, anyhow::Error> {
+    // Wait for a new round that is still accepting deploys.
+    let board = get_board(rpc, get_board_id()).await?;
+    let clock = get_clock(rpc).await?;
+    if last_round_id == Some(board.round_id) {
+        return Ok(None);
+    }
+    if board.end_slot != u64::MAX && clock.slot >= board.end_slot {
+        return Ok(None);
+    }
+
+    // Build the instructions for each automation.
+    let seasons = get_seasons(rpc).await?;
+    let automations = get_automations_by_executor(rpc, payer.pubkey())
+        .await?
+        .into_iter()
+        .filter(|(_, a)| a.board_id == board.id)
+        .collect::<Vec<_>>();
+    println!(
+        "Round #{}: executing {} automations",
+        board.round_id,
+        automations.len()
+    );
+    let mut jobs = vec![];
+    for (_address, automation) in automations.iter() {
+        let mut ixs = vec![];
+        if let Ok(miner) = get_miner(rpc, automation.authority).await {
+            // Skip miners that already deployed this round.
+            if miner.board_id == board.id
+                && miner.round_id == board.round_id
+                && miner.deployed.iter().any(|&d| d > 0)
+            {
+                continue;
+            }
+
+            // Checkpoint the previous round so the miner can deploy again.
+            if miner.checkpoint_id < miner.round_id {
+                ixs.push(skill_api::sdk::checkpoint(
+                    payer.pubkey(),
+                    automation.authority,
+                    miner.board_id,
+                    miner.round_id,
+                    guild_of(&miner),
+                    season_for_round(&seasons, miner.board_id, miner.round_id),
+                ));
+            }
+        }
+
+        // Reload SOL winnings into the automation balance.
+        if automation.reload > 0 {
+            ixs.push(skill_api::sdk::reload_sol(
+                payer.pubkey(),
+                automation.authority,
+                funding_guild_of(automation),
+            ));
+        }
+
+        // Deploy. The program uses the automation's amount and strategy.
+        ixs.push(skill_api::sdk::deploy(
+            payer.pubkey(),
+            automation.authority,
+            board.id,
+            automation.amount,
+            board.round_id,
+            [false; MAX_SQUARES],
+            Pubkey::default(),
+            funding_guild_of(automation),
+        ));
+        jobs.push((*automation, ixs));
+    }
+
+    // Batch and submit the instructions, falling back to one automation per transaction if a
+    // batch fails so a single closed or capped automation cannot block the others.
+    let mut landed = 0;
+    for batch in jobs.chunks(EXECUTOR_BATCH_SIZE) {
+        let ixs = batch
+            .iter()
+            .flat_map(|(_, ixs)| ixs.clone())
+            .collect::<Vec<Instruction>>();
+        let cost = EXECUTOR_TX_COST / batch.len() as u64;
+        if submit_transaction(rpc, payer, &ixs).await.is_ok() {
+            for (automation, _) in batch {
+                record_execution(stats, automation, cost);
+            }
+            landed += batch.len();
+            continue;
+        }
+        for (automation, ixs) in batch {
+            if submit_transaction(rpc, payer, ixs).await.is_ok() {
+                record_execution(stats, automation, EXECUTOR_TX_COST);
+                landed += 1;
+            }
+        }
+    }
+
+    // Log profit per automation.
+    let mut total = 0;
+    for (authority, s) in stats.iter() {
+        total += s.profit();
+        println!(
+            "  {}: {} deploys, {} SOL fees, {} SOL costs, {} SOL profit",
+            authority,
+            s.deploys,
+            s.fees as f64 / LAMPORTS_PER_SOL as f64,
+            s.costs as f64 / LAMPORTS_PER_SOL as f64,
+            s.profit() as f64 / LAMPORTS_PER_SOL as f64
+        );
+    }
+    println!(
+        "  Total profit: {} SOL",
+        total as f64 / LAMPORTS_PER_SOL as f64
+    );
+
+    if !jobs.is_empty() && landed == 0 {
+        println!("Round #{}: no deploys landed, retrying", board.round_id);
+        return Ok(None);
+    }
+    Ok(Some(board.round_id))
+}
+
+fn record_execution(
+    stats: &mut HashMap<Pubkey, ExecutorStats>,
+    automation: &Automation,
+    cost: u64,
+) {
+    let s = stats.entry(automation.authority).or_default();
+    s.deploys += 1;
+    s.fees += automation.fee;
+    s.costs += cost;
+}
+
+/// The salt an executor commits with on behalf of an automation in a round. It is derived from
+/// the executor, the automation and the round, so the executor can reveal without storing it.
+/// The square it hides is the miner's largest deployment, which is public anyway.
+fn executor_salt(executor: &Pubkey, automation: &Pubkey, round_id: u64) -> [u8; 16] {
+    let hash = keccak::hashv(&[
+        executor.as_ref(),
+        automation.as_ref(),
+        &round_id.to_le_bytes(),
+    ]);
+    hash.to_bytes()[..16].try_into().unwrap()
+}
+
+/// The squares and salts of an automation's pre-committed hashes, if its authority left them in
+/// the SALTS directory.
+fn read_salts(automation: &Pubkey) -> Vec<(u8, [u8; 16])> {
+    salts_path(automation)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// The automations run by the payer that commit on behalf of their authority, with the miners
+/// that deployed in the current round.
+async fn executor_reveal_jobs(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    board: &Board,
+) -> Result<Vec<(Pubkey, Automation, Miner)>, anyhow::Error> {
+    let automations = get_automations_by_executor(rpc, payer.pubkey())
+        .await?
+        .into_iter()
+        .filter(|(_, a)| {
+            a.board_id == board.id
+                && AutomationReveal::from_u64(a.reveal_mode) != AutomationReveal::None
+        })
+        .collect::<Vec<_>>();
+    let mut jobs = vec![];
+    for (address, automation) in automations {
+        let Ok(miner) = get_miner(rpc, automation.authority).await else {
+            continue;
+        };
+        if miner.board_id == board.id && miner.round_id == board.round_id {
+            jobs.push((address, automation, miner));
+        }
+    }
+    Ok(jobs)
+}
+
+/// Submits the instructions of an executor phase in batches, falling back to one instruction
+/// per transaction if a batch fails. Returns whether all instructions landed.
+async fn submit_executor_batches(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    ixs: &[Instruction],
+) -> bool {
+    let mut landed = true;
+    for batch in ixs.chunks(EXECUTOR_BATCH_SIZE) {
+        if submit_transaction(rpc, payer, batch).await.is_ok() {
+            continue;
+        }
+        for ix in batch {
+            if let Err(e) = submit_transaction(rpc, payer, &[ix.clone()]).await {
+                println!("  {:?}", e);
+                landed = false;
+            }
+        }
+    }
+    landed
+}
+
+/// Commits for all automations with a reveal mode once the current round's commit phase
+/// starts. Automations with pre-committed hashes use their next hash. Automations that reveal
+/// their deployed square commit to it with the executor's salt. Returns the round id once all
+/// commits have landed.
+async fn execute_commits(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    last_round_id: Option<u64>,
+) -> Result<Option<u64>, anyhow::Error> {
+    let board = get_board(rpc, get_board_id()).await?;
+    if last_round_id == Some(board.round_id) || board.end_slot == u64::MAX {
+        return Ok(None);
+    }
+    let clock = get_clock(rpc).await?;
+    let round = get_round(rpc, board.id, board.round_id).await?;
+    if !round.is_commit_phase(clock.slot) {
+        return Ok(None);
+    }
+
+    let mut ixs = vec![];
+    for (address, automation, miner) in executor_reveal_jobs(rpc, payer, &board).await? {
+        if miner.has_commitment_for_round(board.round_id) {
+            continue;
+        }
+        let commitment = match AutomationReveal::from_u64(automation.reveal_mode) {
+            AutomationReveal::Deployed => {
+                let salt = executor_salt(&payer.pubkey(), &address, board.round_id);
+                Miner::compute_commitment(miner.max_deployed_square(), &salt, &miner.authority)
+            }
+            // The program submits the automation's next pre-committed hash.
+            _ => [0; 32],
+        };
+        ixs.push(skill_api::sdk::submit_commit(
+            payer.pubkey(),
+            automation.authority,
+            board.id,
+            board.round_id,
+            commitment,
+        ));
+    }
+    println!(
+        "Round #{}: committing for {} automations",
+        board.round_id,
+        ixs.len()
+    );
+    if !submit_executor_batches(rpc, payer, &ixs).await {
+        return Ok(None);
+    }
+    Ok(Some(board.round_id))
+}
+
+/// Reveals for all automations with a reveal mode once the current round's reveal phase starts.
+/// Automations that commit to their deployed square reveal it with the executor's salt.
+/// Automations with pre-committed hashes reveal the square and salt their authority left in the
+/// SALTS directory. Returns the round id once all reveals have landed.
+async fn execute_reveals(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+    last_round_id: Option<u64>,
+) -> Result<Option<u64>, anyhow::Error> {
+    let board = get_board(rpc, get_board_id()).await?;
+    if last_round_id == Some(board.round_id) || board.end_slot == u64::MAX {
+        return Ok(None);
+    }
+    let clock = get_clock(rpc).await?;
+    let round = get_round(rpc, board.id, board.round_id).await?;
+    if !round.is_reveal_phase(clock.slot) || clock.slot >= board.end_slot {
+        return Ok(None);
+    }
+
+    let mut ixs = vec![];
+    for (address, automation, miner) in executor_reveal_jobs(rpc, payer, &board).await? {
+        if !miner.has_commitment_for_round(board.round_id)
+            || miner.has_revealed_for_round(board.round_id)
+        {
+            continue;
+        }
+        let (square, salt) = match AutomationReveal::from_u64(automation.reveal_mode) {
+            AutomationReveal::Deployed => (
+                miner.max_deployed_square(),
+                executor_salt(&payer.pubkey(), &address, board.round_id),
+            ),
+            _ => {
+                let choice = read_salts(&address)
+                    .into_iter()
+                    .find(|(square, salt)| miner.verify_commitment(*square, salt));
+                let Some(choice) = choice else {
+                    println!("  No salt for {} in SALTS", address);
+                    continue;
+                };
+                choice
+            }
+        };
+        ixs.push(skill_api::sdk::reveal_choice(
+            payer.pubkey(),
+            automation.authority,
+            board.id,
+            board.round_id,
+            square,
+            salt,
+            None,
+        ));
+    }
+    println!(
+        "Round #{}: revealing for {} automations",
+        board.round_id,
+        ixs.len()
+    );
+    if !submit_executor_batches(rpc, payer, &ixs).await {
+        return Ok(None);
+    }
+    Ok(Some(board.round_id))
+}
+
+async fn close_all(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let config = get_config(rpc).await?;
+    let rounds = get_rounds(rpc).await?;
+    let mut ixs = vec![];
+    let clock = get_clock(rpc).await?;
+    for (_i, (address, round)) in rounds.iter().enumerate() {
+        if clock.slot >= round.expires_at {
+            // Rounds do not store their board, so find it from the round address.
+            let Some(board_id) =
+                (0..config.board_count).find(|&b| round_pda(b, round.id).0 == *address)
+            else {
+                continue;
+            };
+            ixs.push(skill_api::sdk::close(
+                payer.pubkey(),
+                board_id,
+                round.id,
+                round.rent_payer,
+            ));
+        }
+    }
+
+    // Batch and submit the instructions.
+    while !ixs.is_empty() {
+        let batch = ixs
+            .drain(..std::cmp::min(12, ixs.len()))
+            .collect::<Vec<Instruction>>();
+        // simulate_transaction(rpc, payer, &batch).await;
+        submit_transaction(rpc, payer, &batch).await?;
+    }
+
+    Ok(())
+}
+
+// async fn log_meteora_pool(rpc: &RpcClient) -> Result<(), anyhow::Error> {
 //     let address = pubkey!("GgaDTFbqdgjoZz3FP7zrtofGwnRS4E6MCzmmD5Ni1Mxj");
 //     let pool = get_meteora_pool(rpc, address).await?;
 //     let vault_a = get_meteora_vault(rpc, pool.a_vault).await?;
@@ -835,6 +2367,154 @@ async fn close_all(
 //     Ok(())
 // }
 
+/// Create or update the payer's automation account.
+/// Usage: COMMAND=automate AMOUNT=<lamports> DEPOSIT=<lamports> EXECUTOR=<pubkey> FEE=<lamports> STRATEGY=<name> MASK=<u64> BOARD=<id> cargo run -p skill-cli
+async fn automate(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let amount = std::env::var("AMOUNT").expect("Missing AMOUNT env var");
+    let amount = u64::from_str(&amount).expect("Invalid AMOUNT");
+    let deposit = std::env::var("DEPOSIT").unwrap_or("0".to_string());
+    let deposit = u64::from_str(&deposit).expect("Invalid DEPOSIT");
+    let executor = std::env::var("EXECUTOR").expect("Missing EXECUTOR env var");
+    let executor = Pubkey::from_str(&executor).expect("Invalid EXECUTOR");
+    let fee = std::env::var("FEE").unwrap_or("0".to_string());
+    let fee = u64::from_str(&fee).expect("Invalid FEE");
+    let mask = std::env::var("MASK").unwrap_or("0".to_string());
+    let mask = u64::from_str(&mask).expect("Invalid MASK");
+    let strategy = std::env::var("STRATEGY").unwrap_or("random".to_string());
+    let strategy = parse_strategy(&strategy).expect("Invalid STRATEGY");
+    let reload = std::env::var("RELOAD")
+        .map(|s| s == "true")
+        .unwrap_or(false);
+    let guild = get_automation(rpc, automation_pda(payer.pubkey()).0)
+        .await
+        .ok()
+        .and_then(|automation| funding_guild_of(&automation));
+    let ix = skill_api::sdk::automate(
+        payer.pubkey(),
+        get_board_id(),
+        amount,
+        deposit,
+        executor,
+        fee,
+        mask,
+        strategy as u8,
+        reload,
+        get_referrer(),
+        guild,
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+/// A salt for an automation's `index`th pre-committed hash. It is derived from the authority's
+/// signature, so only the authority, and the executor it hands the salts to, can reveal.
+fn committed_salt(
+    authority: &solana_sdk::signer::keypair::Keypair,
+    automation: &Pubkey,
+    slot: u64,
+    index: usize,
+) -> [u8; 16] {
+    let message = [
+        automation.as_ref(),
+        &slot.to_le_bytes(),
+        &(index as u64).to_le_bytes(),
+    ]
+    .concat();
+    let signature = authority.sign_message(&message);
+    keccak::hash(signature.as_ref()).to_bytes()[..16]
+        .try_into()
+        .unwrap()
+}
+
+/// The file in the SALTS directory holding the squares and salts of an automation's
+/// pre-committed hashes.
+fn salts_path(automation: &Pubkey) -> Option<std::path::PathBuf> {
+    let dir = std::env::var("SALTS").ok()?;
+    Some(std::path::Path::new(&dir).join(format!("{}.json", automation)))
+}
+
+async fn automate_reveal(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let mode = std::env::var("MODE").unwrap_or("none".to_string());
+    let mode = match mode.as_str() {
+        "none" => AutomationReveal::None,
+        "committed" => AutomationReveal::Committed,
+        "deployed" => AutomationReveal::Deployed,
+        _ => panic!("Invalid MODE"),
+    };
+
+    // Pre-commit to the given SQUARES, and leave their salts in SALTS for the executor to reveal.
+    let automation_address = automation_pda(payer.pubkey()).0;
+    let clock = get_clock(rpc).await?;
+    let squares = std::env::var("SQUARES").unwrap_or_default();
+    let choices = squares
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .enumerate()
+        .map(|(i, s)| {
+            let square = u8::from_str(s).expect("Invalid SQUARES");
+            let salt = committed_salt(payer, &automation_address, clock.slot, i);
+            (square, salt)
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        choices.len() <= Automation::MAX_COMMITMENTS,
+        "Too many SQUARES"
+    );
+    if !choices.is_empty() {
+        let path = salts_path(&automation_address).expect("Missing SALTS env var");
+        std::fs::write(&path, serde_json::to_string(&choices)?)?;
+        println!("Saved salts to {}", path.display());
+    }
+    let commitments = choices
+        .iter()
+        .map(|(square, salt)| Miner::compute_commitment(*square, salt, &payer.pubkey()))
+        .collect::<Vec<_>>();
+    let ix = skill_api::sdk::set_automation_reveal(payer.pubkey(), mode, &commitments);
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn automate_limits(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let limit = |name: &str| {
+        std::env::var(name)
+            .map(|s| u64::from_str(&s).expect(&format!("Invalid {}", name)))
+            .unwrap_or(0)
+    };
+    let ix = skill_api::sdk::set_automation_limits(
+        payer.pubkey(),
+        limit("MAX_ROUNDS"),
+        limit("EXPIRES_AT"),
+        limit("DAILY_CAP"),
+        limit("STOP_LOSS"),
+        limit("TAKE_PROFIT"),
+    );
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+fn parse_strategy(value: &str) -> Option<AutomationStrategy> {
+    match value {
+        "random" => Some(AutomationStrategy::Random),
+        "preferred" => Some(AutomationStrategy::Preferred),
+        "follow_crowd" => Some(AutomationStrategy::FollowCrowd),
+        "top_k" => Some(AutomationStrategy::TopK),
+        "bonus" => Some(AutomationStrategy::Bonus),
+        "contrarian" => Some(AutomationStrategy::Contrarian),
+        _ => u8::from_str(value)
+            .ok()
+            .and_then(|v| AutomationStrategy::try_from(v).ok()),
+    }
+}
+
 async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let authority = std::env::var("AUTHORITY").expect("Missing AUTHORITY env var");
     let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
@@ -848,13 +2528,48 @@ async fn log_automation(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  amount: {} SOL", automation.amount as f64 / LAMPORTS_PER_SOL as f64);
     println!("  required rent: {} SOL", required_rent as f64 / LAMPORTS_PER_SOL as f64);
     println!("  authority: {}", automation.authority);
+    println!("  board_id: {}", automation.board_id);
     println!("  balance: {} SOL", automation.balance as f64 / LAMPORTS_PER_SOL as f64);
     println!("  lamports: {} SOL", account_balance as f64 / LAMPORTS_PER_SOL as f64);
     println!("  executor: {}", automation.executor);
     println!("  fee: {} SOL", automation.fee as f64 / LAMPORTS_PER_SOL as f64);
     println!("  mask: {}", automation.mask);
-    println!("  strategy: {}", automation.strategy);
+    println!(
+        "  strategy: {:?}",
+        AutomationStrategy::from_u64(automation.strategy)
+    );
     println!("  reload: {}", automation.reload);
+    println!(
+        "  reveal mode: {:?}",
+        AutomationReveal::from_u64(automation.reveal_mode)
+    );
+    println!(
+        "  commitments: {}/{} used",
+        automation.commit_index, automation.commit_count
+    );
+    println!("  rounds: {}/{}", automation.rounds, automation.max_rounds);
+    println!("  expires at: {}", automation.expires_at);
+    println!(
+        "  daily cap: {} SOL ({} SOL spent today)",
+        automation.daily_cap as f64 / LAMPORTS_PER_SOL as f64,
+        automation.day_spent as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  stop loss: {} SOL",
+        automation.stop_loss as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  take profit: {} SOL",
+        automation.take_profit as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  total spent: {} SOL",
+        automation.total_spent as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  total won: {} SOL",
+        automation.total_won as f64 / LAMPORTS_PER_SOL as f64
+    );
     Ok(())
 }
 
@@ -867,7 +2582,10 @@ async fn log_automations(rpc: &RpcClient) -> Result<(), anyhow::Error> {
         println!("  executor: {}", automation.executor);
         println!("  fee: {}", automation.fee);
         println!("  mask: {}", automation.mask);
-        println!("  strategy: {}", automation.strategy);
+        println!(
+            "  strategy: {:?}",
+            AutomationStrategy::from_u64(automation.strategy)
+        );
         println!();
     }
     Ok(())
@@ -880,7 +2598,7 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  address: {}", treasury_address);
     println!("  balance: {} SOL", treasury.balance as f64 / LAMPORTS_PER_SOL as f64);
     println!(
-        "  motherlode: {} ORE",
+        "  legacy motherlode: {} ORE",
         amount_to_ui_amount(treasury.motherlode, TOKEN_DECIMALS)
     );
     println!(
@@ -909,8 +2627,9 @@ async fn log_treasury(rpc: &RpcClient) -> Result<(), anyhow::Error> {
 async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     let id = std::env::var("ID").expect("Missing ID env var");
     let id = u64::from_str(&id).expect("Invalid ID");
-    let round_address = round_pda(id).0;
-    let round = get_round(rpc, id).await?;
+    let board_id = get_board_id();
+    let round_address = round_pda(board_id, id).0;
+    let round = get_round(rpc, board_id, id).await?;
     println!("Round");
     println!("  Address: {}", round_address);
     println!("  Count: {:?}", round.count);
@@ -918,6 +2637,10 @@ async fn log_round(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  Expires at: {}", round.expires_at);
     println!("  Id: {:?}", round.id);
     println!("  Motherlode: {}", round.motherlode);
+    println!(
+        "  Bonus squares: {:?}",
+        round.active_bonus_squares().collect::<Vec<_>>()
+    );
     println!("  Rent payer: {}", round.rent_payer);
     println!("  Slot hash: {:?}", round.slot_hash);
     println!("  Top miner: {:?}", round.top_miner);
@@ -956,6 +2679,12 @@ async fn log_miner(
         "  refined_ore: {} ORE",
         amount_to_ui_amount(miner.refined_ore, TOKEN_DECIMALS)
     );
+    println!(
+        "  vesting_ore: {} ORE",
+        amount_to_ui_amount(miner.vesting_ore, TOKEN_DECIMALS)
+    );
+    println!("  vesting_end_at: {}", miner.vesting_end_at);
+    println!("  board_id: {}", miner.board_id);
     println!("  round_id: {}", miner.round_id);
     println!("  checkpoint_id: {}", miner.checkpoint_id);
     println!(
@@ -966,6 +2695,79 @@ async fn log_miner(
         "  lifetime_rewards_ore: {} ORE",
         amount_to_ui_amount(miner.lifetime_rewards_ore, TOKEN_DECIMALS)
     );
+    println!("  referrer: {}", miner.referrer);
+    println!(
+        "  referral_rewards_ore: {} ORE",
+        amount_to_ui_amount(miner.referral_rewards_ore, TOKEN_DECIMALS)
+    );
+    println!("  guild: {}", miner.guild);
+    println!("  claim_recipient: {}", miner.recipient());
+    Ok(())
+}
+
+async fn log_referrals(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let miner = get_miner(&rpc, authority).await?;
+    let referees = get_program_accounts::<Miner>(
+        rpc,
+        skill_api::ID,
+        vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            8 + std::mem::offset_of!(Miner, referrer),
+            &authority.to_bytes(),
+        ))],
+    )
+    .await?;
+    println!("Referrals");
+    println!("  authority: {}", authority);
+    println!("  referrer: {}", miner.referrer);
+    println!(
+        "  referral_rewards_ore: {} ORE",
+        amount_to_ui_amount(miner.referral_rewards_ore, TOKEN_DECIMALS)
+    );
+    println!("  referees: {}", referees.len());
+    for (_, referee) in referees {
+        println!("    {}", referee.authority);
+    }
+    Ok(())
+}
+
+async fn open_achievements(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let ix = skill_api::sdk::open_achievements(payer.pubkey());
+    submit_transaction(rpc, payer, &[ix]).await?;
+    Ok(())
+}
+
+async fn log_achievements(
+    rpc: &RpcClient,
+    payer: &solana_sdk::signer::keypair::Keypair,
+) -> Result<(), anyhow::Error> {
+    let authority = std::env::var("AUTHORITY").unwrap_or(payer.pubkey().to_string());
+    let authority = Pubkey::from_str(&authority).expect("Invalid AUTHORITY");
+    let address = achievements_pda(authority).0;
+    let account = rpc.get_account(&address).await?;
+    let achievements = Achievements::try_from_bytes(&account.data)?;
+    println!("Achievements");
+    println!("  address: {}", address);
+    println!("  authority: {}", authority);
+    println!("  correct_reveals: {}", achievements.correct_reveals);
+    for achievement in Achievement::ALL {
+        if achievements.has(achievement) {
+            println!(
+                "  [x] {} (round {})",
+                achievement.name(),
+                achievements.unlocked_round[achievement as usize]
+            );
+        } else {
+            println!("  [ ] {}", achievement.name());
+        }
+    }
     Ok(())
 }
 
@@ -988,20 +2790,243 @@ async fn log_config(rpc: &RpcClient) -> Result<(), anyhow::Error> {
     println!("  fee_collector: {}", config.fee_collector);
     println!("  swap_program: {}", config.swap_program);
     println!("  var_address: {}", config.var_address);
-    println!("  admin_fee: {}", config.admin_fee);
+    println!("  pending_admin: {}", config.pending_admin);
+    println!("  fee_authority: {}", config.fee_authority);
+    println!("  pause_authority: {}", config.pause_authority);
+    println!("  change_delay: {} slots", config.change_delay);
+    println!("  change_count: {}", config.change_count);
+    println!("  paused: {:#b}", config.paused);
+    println!(
+        "  buyback_cap: {} SOL",
+        config.buyback_cap as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!("  liq_manager: {}", config.liq_manager);
+    println!("  referral_fee: {} bps", config.referral_fee);
+    println!("  board_count: {}", config.board_count);
+    println!("  total_board_weight: {}", config.total_board_weight);
+    println!("  season_count: {}", config.season_count);
+    println!("  guild_count: {}", config.guild_count);
+    println!("  tournament_count: {}", config.tournament_count);
+    println!("  vesting_period: {} s", config.vesting_period);
+    println!("  motherlode_odds: 1 in {}", config.motherlode_odds);
+    println!(
+        "  rng_mode: {}",
+        if config.uses_entropy() {
+            "entropy"
+        } else {
+            "slot_hashes"
+        }
+    );
+    println!("  bonus_square_count: {}", config.bonus_square_count);
+    println!(
+        "  bonus_square_multiplier: {}%",
+        config.bonus_square_multiplier
+    );
+    println!(
+        "  motherlode_rate: {} ORE",
+        amount_to_ui_amount(config.motherlode_rate, TOKEN_DECIMALS)
+    );
+    Ok(())
+}
+
+async fn log_season(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let id = std::env::var("SEASON").expect("Missing SEASON env var");
+    let id = u64::from_str(&id).expect("Invalid SEASON");
+    let season = get_season(rpc, id).await?;
+    println!("Season #{}", season.id);
+    println!("  address: {}", season_pda(id).0);
+    println!("  board: {}", season.board_id);
+    println!("  rounds: {}-{}", season.start_round, season.end_round);
+    println!(
+        "  prize: {} SOL",
+        season.prize as f64 / LAMPORTS_PER_SOL as f64
+    );
+    let mut leaders = (0..SEASON_WINNERS)
+        .filter(|&i| season.leader_points[i] > 0)
+        .collect::<Vec<_>>();
+    leaders.sort_by_key(|&i| std::cmp::Reverse(season.leader_points[i]));
+    println!("  leaderboard:");
+    for (rank, i) in leaders.into_iter().enumerate() {
+        println!(
+            "    {}. {} - {} points, {} SOL{}",
+            rank + 1,
+            season.leaders[i],
+            season.leader_points[i],
+            season.prize_share(i) as f64 / LAMPORTS_PER_SOL as f64,
+            if season.claimed & (1 << i) != 0 {
+                " (claimed)"
+            } else {
+                ""
+            }
+        );
+    }
+    Ok(())
+}
+
+async fn log_guild(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let id = get_guild_id();
+    let address = guild_pda(id).0;
+    let guild = get_guild(rpc, id).await?;
+    println!("Guild #{}", guild.id);
+    println!("  address: {}", address);
+    println!("  name: {}", guild.name());
+    println!("  authority: {}", guild.authority);
+    println!("  executor: {}", guild.executor);
+    println!("  revenue_share: {} bps", guild.revenue_share);
+    println!(
+        "  balance: {} SOL",
+        guild.balance as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  rewards_ore: {} ORE",
+        amount_to_ui_amount(guild.rewards_ore, TOKEN_DECIMALS)
+    );
+    println!(
+        "  lifetime_rewards_ore: {} ORE",
+        amount_to_ui_amount(guild.lifetime_rewards_ore, TOKEN_DECIMALS)
+    );
+    println!("  wins: {} / {} rounds", guild.wins, guild.rounds);
+    println!(
+        "  volume: {} SOL",
+        guild.volume as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!("  skill_points: {}", guild.skill_points);
+    println!("  members ({}):", guild.member_count);
+    for (_, miner) in get_miners(rpc).await? {
+        if miner.guild == address {
+            println!("    {}", miner.authority);
+        }
+    }
+    Ok(())
+}
+
+async fn log_tournament(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let id = get_tournament_id();
+    let tournament = get_tournament(rpc, id).await?;
+    println!("Tournament #{}", tournament.id);
+    println!("  address: {}", tournament_pda(id).0);
+    println!("  board: {}", tournament.board_id);
+    println!(
+        "  rounds: {}-{}",
+        tournament.start_round,
+        tournament.end_round()
+    );
+    println!(
+        "  players: {}/{}",
+        tournament.player_count, tournament.max_players
+    );
+    println!(
+        "  entry_fee: {} SOL",
+        tournament.entry_fee as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  prize: {} SOL",
+        tournament.prize as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  matchdays scored: {}/{}",
+        tournament.scored.count_ones(),
+        tournament.matchdays()
+    );
+    println!("  settled: {}", tournament.settled > 0);
+    println!("  standings:");
+    for (place, player) in tournament.standings().into_iter().enumerate() {
+        println!(
+            "    {}. {} - {} points, {} hits, {} SOL",
+            place + 1,
+            tournament.players[player],
+            tournament.points[player],
+            tournament.hits[player],
+            tournament.prize_share(place) as f64 / LAMPORTS_PER_SOL as f64
+        );
+    }
+    if tournament.is_full() {
+        println!("  schedule:");
+        for matchday in 0..tournament.matchdays() as usize {
+            let pairs = (0..tournament.max_players as usize)
+                .filter(|&p| p < tournament.opponent(p, matchday))
+                .map(|p| format!("{} v {}", p, tournament.opponent(p, matchday)))
+                .collect::<Vec<_>>();
+            println!(
+                "    round {}: {}",
+                tournament.start_round + matchday as u64,
+                pairs.join(", ")
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn log_changes(rpc: &RpcClient) -> Result<(), anyhow::Error> {
+    let clock = get_clock(rpc).await?;
+    let mut changes = get_changes(rpc).await?;
+    changes.sort_by_key(|(_, change)| change.id);
+    println!("Pending changes: {}", changes.len());
+    for (address, change) in changes {
+        let kind = ChangeKind::from_u64(change.kind);
+        let value = match kind {
+            ChangeKind::SwapProgram
+            | ChangeKind::VarAddress
+            | ChangeKind::FeeCollector
+            | ChangeKind::LiqManager => change.value_address().to_string(),
+            ChangeKind::AdminFee | ChangeKind::BoardWeight => format!(
+                "{} (board #{})",
+                change.value_u64(),
+                change.value_board_id()
+            ),
+            ChangeKind::ChangeDelay
+            | ChangeKind::BuybackCap
+            | ChangeKind::ReferralFee
+            | ChangeKind::VestingPeriod
+            | ChangeKind::MotherlodeOdds
+            | ChangeKind::MotherlodeRate
+            | ChangeKind::RngMode
+            | ChangeKind::BonusSquareCount
+            | ChangeKind::BonusSquareMultiplier => change.value_u64().to_string(),
+        };
+        let status = if clock.slot >= change.effective_slot {
+            "ready".to_string()
+        } else {
+            format!(
+                "{} s remaining",
+                (change.effective_slot - clock.slot) as f64 * 0.4
+            )
+        };
+        println!("Change #{}", change.id);
+        println!("  address: {}", address);
+        println!("  kind: {:?}", kind);
+        println!("  value: {}", value);
+        println!("  proposer: {}", change.proposer);
+        println!("  created at: {}", change.created_at);
+        println!("  effective slot: {} ({})", change.effective_slot, status);
+    }
     Ok(())
 }
 
 async fn log_board(rpc: &RpcClient) -> Result<(), anyhow::Error> {
-    let board = get_board(&rpc).await?;
+    let board = get_board(&rpc, get_board_id()).await?;
     let clock = get_clock(&rpc).await?;
     print_board(board, &clock);
+    let round = get_round(&rpc, board.id, board.round_id).await?;
+    let config = get_config(&rpc).await?;
+    let treasury = get_treasury(&rpc).await?;
+    let mut motherlode = board.motherlode;
+    if board.id == 0 {
+        motherlode += treasury.motherlode;
+    }
+    let ev = config.motherlode_ev(motherlode, round.total_deployed);
+    println!(
+        "  Motherlode EV: {:.6} ORE per SOL (1 in {})",
+        ev * LAMPORTS_PER_SOL as f64 / 10f64.powi(TOKEN_DECIMALS as i32),
+        config.motherlode_odds
+    );
     Ok(())
 }
 
 fn print_board(board: Board, clock: &Clock) {
     let current_slot = clock.slot;
     println!("Board");
+    println!("  Board: {}", board.id);
     println!("  Id: {:?}", board.round_id);
     println!("  Start slot: {}", board.start_slot);
     println!("  End slot: {}", board.end_slot);
@@ -1009,6 +3034,26 @@ fn print_board(board: Board, clock: &Clock) {
         "  Time remaining: {} sec",
         (board.end_slot.saturating_sub(current_slot) as f64) * 0.4
     );
+    println!(
+        "  Min deploy: {} SOL",
+        board.min_deploy as f64 / LAMPORTS_PER_SOL as f64
+    );
+    println!(
+        "  Phase slots: {} deploy, {} commit, {} reveal",
+        board.deploy_slots, board.commit_slots, board.reveal_slots
+    );
+    println!("  Admin fee: {} bps", board.admin_fee);
+    println!("  Weight: {}", board.weight);
+    println!(
+        "  Squares: {} ({}x{})",
+        board.squares,
+        board.side(),
+        board.side()
+    );
+    println!(
+        "  Motherlode: {} ORE",
+        amount_to_ui_amount(board.motherlode, TOKEN_DECIMALS)
+    );
 }
 
 async fn get_automation(rpc: &RpcClient, address: Pubkey) -> Result<Automation, anyhow::Error> {
@@ -1019,10 +3064,14 @@ async fn get_automation(rpc: &RpcClient, address: Pubkey) -> Result<Automation,
 
 async fn get_automations(rpc: &RpcClient) -> Result<Vec<(Pubkey, Automation)>, anyhow::Error> {
     const REGOLITH_EXECUTOR: Pubkey = pubkey!("HNWhK5f8RMWBqcA7mXJPaxdTPGrha3rrqUrri7HSKb3T");
-    let filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
-        56,
-        &REGOLITH_EXECUTOR.to_bytes(),
-    ));
+    get_automations_by_executor(rpc, REGOLITH_EXECUTOR).await
+}
+
+async fn get_automations_by_executor(
+    rpc: &RpcClient,
+    executor: Pubkey,
+) -> Result<Vec<(Pubkey, Automation)>, anyhow::Error> {
+    let filter = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(56, &executor.to_bytes()));
     let automations = get_program_accounts::<Automation>(rpc, skill_api::ID, vec![filter]).await?;
     Ok(automations)
 }
@@ -1039,8 +3088,20 @@ async fn get_automations(rpc: &RpcClient) -> Result<Vec<(Pubkey, Automation)>, a
 //     Ok(vault)
 // }
 
-async fn get_board(rpc: &RpcClient) -> Result<Board, anyhow::Error> {
-    let board_pda = skill_api::state::board_pda();
+async fn get_change(rpc: &RpcClient, id: u64) -> Result<Change, anyhow::Error> {
+    let address = change_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let change = Change::try_from_bytes(&account.data)?;
+    Ok(*change)
+}
+
+async fn get_changes(rpc: &RpcClient) -> Result<Vec<(Pubkey, Change)>, anyhow::Error> {
+    let changes = get_program_accounts::<Change>(rpc, skill_api::ID, vec![]).await?;
+    Ok(changes)
+}
+
+async fn get_board(rpc: &RpcClient, id: u64) -> Result<Board, anyhow::Error> {
+    let board_pda = skill_api::state::board_pda(id);
     let account = rpc.get_account(&board_pda.0).await?;
     let board = Board::try_from_bytes(&account.data)?;
     Ok(*board)
@@ -1048,8 +3109,8 @@ async fn get_board(rpc: &RpcClient) -> Result<Board, anyhow::Error> {
 
 // get_var removed - Schelling Point doesn't need entropy
 
-async fn get_round(rpc: &RpcClient, id: u64) -> Result<Round, anyhow::Error> {
-    let round_pda = skill_api::state::round_pda(id);
+async fn get_round(rpc: &RpcClient, board_id: u64, id: u64) -> Result<Round, anyhow::Error> {
+    let round_pda = skill_api::state::round_pda(board_id, id);
     let account = rpc.get_account(&round_pda.0).await?;
     let round = Round::try_from_bytes(&account.data)?;
     Ok(*round)
@@ -1069,6 +3130,26 @@ async fn get_config(rpc: &RpcClient) -> Result<Config, anyhow::Error> {
     Ok(*config)
 }
 
+/// The token program that owns the SKILL mint.
+async fn get_token_program(rpc: &RpcClient) -> Result<Pubkey, anyhow::Error> {
+    let account = rpc.get_account(&MINT_ADDRESS).await?;
+    Ok(account.owner)
+}
+
+/// The board to play on, read from the BOARD env var. Defaults to the main board.
+fn get_board_id() -> u64 {
+    std::env::var("BOARD")
+        .map(|s| u64::from_str(&s).expect("Invalid BOARD"))
+        .unwrap_or(0)
+}
+
+/// The referrer to record when the payer's miner is created, read from the REFERRER env var.
+fn get_referrer() -> Pubkey {
+    std::env::var("REFERRER")
+        .map(|s| Pubkey::from_str(&s).expect("Invalid REFERRER"))
+        .unwrap_or_default()
+}
+
 async fn get_miner(rpc: &RpcClient, authority: Pubkey) -> Result<Miner, anyhow::Error> {
     let miner_pda = skill_api::state::miner_pda(authority);
     let account = rpc.get_account(&miner_pda.0).await?;
@@ -1089,6 +3170,76 @@ async fn get_stake(rpc: &RpcClient, authority: Pubkey) -> Result<Stake, anyhow::
     Ok(*stake)
 }
 
+async fn get_season(rpc: &RpcClient, id: u64) -> Result<Season, anyhow::Error> {
+    let address = season_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let season = Season::try_from_bytes(&account.data)?;
+    Ok(*season)
+}
+
+async fn get_seasons(rpc: &RpcClient) -> Result<Vec<(Pubkey, Season)>, anyhow::Error> {
+    let seasons = get_program_accounts::<Season>(rpc, skill_api::ID, vec![]).await?;
+    Ok(seasons)
+}
+
+/// The season a round counts toward, if any.
+fn season_for_round(seasons: &[(Pubkey, Season)], board_id: u64, round_id: u64) -> Option<u64> {
+    seasons
+        .iter()
+        .find(|(_, s)| s.includes(board_id, round_id))
+        .map(|(_, s)| s.id)
+}
+
+async fn get_guild(rpc: &RpcClient, id: u64) -> Result<Guild, anyhow::Error> {
+    let address = guild_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let guild = Guild::try_from_bytes(&account.data)?;
+    Ok(*guild)
+}
+
+/// The guild id, read from the GUILD env var.
+fn get_guild_id() -> u64 {
+    let id = std::env::var("GUILD").expect("Missing GUILD env var");
+    u64::from_str(&id).expect("Invalid GUILD")
+}
+
+/// The guild a miner must checkpoint with, if any.
+fn guild_of(miner: &Miner) -> Option<Pubkey> {
+    miner.has_guild().then_some(miner.guild)
+}
+
+/// The guild whose funds an automation holds, if any. It must be passed to instructions that may
+/// close the automation.
+fn funding_guild_of(automation: &Automation) -> Option<Pubkey> {
+    (automation.guild_balance > 0).then_some(automation.guild)
+}
+
+/// The claim recipient a miner's claims must pay, if any.
+fn claim_recipient_of(miner: &Miner) -> Option<Pubkey> {
+    miner.has_claim_recipient().then_some(miner.claim_recipient)
+}
+
+/// A miner as it is passed to checkpoint_many.
+fn checkpoint_miner_of(miner: &Miner) -> CheckpointMiner {
+    CheckpointMiner {
+        authority: miner.authority,
+        guild: guild_of(miner),
+    }
+}
+
+async fn get_tournament(rpc: &RpcClient, id: u64) -> Result<Tournament, anyhow::Error> {
+    let address = tournament_pda(id).0;
+    let account = rpc.get_account(&address).await?;
+    let tournament = Tournament::try_from_bytes(&account.data)?;
+    Ok(*tournament)
+}
+
+/// The tournament id, read from the TOURNAMENT env var.
+fn get_tournament_id() -> u64 {
+    let id = std::env::var("TOURNAMENT").expect("Missing TOURNAMENT env var");
+    u64::from_str(&id).expect("Invalid TOURNAMENT")
+}
+
 async fn get_rounds(rpc: &RpcClient) -> Result<Vec<(Pubkey, Round)>, anyhow::Error> {
     let rounds = get_program_accounts::<Round>(rpc, skill_api::ID, vec![]).await?;
     Ok(rounds)
@@ -1320,28 +3471,31 @@ where
 // ============ v0.2 Skill System CLI ============
 
 /// Submit a prediction for the winning square.
-/// Usage: COMMAND=predict SQUARE=<0-24> cargo run -p skill-cli
+/// Usage: COMMAND=predict SQUARE=<index> BOARD=<id> cargo run -p skill-cli
 async fn predict(
     rpc: &RpcClient,
     payer: &solana_sdk::signer::keypair::Keypair,
 ) -> Result<(), anyhow::Error> {
     // Read the predicted square from environment variable
     let square: u8 = std::env::var("SQUARE")
-        .expect("Missing SQUARE env var (0-24)")
+        .expect("Missing SQUARE env var")
         .parse()
-        .expect("SQUARE must be a number 0-24");
-
-    if square > 24 {
-        return Err(anyhow::anyhow!("SQUARE must be 0-24, got {}", square));
-    }
+        .expect("SQUARE must be a square index");
 
     // Get current board state
-    let board = get_board(rpc).await?;
+    let board = get_board(rpc, get_board_id()).await?;
+    if square as u64 >= board.squares {
+        return Err(anyhow::anyhow!(
+            "SQUARE must be 0-{}, got {}",
+            board.squares - 1,
+            square
+        ));
+    }
     println!("Submitting prediction for round {}", board.round_id);
     println!("Predicted square: {}", square);
 
     // Build and submit transaction
-    let ix = skill_api::sdk::submit_prediction(payer.pubkey(), square);
+    let ix = skill_api::sdk::submit_prediction(payer.pubkey(), board.id, square);
     let sig = submit_transaction(rpc, payer, &[ix]).await?;
 
     println!();
@@ -1385,7 +3539,7 @@ async fn log_skill(
     println!();
     println!("Current Prediction:");
     if miner.prediction == Miner::NO_PREDICTION {
-        println!("  None (use COMMAND=predict SQUARE=<0-24> to submit)");
+        println!("  None (use COMMAND=predict SQUARE=<index> to submit)");
     } else {
         println!("  Square: {} (for round {})", miner.prediction, miner.last_prediction_round);
     }
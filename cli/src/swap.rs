@@ -0,0 +1,207 @@
+use jup_swap::{
+    quote::{QuoteRequest, QuoteResponse},
+    swap::SwapRequest,
+    transaction_config::{DynamicSlippageSettings, TransactionConfig},
+    JupiterSwapApiClient,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{extension::StateWithExtensions, state::Account};
+use steel::AccountMeta;
+
+/// A swap instruction for the treasury to invoke during a buyback.
+pub struct SwapRoute {
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+    pub address_lookup_tables: Vec<Pubkey>,
+}
+
+/// A source of quotes and swap routes for buybacks.
+pub trait SwapProvider {
+    type Quote;
+
+    /// Quotes a swap of an exact input amount.
+    async fn quote(
+        &self,
+        amount: u64,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        slippage_bps: u16,
+    ) -> Result<Self::Quote, anyhow::Error>;
+
+    /// The expected output amount of a quote.
+    fn out_amount(quote: &Self::Quote) -> u64;
+
+    /// Builds the swap instruction for a quote, executed on behalf of the given user.
+    async fn swap_route(
+        &self,
+        quote: Self::Quote,
+        user: Pubkey,
+        slippage_bps: u16,
+    ) -> Result<SwapRoute, anyhow::Error>;
+}
+
+/// Routes swaps through the Jupiter swap API.
+pub struct JupiterProvider {
+    client: JupiterSwapApiClient,
+}
+
+impl JupiterProvider {
+    pub fn new(api_base_url: String) -> Self {
+        Self {
+            client: JupiterSwapApiClient::new(api_base_url),
+        }
+    }
+}
+
+impl SwapProvider for JupiterProvider {
+    type Quote = QuoteResponse;
+
+    async fn quote(
+        &self,
+        amount: u64,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        slippage_bps: u16,
+    ) -> Result<QuoteResponse, anyhow::Error> {
+        let quote_request = QuoteRequest {
+            amount,
+            input_mint,
+            output_mint,
+            max_accounts: Some(55),
+            slippage_bps,
+            ..QuoteRequest::default()
+        };
+        self.client
+            .quote(&quote_request)
+            .await
+            .map_err(|e| anyhow::anyhow!("quote failed: {e:#?}"))
+    }
+
+    fn out_amount(quote: &QuoteResponse) -> u64 {
+        quote.out_amount
+    }
+
+    async fn swap_route(
+        &self,
+        quote: QuoteResponse,
+        user: Pubkey,
+        slippage_bps: u16,
+    ) -> Result<SwapRoute, anyhow::Error> {
+        let response = self
+            .client
+            .swap_instructions(&SwapRequest {
+                user_public_key: user,
+                quote_response: quote,
+                config: TransactionConfig {
+                    skip_user_accounts_rpc_calls: true,
+                    wrap_and_unwrap_sol: false,
+                    dynamic_compute_unit_limit: true,
+                    dynamic_slippage: Some(DynamicSlippageSettings {
+                        min_bps: Some(slippage_bps.min(50)),
+                        max_bps: Some(slippage_bps),
+                    }),
+                    ..TransactionConfig::default()
+                },
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("swap instructions failed: {e:#?}"))?;
+        Ok(SwapRoute {
+            accounts: response.swap_instruction.accounts,
+            data: response.swap_instruction.data,
+            address_lookup_tables: response.address_lookup_table_addresses,
+        })
+    }
+}
+
+/// A quote against the local mock AMM pool.
+pub struct MockAmmQuote {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_token_program: Pubkey,
+    pub output_token_program: Pubkey,
+    pub in_amount: u64,
+    pub out_amount: u64,
+}
+
+/// Routes swaps through the mock AMM program, for use with a local validator.
+pub struct MockAmmProvider<'a> {
+    rpc: &'a RpcClient,
+}
+
+impl<'a> MockAmmProvider<'a> {
+    pub fn new(rpc: &'a RpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// The token program that owns a mint, spl-token or Token-2022.
+    async fn token_program(&self, mint: &Pubkey) -> Result<Pubkey, anyhow::Error> {
+        Ok(self.rpc.get_account(mint).await?.owner)
+    }
+
+    async fn reserve(&self, mint: &Pubkey, token_program: &Pubkey) -> Result<u64, anyhow::Error> {
+        let pool_address = mock_amm::pool_pda().0;
+        let vault_address =
+            get_associated_token_address_with_program_id(&pool_address, mint, token_program);
+        let data = self.rpc.get_account_data(&vault_address).await?;
+        Ok(StateWithExtensions::<Account>::unpack(&data)?.base.amount)
+    }
+}
+
+impl SwapProvider for MockAmmProvider<'_> {
+    type Quote = MockAmmQuote;
+
+    async fn quote(
+        &self,
+        amount: u64,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        _slippage_bps: u16,
+    ) -> Result<MockAmmQuote, anyhow::Error> {
+        let input_token_program = self.token_program(&input_mint).await?;
+        let output_token_program = self.token_program(&output_mint).await?;
+        let reserve_in = self.reserve(&input_mint, &input_token_program).await?;
+        let reserve_out = self.reserve(&output_mint, &output_token_program).await?;
+        Ok(MockAmmQuote {
+            input_mint,
+            output_mint,
+            input_token_program,
+            output_token_program,
+            in_amount: amount,
+            out_amount: mock_amm::quote(amount, reserve_in, reserve_out),
+        })
+    }
+
+    fn out_amount(quote: &MockAmmQuote) -> u64 {
+        quote.out_amount
+    }
+
+    async fn swap_route(
+        &self,
+        quote: MockAmmQuote,
+        user: Pubkey,
+        slippage_bps: u16,
+    ) -> Result<SwapRoute, anyhow::Error> {
+        let min_amount_out =
+            (quote.out_amount as u128 * (10_000 - slippage_bps as u128) / 10_000) as u64;
+        let ix = mock_amm::swap(
+            user,
+            quote.input_mint,
+            quote.output_mint,
+            quote.input_token_program,
+            quote.output_token_program,
+            quote.in_amount,
+            min_amount_out,
+        );
+
+        // The invoked program must be among the accounts passed through to the swap.
+        let mut accounts = ix.accounts;
+        accounts.push(AccountMeta::new_readonly(mock_amm::ID, false));
+        Ok(SwapRoute {
+            accounts,
+            data: ix.data,
+            address_lookup_tables: vec![],
+        })
+    }
+}
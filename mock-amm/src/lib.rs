@@ -0,0 +1,227 @@
+//! A constant-product AMM used to exercise buybacks against a local validator.
+//!
+//! The pool is a single PDA which owns one associated token account per mint. Liquidity is
+//! provided by transferring tokens directly into those accounts. Either mint may belong to
+//! spl-token or Token-2022, so each side of a swap takes its own token program. This program is
+//! not intended for deployment outside of local testing.
+
+use solana_program::program::invoke;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account, Mint},
+};
+use steel::*;
+
+declare_id!("Z5zcQn54J9UZtRrKbKL8xA7aKdwZff8Ameu2FQJJxuL");
+
+/// The seed of the pool PDA.
+pub const POOL: &[u8] = b"pool";
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
+pub enum MockAmmInstruction {
+    Swap = 0,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct Swap {
+    pub amount_in: [u8; 8],
+    pub min_amount_out: [u8; 8],
+}
+
+instruction!(MockAmmInstruction, Swap);
+
+pub fn pool_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL], &crate::ID)
+}
+
+/// Returns the output amount of a swap against the given reserves.
+pub fn quote(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    let denominator = reserve_in as u128 + amount_in as u128;
+    if denominator == 0 {
+        return 0;
+    }
+    (reserve_out as u128 * amount_in as u128 / denominator) as u64
+}
+
+// let [user_info, input_mint_info, output_mint_info, user_source_info, user_destination_info, pool_info, pool_source_info, pool_destination_info, input_token_program, output_token_program] =
+pub fn swap(
+    user: Pubkey,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    input_token_program: Pubkey,
+    output_token_program: Pubkey,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let pool_address = pool_pda().0;
+    let input_ata = |owner| {
+        get_associated_token_address_with_program_id(owner, &input_mint, &input_token_program)
+    };
+    let output_ata = |owner| {
+        get_associated_token_address_with_program_id(owner, &output_mint, &output_token_program)
+    };
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(user, true),
+            AccountMeta::new_readonly(input_mint, false),
+            AccountMeta::new_readonly(output_mint, false),
+            AccountMeta::new(input_ata(&user), false),
+            AccountMeta::new(output_ata(&user), false),
+            AccountMeta::new_readonly(pool_address, false),
+            AccountMeta::new(input_ata(&pool_address), false),
+            AccountMeta::new(output_ata(&pool_address), false),
+            AccountMeta::new_readonly(input_token_program, false),
+            AccountMeta::new_readonly(output_token_program, false),
+        ],
+        data: Swap {
+            amount_in: amount_in.to_le_bytes(),
+            min_amount_out: min_amount_out.to_le_bytes(),
+        }
+        .to_bytes(),
+    }
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let (ix, data) = parse_instruction(&crate::ID, program_id, data)?;
+
+    match ix {
+        MockAmmInstruction::Swap => process_swap(accounts, data)?,
+    }
+
+    Ok(())
+}
+
+/// Swaps the input token for the output token at the pool's current price.
+fn process_swap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Swap::try_from_bytes(data)?;
+    let amount_in = u64::from_le_bytes(args.amount_in);
+    let min_amount_out = u64::from_le_bytes(args.min_amount_out);
+
+    // Load accounts. Trailing accounts, such as this program when invoked via CPI, are ignored.
+    let [user_info, input_mint_info, output_mint_info, user_source_info, user_destination_info, pool_info, pool_source_info, pool_destination_info, input_token_program, output_token_program, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    user_info.is_signer()?;
+    let input_mint = load_mint(input_mint_info, input_token_program)?;
+    let output_mint = load_mint(output_mint_info, output_token_program)?;
+    load_token_account(
+        user_source_info,
+        user_info.key,
+        input_mint_info,
+        input_token_program,
+    )?;
+    load_token_account(
+        user_destination_info,
+        user_info.key,
+        output_mint_info,
+        output_token_program,
+    )?;
+    pool_info.has_seeds(&[POOL], &crate::ID)?;
+    let pool_source = load_token_account(
+        pool_source_info,
+        pool_info.key,
+        input_mint_info,
+        input_token_program,
+    )?;
+    let pool_destination = load_token_account(
+        pool_destination_info,
+        pool_info.key,
+        output_mint_info,
+        output_token_program,
+    )?;
+
+    // Price the swap.
+    let amount_out = quote(amount_in, pool_source.amount, pool_destination.amount);
+    if amount_out == 0 || amount_out < min_amount_out {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // Move tokens.
+    invoke(
+        &spl_token_2022::instruction::transfer_checked(
+            input_token_program.key,
+            user_source_info.key,
+            input_mint_info.key,
+            pool_source_info.key,
+            user_info.key,
+            &[],
+            amount_in,
+            input_mint.decimals,
+        )?,
+        &[
+            user_source_info.clone(),
+            input_mint_info.clone(),
+            pool_source_info.clone(),
+            user_info.clone(),
+            input_token_program.clone(),
+        ],
+    )?;
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            output_token_program.key,
+            pool_destination_info.key,
+            output_mint_info.key,
+            user_destination_info.key,
+            pool_info.key,
+            &[],
+            amount_out,
+            output_mint.decimals,
+        )?,
+        &[
+            pool_destination_info.clone(),
+            output_mint_info.clone(),
+            user_destination_info.clone(),
+            pool_info.clone(),
+            output_token_program.clone(),
+        ],
+        &crate::ID,
+        &[POOL],
+    )?;
+
+    Ok(())
+}
+
+/// Loads a mint, checking that the token program, spl-token or Token-2022, owns it.
+fn load_mint(
+    mint_info: &AccountInfo<'_>,
+    token_program: &AccountInfo<'_>,
+) -> Result<Mint, ProgramError> {
+    if *token_program.key != spl_token::ID && *token_program.key != spl_token_2022::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    token_program.is_program(mint_info.owner)?;
+    let data = mint_info.try_borrow_data()?;
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?.base)
+}
+
+/// Loads the writable associated token account of the given owner for a mint.
+fn load_token_account(
+    info: &AccountInfo<'_>,
+    owner: &Pubkey,
+    mint_info: &AccountInfo<'_>,
+    token_program: &AccountInfo<'_>,
+) -> Result<Account, ProgramError> {
+    info.is_writable()?
+        .has_address(&get_associated_token_address_with_program_id(
+            owner,
+            mint_info.key,
+            token_program.key,
+        ))?
+        .has_owner(token_program.key)?;
+    let data = info.try_borrow_data()?;
+    Ok(StateWithExtensions::<Account>::unpack(&data)?.base)
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
@@ -0,0 +1,23 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Accepts a proposed admin transfer.
+pub fn process_accept_admin(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.pending_admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+
+    // Set admin.
+    config.admin = *signer_info.key;
+    config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
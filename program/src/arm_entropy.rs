@@ -0,0 +1,46 @@
+use entropy_api::state::Var;
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Arms the board's entropy var to sample the slot hash at the end of the board's current round.
+pub fn process_arm_entropy(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, config_info, var_info, entropy_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let board = board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.end_slot != u64::MAX && clock.slot < b.end_slot)?;
+    config_info
+        .as_account::<Config>(&skill_api::ID)?
+        .assert(|c| c.uses_entropy())?;
+    let var = var_info
+        .has_address(&board_var_pda(*board_info.key).0)?
+        .as_account::<Var>(&entropy_api::ID)?
+        .assert(|v| v.authority == *board_info.key)?;
+    entropy_program.is_program(&entropy_api::ID)?;
+
+    // Nothing to do if the var is already armed for this round.
+    if var.end_at == board.end_slot {
+        return Ok(());
+    }
+
+    // Point the var at the round's end slot.
+    let board_id = board.id.to_le_bytes();
+    invoke_signed(
+        &entropy_api::sdk::next(*board_info.key, *var_info.key, board.end_slot),
+        &[board_info.clone(), var_info.clone()],
+        &entropy_api::ID,
+        &board_seeds(&board_id),
+    )?;
+
+    sol_log(&format!(
+        "Entropy var armed for board #{} round #{} (slot {})",
+        board.id, board.round_id, board.end_slot
+    ));
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use skill_api::prelude::*;
+use solana_program::rent::Rent;
 use steel::*;
 
+use crate::{close_automation, migrate_miner, return_guild_balance};
+
 /// Sets the executor.
 pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -11,14 +14,19 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     let mask = u64::from_le_bytes(args.mask);
     let strategy = AutomationStrategy::from_u64(args.strategy as u64);
     let reload = u64::from_le_bytes(args.reload) > 0;
+    let referrer = Pubkey::new_from_array(args.referrer);
+    let board_id = u64::from_le_bytes(args.board_id);
 
     // Load accounts.
-    let [signer_info, automation_info, executor_info, miner_info, system_program] = accounts else {
+    let [signer_info, automation_info, executor_info, miner_info, system_program, optional_accounts @ ..] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     automation_info.is_writable()?;
     system_program.is_program(&system_program::ID)?;
+    migrate_miner(miner_info, signer_info)?;
 
     // Open miner account.
     let miner = if miner_info.data_is_empty() {
@@ -31,8 +39,8 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
         miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.checkpoint_fee = 0;
         miner.checkpoint_id = 0;
         miner.rewards_sol = 0;
@@ -40,6 +48,12 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         miner.round_id = 0;
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_ore = 0;
+        miner.referrer = if referrer != *signer_info.key {
+            referrer
+        } else {
+            Pubkey::default()
+        };
+        miner.referral_rewards_ore = 0;
         miner
     } else {
         miner_info
@@ -50,15 +64,34 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             )?
     };
 
+    // Migrate automation accounts created before the account was extended.
+    let expected_size = 8 + std::mem::size_of::<Automation>();
+    if !automation_info.data_is_empty() && automation_info.data_len() < expected_size {
+        automation_info.has_seeds(&[AUTOMATION, &signer_info.key.to_bytes()], &skill_api::ID)?;
+        let old_size = automation_info.data_len();
+        let rent = Rent::get()?;
+        let diff = rent.minimum_balance(expected_size) - rent.minimum_balance(old_size);
+        automation_info.collect(diff, signer_info)?;
+        automation_info.resize(expected_size)?;
+        automation_info.try_borrow_mut_data()?[old_size..].fill(0);
+    }
+
     // Close account if executor is Pubkey::default().
+    let guild_info = optional_accounts.first();
     if *executor_info.key == Pubkey::default() {
-        automation_info
+        let automation = automation_info
             .as_account_mut::<Automation>(&skill_api::ID)?
             .assert_mut_err(
                 |a| a.authority == *signer_info.key,
                 OreError::NotAuthorized.into(),
             )?;
-        automation_info.close(signer_info)?;
+        close_automation(
+            automation_info,
+            automation,
+            signer_info,
+            guild_info,
+            AutomationCloseReason::Closed,
+        )?;
         return Ok(());
     }
 
@@ -84,9 +117,15 @@ pub fn process_automate(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             )?
     };
 
+    // Guild funds are only for the guild's executor, so return them if the executor changes.
+    if automation.executor != *executor_info.key {
+        return_guild_balance(automation_info, automation, guild_info)?;
+    }
+
     // Set strategy and mask.
     automation.amount = amount;
     automation.balance += deposit;
+    automation.board_id = board_id;
     automation.executor = *executor_info.key;
     automation.fee = fee;
     automation.mask = mask;
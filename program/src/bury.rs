@@ -3,6 +3,8 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::token::*;
+
 /// Bury ORE and distribute yield to stakers.
 pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -16,22 +18,21 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let sender = sender_info
-        .is_writable()?
-        .as_associated_token_account(&signer_info.key, &MINT_ADDRESS)?;
+    load_skill_mint(mint_info, token_program)?;
+    let sender =
+        load_skill_token_account(sender_info.is_writable()?, signer_info.key, token_program)?;
     board_info.as_account_mut::<Board>(&skill_api::ID)?;
-    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
-    treasury_ore_info.as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?;
-    token_program.is_program(&spl_token::ID)?;
+    load_skill_token_account(treasury_ore_info, treasury_info.key, token_program)?;
     ore_program.is_program(&skill_api::ID)?;
 
     // Transfer ORE from sender to treasury.
-    let amount = sender.amount().min(amount);
-    transfer(
+    let amount = sender.amount.min(amount);
+    transfer_skill(
         signer_info,
         sender_info,
         treasury_ore_info,
+        mint_info,
         token_program,
         amount,
     )?;
@@ -50,13 +51,12 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
 
     // Burn ORE.
     let burn_amount = amount - shared_amount;
-    burn_signed(
+    burn_skill_signed(
         treasury_ore_info,
         mint_info,
         treasury_info,
         token_program,
         burn_amount,
-        &[TREASURY],
     )?;
 
     sol_log(
@@ -68,7 +68,7 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
     );
 
     // Emit event.
-    let mint = mint_info.as_mint()?;
+    let mint = load_skill_mint(mint_info, token_program)?;
     program_log(
         &[board_info.clone(), ore_program.clone()],
         BuryEvent {
@@ -76,7 +76,7 @@ pub fn process_bury(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult
             ore_buried: burn_amount,
             ore_shared: shared_amount,
             sol_amount: 0,
-            new_circulating_supply: mint.supply(),
+            new_circulating_supply: mint.supply,
             ts: Clock::get()?.unix_timestamp,
         }
         .to_bytes(),
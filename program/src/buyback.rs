@@ -3,13 +3,26 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::token::*;
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
 /// Swap vaulted SOL to ORE, and burn the ORE.
 pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    if data.len() < std::mem::size_of::<Buyback>() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (args, data) = data.split_at(std::mem::size_of::<Buyback>());
+    let args = Buyback::try_from_bytes(args)?;
+    let min_ore_out = u64::from_le_bytes(args.min_ore_out);
+
     // Load accounts.
-    let (ore_accounts, swap_accounts) = accounts.split_at(9);
-    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_ore_info, treasury_sol_info, token_program, ore_program] =
+    if accounts.len() < 10 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (ore_accounts, swap_accounts) = accounts.split_at(10);
+    let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_ore_info, treasury_sol_info, token_program, sol_token_program, ore_program] =
         ore_accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -19,12 +32,12 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     let config = config_info
         .as_account::<Config>(&skill_api::ID)?
         .assert(|c| c.bury_authority == *signer_info.key)?;
-    let ore_mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    let ore_mint = load_skill_mint(mint_info, token_program)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
     let treasury_ore =
-        treasury_ore_info.as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?;
+        load_skill_token_account(treasury_ore_info, treasury_info.key, token_program)?;
     treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    token_program.is_program(&spl_token::ID)?;
+    sol_token_program.is_program(&spl_token::ID)?;
     ore_program.is_program(&skill_api::ID)?;
 
     // Sync native token balance.
@@ -33,12 +46,20 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     // Record pre-swap balances.
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    let pre_swap_ore_balance = treasury_ore.amount();
+    let pre_swap_ore_balance = treasury_ore.amount;
     let pre_swap_sol_balance = treasury_sol.amount();
     assert!(pre_swap_sol_balance > 0);
+    if pre_swap_sol_balance > config.buyback_cap {
+        sol_log(&format!(
+            "Buyback of {} SOL exceeds cap of {} SOL",
+            pre_swap_sol_balance as f64 / LAMPORTS_PER_SOL as f64,
+            config.buyback_cap as f64 / LAMPORTS_PER_SOL as f64,
+        ));
+        return Err(OreError::BuybackCapExceeded.into());
+    }
 
     // Record pre-swap mint supply.
-    let pre_swap_mint_supply = ore_mint.supply();
+    let pre_swap_mint_supply = ore_mint.supply;
 
     // Record pre-swap treasury lamports.
     let pre_swap_treasury_lamports = treasury_info.lamports();
@@ -83,7 +104,7 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     );
 
     // Record post-swap mint supply.
-    let post_swap_mint_supply = mint_info.as_mint()?.supply();
+    let post_swap_mint_supply = load_skill_mint(mint_info, token_program)?.supply;
     assert_eq!(
         post_swap_mint_supply, pre_swap_mint_supply,
         "Mint supply changed during swap: {} -> {}",
@@ -92,14 +113,24 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     // Record post-swap balances.
     let treasury_ore =
-        treasury_ore_info.as_associated_token_account(treasury_info.key, &MINT_ADDRESS)?;
+        load_skill_token_account(treasury_ore_info, treasury_info.key, token_program)?;
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    let post_swap_ore_balance = treasury_ore.amount();
+    let post_swap_ore_balance = treasury_ore.amount;
     let post_swap_sol_balance = treasury_sol.amount();
     let total_ore = post_swap_ore_balance - pre_swap_ore_balance;
     assert_eq!(post_swap_sol_balance, 0);
     assert!(post_swap_ore_balance >= pre_swap_ore_balance);
+
+    // Enforce minimum output.
+    if total_ore < min_ore_out {
+        sol_log(&format!(
+            "Swap returned {} ORE, below minimum of {} ORE",
+            amount_to_ui_amount(total_ore, TOKEN_DECIMALS),
+            amount_to_ui_amount(min_ore_out, TOKEN_DECIMALS),
+        ));
+        return Err(OreError::SlippageExceeded.into());
+    }
     sol_log(
         &format!(
             "📈 Swapped {} SOL into {} ORE",
@@ -124,13 +155,12 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     // Burn ORE.
     let burn_amount = total_ore - shared_amount;
-    burn_signed(
+    burn_skill_signed(
         treasury_ore_info,
         mint_info,
         treasury_info,
         token_program,
         burn_amount,
-        &[TREASURY],
     )?;
 
     sol_log(
@@ -142,7 +172,7 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     );
 
     // Emit event.
-    let mint = mint_info.as_mint()?;
+    let mint = load_skill_mint(mint_info, token_program)?;
     program_log(
         &[board_info.clone(), ore_program.clone()],
         BuryEvent {
@@ -150,7 +180,7 @@ pub fn process_buyback(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             ore_buried: burn_amount,
             ore_shared: shared_amount,
             sol_amount: pre_swap_sol_balance,
-            new_circulating_supply: mint.supply(),
+            new_circulating_supply: mint.supply,
             ts: Clock::get()?.unix_timestamp,
         }
         .to_bytes(),
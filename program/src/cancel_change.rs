@@ -0,0 +1,26 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Cancels a scheduled change. Callable by the admin or the proposer.
+pub fn process_cancel_change(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, change_info, proposer_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account::<Config>(&skill_api::ID)?;
+    change_info
+        .is_writable()?
+        .as_account::<Change>(&skill_api::ID)?
+        .assert(|c| c.proposer == *proposer_info.key)?
+        .assert_err(
+            |c| config.admin == *signer_info.key || c.proposer == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    proposer_info.is_writable()?;
+
+    // Close change account.
+    change_info.close(proposer_info)?;
+
+    Ok(())
+}
@@ -1,55 +1,147 @@
 use skill_api::prelude::*;
-use solana_program::{log::sol_log, rent::Rent};
+use solana_program::{log::sol_log_data, rent::Rent};
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::{migrate_miner, migrate_round};
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
 /// Checkpoints a miner's rewards.
 pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, miner_info, round_info, treasury_info, system_program] = accounts
+    let [signer_info, board_info, miner_info, round_info, treasury_info, system_program, config_info, optional_accounts @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    let board = board_info.as_account::<Board>(&skill_api::ID)?;
+    let config = config_info
+        .has_address(&CONFIG_ADDRESS)?
+        .as_account::<Config>(&skill_api::ID)?;
+    migrate_miner(miner_info, signer_info)?;
+    migrate_round(round_info, signer_info)?;
     let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
+
+    // Guild members must provide their guild first.
+    let (guild_info, optional_accounts) = if miner.has_guild() {
+        let [guild_info, optional_accounts @ ..] = optional_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        guild_info.is_writable()?.has_address(&miner.guild)?;
+        (Some(guild_info), optional_accounts)
+    } else {
+        (None, optional_accounts)
+    };
+
+    // Then the achievements account, required even if it has not been opened so achievements
+    // can't be skipped, the program for events and the round's season and the miner's season
+    // account, if it is part of one.
+    let [achievements_info, ore_program, season_accounts @ ..] = optional_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let season_accounts = match season_accounts {
+        [] => None,
+        [season_info, season_miner_info, ..] => Some((season_info, season_miner_info)),
+        _ => return Err(ProgramError::NotEnoughAccountKeys),
+    };
+    achievements_info
+        .is_writable()?
+        .has_address(&achievements_pda(miner.authority).0)?;
+    ore_program.is_program(&skill_api::ID)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
+    // Checkpoint miner.
+    let (rewards_sol, bot_fee) = checkpoint_miner(
+        &clock,
+        config,
+        board_info,
+        round_info,
+        treasury,
+        miner,
+        guild_info,
+        (achievements_info, ore_program),
+        season_accounts,
+        (signer_info, system_program),
+    )?;
+
+    // Do SOL transfers.
+    if rewards_sol > 0 {
+        round_info.send(rewards_sol, &miner_info);
+    }
+    if bot_fee > 0 {
+        miner_info.send(bot_fee, &signer_info);
+    }
+
+    // Assert miner account has sufficient funds for rent and rewards.
+    let account_size = 8 + std::mem::size_of::<Miner>();
+    let required_rent = Rent::get()?.minimum_balance(account_size);
+    assert!(
+        miner_info.lamports() >= required_rent + miner.checkpoint_fee + miner.rewards_sol,
+        "Miner does not have sufficient funds for rent and rewards"
+    );
+
+    Ok(())
+}
+
+/// Checkpoints a miner's last round. Returns the SOL the round owes the miner and the bot fee
+/// the miner owes the signer, which the caller must transfer. The season and the miner's season
+/// account are required if the round is part of one. The signer pays the rent of the season
+/// account when the miner earns its first points of the season.
+#[allow(clippy::too_many_arguments)]
+pub fn checkpoint_miner<'info>(
+    clock: &Clock,
+    config: &Config,
+    board_info: &AccountInfo<'info>,
+    round_info: &AccountInfo<'info>,
+    treasury: &mut Treasury,
+    miner: &mut Miner,
+    guild_info: Option<&AccountInfo<'info>>,
+    (achievements_info, ore_program): (&AccountInfo<'info>, &AccountInfo<'info>),
+    season_accounts: Option<(&AccountInfo<'info>, &AccountInfo<'info>)>,
+    (signer_info, system_program): (&AccountInfo<'info>, &AccountInfo<'info>),
+) -> Result<(u64, u64), ProgramError> {
+    let board = board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == miner.board_id)?;
+
     // If miner has already checkpointed this round, return.
     if miner.checkpoint_id == miner.round_id {
-        return Ok(());
+        return Ok((0, 0));
     }
 
     // If round account is empty, verify the correct account was provided.
     // This can happen if the miner attempted to checkpoint after the round expired and the account was closed.
     // In this case, the miner forfeits any potential rewards.
     if round_info.data_is_empty() {
-        sol_log(&format!("Round account is empty").as_str());
-        round_info.has_seeds(&[ROUND, &miner.round_id.to_le_bytes()], &skill_api::ID)?;
+        debug_log!("Round account is empty");
+        round_info.has_seeds(
+            &round_seeds(&board.id.to_le_bytes(), &miner.round_id.to_le_bytes()),
+            &skill_api::ID,
+        )?;
         miner.checkpoint_id = miner.round_id;
-        return Ok(());
+        return Ok((0, 0));
     }
 
     // If round is current round, or the miner round ID does not match the provided round, return.
+    round_info.has_address(&round_pda(board.id, miner.round_id).0)?;
     let round = round_info.as_account_mut::<Round>(&skill_api::ID)?;
-    sol_log(&format!("Round ID: {}", round.id).as_str());
+    debug_log!("Round ID: {}", round.id);
 
     // Check if round is valid and finalized (has slot_hash from reset)
     if round.id == board.round_id || round.id != miner.round_id || !round.is_finalized() {
-        sol_log(&format!("Round not valid or not finalized").as_str());
-        return Ok(());
+        debug_log!("Round not valid or not finalized");
+        return Ok((0, 0));
     }
 
     // Ensure round is not expired.
     // In this case, the miner forfeits any potential rewards.
     if clock.slot >= round.expires_at {
-        sol_log(&format!("Round expired").as_str());
+        debug_log!("Round expired");
         miner.checkpoint_id = miner.round_id;
-        return Ok(());
+        return Ok((0, 0));
     }
 
     // Calculate bot fee.
@@ -82,11 +174,14 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
             // Calculate SOL rewards.
             let original_deployment = miner.deployed[winning_square];
-            let admin_fee = (original_deployment / 100).max(1);
+            let admin_fee = board.admin_fee(original_deployment).max(1);
             rewards_sol = original_deployment - admin_fee;
             rewards_sol += ((round.total_winnings as u128 * miner.deployed[winning_square] as u128)
                 / round.deployed[winning_square] as u128) as u64;
-            sol_log(&format!("Base rewards: {} SOL", rewards_sol as f64 / LAMPORTS_PER_SOL as f64).as_str());
+            debug_log!(
+                "Base rewards: {} SOL",
+                rewards_sol as f64 / LAMPORTS_PER_SOL as f64
+            );
 
             // Calculate ORE rewards.
             if round.top_miner == SPLIT_ADDRESS {
@@ -94,12 +189,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 rewards_ore = ((round.top_miner_reward as u128
                     * miner.deployed[winning_square] as u128)
                     / round.deployed[winning_square] as u128) as u64;
-                sol_log(
-                    &format!(
-                        "Split rewards: {} ORE",
-                        amount_to_ui_amount(rewards_ore, TOKEN_DECIMALS)
-                    )
-                    .as_str(),
+                debug_log!(
+                    "Split rewards: {} ORE",
+                    amount_to_ui_amount(rewards_ore, TOKEN_DECIMALS)
                 );
             } else {
                 // If round is not split, payout to the top miner.
@@ -110,12 +202,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 {
                     rewards_ore = round.top_miner_reward;
                     round.top_miner = miner.authority;
-                    sol_log(
-                        &format!(
-                            "Top miner rewards: {} ORE",
-                            amount_to_ui_amount(rewards_ore, TOKEN_DECIMALS)
-                        )
-                        .as_str(),
+                    debug_log!(
+                        "Top miner rewards: {} ORE",
+                        amount_to_ui_amount(rewards_ore, TOKEN_DECIMALS)
                     );
                 }
             }
@@ -125,12 +214,9 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
                 let motherload_rewards =
                     ((round.motherlode as u128 * miner.deployed[winning_square] as u128)
                         / round.deployed[winning_square] as u128) as u64;
-                sol_log(
-                    &format!(
-                        "Motherlode rewards: {} ORE",
-                        amount_to_ui_amount(motherload_rewards, TOKEN_DECIMALS)
-                    )
-                    .as_str(),
+                debug_log!(
+                    "Motherlode rewards: {} ORE",
+                    amount_to_ui_amount(motherload_rewards, TOKEN_DECIMALS)
                 );
                 rewards_ore += motherload_rewards;
             }
@@ -145,7 +231,10 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
         // Round has no slot hash, refund all SOL.
         let refund_amount = miner.deployed.iter().sum::<u64>();
-        sol_log(&format!("Refunding {} SOL", refund_amount as f64 / LAMPORTS_PER_SOL as f64).as_str());
+        debug_log!(
+            "Refunding {} SOL",
+            refund_amount as f64 / LAMPORTS_PER_SOL as f64
+        );
         rewards_sol = refund_amount;
     }
 
@@ -153,64 +242,165 @@ pub fn process_checkpoint(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
     miner.update_rewards(treasury);
 
     // v0.6: Enhanced multiplier system combining skill, contrarian, and bonus squares
+    let mut season_points = 0;
+    let mut bonus_ore = 0;
+    let mut multiplier = 100;
     if let Some(winning_square) = winning_square_for_skill {
         // First, evaluate prediction to update skill score/streak
         let _skill_mult = miner.evaluate_prediction(winning_square, round.id);
 
         // Calculate total multiplier (skill * contrarian * bonus_square) / 10000
         // Returns value where 100 = 1.0x, 200 = 2.0x, etc.
-        let total_multiplier = miner.calculate_total_multiplier(winning_square, round);
+        let total_multiplier =
+            miner.calculate_total_multiplier(winning_square, round, config.bonus_square_multiplier);
+        multiplier = total_multiplier;
+
+        // Winning a round earns season points worth its multiplier.
+        if miner.deployed[winning_square as usize] > 0 {
+            season_points = total_multiplier;
+        }
 
         if total_multiplier > 100 && rewards_ore > 0 {
             let boosted_ore = (rewards_ore as u128 * total_multiplier as u128 / 100) as u64;
             let bonus = boosted_ore - rewards_ore;
 
             // Log breakdown of multiplier components
-            let skill_mult = miner.calculate_skill_multiplier();
-            let contrarian_mult = round.calculate_contrarian_bonus(winning_square);
-            let bonus_sq_mult = if round.is_bonus_square(winning_square) { 200 } else { 100 };
-
-            sol_log(&format!(
+            debug_log!(
                 "v0.6 Multiplier: skill({}%) * contrarian({}%) * bonus_sq({}%) = {}%, +{} ORE",
-                skill_mult,
-                contrarian_mult,
-                bonus_sq_mult,
+                miner.calculate_skill_multiplier(),
+                round.calculate_contrarian_bonus(winning_square),
+                if round.is_bonus_square(winning_square) {
+                    config.bonus_square_multiplier
+                } else {
+                    100
+                },
                 total_multiplier,
                 amount_to_ui_amount(bonus, TOKEN_DECIMALS)
-            ).as_str());
+            );
+
+            bonus_ore = bonus;
+        }
 
-            rewards_ore = boosted_ore;
+        // Record achievements before the reveal is cleared.
+        if !achievements_info.data_is_empty() {
+            let achievements = achievements_info.as_account_mut::<Achievements>(&skill_api::ID)?;
+            let won = miner.deployed[winning_square as usize] > 0;
+            for achievement in achievements.record(miner, round, winning_square, won) {
+                debug_log!("Achievement unlocked: {}", achievement.name());
+                board_log(
+                    board.id,
+                    &[board_info.clone(), ore_program.clone()],
+                    AchievementEvent {
+                        disc: 6,
+                        authority: miner.authority,
+                        achievement: achievement as u64,
+                        round_id: round.id,
+                        board_id: board.id,
+                        ts: clock.unix_timestamp,
+                    }
+                    .to_bytes(),
+                )?;
+            }
         }
 
         // Clear commitment state for next round
         miner.clear_commitment();
     }
 
+    // Pay the guild its share of the ORE rewards and record the round in its stats.
+    if let Some(guild_info) = guild_info {
+        let guild = guild_info.as_account_mut::<Guild>(&skill_api::ID)?;
+        let base_share = guild.revenue_share(rewards_ore);
+        let bonus_share = guild.revenue_share(bonus_ore);
+        let guild_share = base_share + bonus_share;
+        rewards_ore -= base_share;
+        bonus_ore -= bonus_share;
+        guild.record(miner.deployed.iter().sum(), season_points, guild_share);
+        if guild_share > 0 {
+            debug_log!(
+                "Guild share: {} ORE",
+                amount_to_ui_amount(guild_share, TOKEN_DECIMALS)
+            );
+        }
+    }
+
     // Checkpoint miner.
     miner.checkpoint_id = round.id;
     miner.rewards_ore += rewards_ore;
-    miner.lifetime_rewards_ore += rewards_ore;
+    miner.lifetime_rewards_ore += rewards_ore + bonus_ore;
     miner.rewards_sol += rewards_sol;
     miner.lifetime_rewards_sol += rewards_sol;
 
     // Update treasury.
     treasury.total_unclaimed += rewards_ore;
 
-    // Do SOL transfers.
-    if rewards_sol > 0 {
-        round_info.send(rewards_sol, &miner_info);
+    // The boosted portion of the ORE rewards vests over the vesting period.
+    if bonus_ore > 0 {
+        miner.vest_ore(bonus_ore, config.vesting_period, clock, treasury);
+        debug_log!(
+            "Vesting {} ORE over {}s",
+            amount_to_ui_amount(bonus_ore, TOKEN_DECIMALS),
+            config.vesting_period
+        );
     }
-    if bot_fee > 0 {
-        miner_info.send(bot_fee, &signer_info);
+
+    // Record season points if the round is part of a season. Rounds opened before they were
+    // stamped with their season rely on the season provided.
+    let season_accounts = match round.season_id() {
+        Some(season_id) => {
+            let Some((season_info, _)) = season_accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            season_info.has_address(&season_pda(season_id).0)?;
+            season_accounts
+        }
+        None => season_accounts,
+    };
+    if let Some((season_info, season_miner_info)) = season_accounts {
+        let season = season_info
+            .is_writable()?
+            .as_account_mut::<Season>(&skill_api::ID)?;
+        if season.includes(board.id, round.id) && season_points > 0 {
+            // Points are kept per season, so seasons on other boards don't reset them.
+            let season_id = season.id.to_le_bytes();
+            let authority = miner.authority.to_bytes();
+            let season_miner_seeds: &[&[u8]] = &[SEASON_MINER, &season_id, &authority];
+            let season_miner = if season_miner_info.data_is_empty() {
+                create_program_account::<SeasonMiner>(
+                    season_miner_info,
+                    system_program,
+                    signer_info,
+                    &skill_api::ID,
+                    season_miner_seeds,
+                )?;
+                let season_miner =
+                    season_miner_info.as_account_mut::<SeasonMiner>(&skill_api::ID)?;
+                season_miner.season_id = season.id;
+                season_miner.authority = miner.authority;
+                season_miner
+            } else {
+                season_miner_info
+                    .is_writable()?
+                    .has_seeds(season_miner_seeds, &skill_api::ID)?
+                    .as_account_mut::<SeasonMiner>(&skill_api::ID)?
+            };
+            season_miner.points += season_points;
+            season.record(miner.authority, season_miner.points);
+        }
     }
 
-    // Assert miner account has sufficient funds for rent and rewards.
-    let account_size = 8 + std::mem::size_of::<Miner>();
-    let required_rent = Rent::get()?.minimum_balance(account_size);
-    assert!(
-        miner_info.lamports() >= required_rent + miner.checkpoint_fee + miner.rewards_sol,
-        "Miner does not have sufficient funds for rent and rewards"
-    );
+    sol_log_data(&[CheckpointEvent {
+        disc: 9,
+        authority: miner.authority,
+        round_id: round.id,
+        board_id: board.id,
+        rewards_sol,
+        rewards_ore,
+        bonus_ore,
+        multiplier,
+        ts: clock.unix_timestamp,
+    }
+    .to_bytes()]);
 
-    Ok(())
+    Ok((rewards_sol, bot_fee))
 }
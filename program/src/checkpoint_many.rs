@@ -0,0 +1,115 @@
+use skill_api::prelude::*;
+use solana_program::rent::Rent;
+use steel::*;
+
+use crate::{checkpoint_miner, migrate_miner, migrate_round};
+
+/// Checkpoints many miners of the same round. Each miner is followed by its guild if it is a
+/// member of one, by its achievements account, opened or not, and by its season account if a
+/// season is given. The bot fees of all miners are paid to the signer in one transfer.
+pub fn process_checkpoint_many(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, round_info, treasury_info, system_program, config_info, ore_program, remaining_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    board_info.as_account::<Board>(&skill_api::ID)?;
+    round_info.is_writable()?;
+    migrate_round(round_info, signer_info)?;
+    let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    let config = config_info
+        .has_address(&CONFIG_ADDRESS)?
+        .as_account::<Config>(&skill_api::ID)?;
+    ore_program.is_program(&skill_api::ID)?;
+
+    // The season comes ahead of the miners if the round is part of one.
+    let (season_info, mut remaining_accounts) = match remaining_accounts {
+        [season_info, miner_accounts @ ..]
+            if season_info.as_account::<Season>(&skill_api::ID).is_ok() =>
+        {
+            (Some(season_info), miner_accounts)
+        }
+        _ => (None, remaining_accounts),
+    };
+
+    // Checkpoint miners.
+    let required_rent = Rent::get()?.minimum_balance(8 + std::mem::size_of::<Miner>());
+    let mut miner_count = 0;
+    let mut total_bot_fee = 0;
+    while let [miner_info, miner_accounts @ ..] = remaining_accounts {
+        migrate_miner(miner_info, signer_info)?;
+        let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
+
+        // Guild members are followed by their guild.
+        let (guild_info, miner_accounts) = if miner.has_guild() {
+            let [guild_info, miner_accounts @ ..] = miner_accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            guild_info.is_writable()?.has_address(&miner.guild)?;
+            (Some(guild_info), miner_accounts)
+        } else {
+            (None, miner_accounts)
+        };
+
+        // Then by their achievements account.
+        let [achievements_info, miner_accounts @ ..] = miner_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        achievements_info
+            .is_writable()?
+            .has_address(&achievements_pda(miner.authority).0)?;
+
+        // And by their season account if the season is given.
+        let (season_accounts, miner_accounts) = match season_info {
+            Some(season_info) => {
+                let [season_miner_info, miner_accounts @ ..] = miner_accounts else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+                (Some((season_info, season_miner_info)), miner_accounts)
+            }
+            None => (None, miner_accounts),
+        };
+        remaining_accounts = miner_accounts;
+
+        // Checkpoint miner.
+        let (rewards_sol, bot_fee) = checkpoint_miner(
+            &clock,
+            config,
+            board_info,
+            round_info,
+            treasury,
+            miner,
+            guild_info,
+            (achievements_info, ore_program),
+            season_accounts,
+            (signer_info, system_program),
+        )?;
+
+        // Pay out the miner's rewards net of its bot fee. The round collects the bot fees.
+        if rewards_sol > bot_fee {
+            round_info.send(rewards_sol - bot_fee, miner_info);
+        } else if bot_fee > rewards_sol {
+            miner_info.send(bot_fee - rewards_sol, round_info);
+        }
+        total_bot_fee += bot_fee;
+        miner_count += 1;
+
+        // Assert miner account has sufficient funds for rent and rewards.
+        assert!(
+            miner_info.lamports() >= required_rent + miner.checkpoint_fee + miner.rewards_sol,
+            "Miner does not have sufficient funds for rent and rewards"
+        );
+    }
+
+    // Pay the signer the bot fees.
+    if total_bot_fee > 0 {
+        round_info.send(total_bot_fee, signer_info);
+    }
+    debug_log!("Checkpointed {} miners", miner_count);
+
+    Ok(())
+}
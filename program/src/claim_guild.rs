@@ -0,0 +1,68 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
+use steel::*;
+
+use crate::token::*;
+
+/// Claims a guild's share of its members' ORE rewards to the guild authority.
+pub fn process_claim_guild(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, guild_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let guild = guild_info
+        .as_account_mut::<Guild>(&skill_api::ID)?
+        .assert_mut_err(
+            |g| g.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    load_skill_mint(mint_info, token_program)?;
+    recipient_info.is_writable()?;
+    treasury_info.has_address(&TREASURY_ADDRESS)?;
+    load_skill_token_account(treasury_tokens_info, treasury_info.key, token_program)?;
+    system_program.is_program(&system_program::ID)?;
+    associated_token_program.is_program(&spl_associated_token_account::ID)?;
+
+    // Load recipient.
+    if recipient_info.data_is_empty() {
+        create_skill_token_account(
+            signer_info,
+            signer_info,
+            recipient_info,
+            mint_info,
+            system_program,
+            token_program,
+            associated_token_program,
+        )?;
+    } else {
+        load_skill_token_account(recipient_info, signer_info.key, token_program)?;
+    }
+
+    // Guild rewards are held by the treasury outside of total_unclaimed, so they pay no claim fee.
+    let amount = guild.claim_ore();
+    sol_log(
+        &format!(
+            "Claiming {} ORE for guild #{}",
+            amount_to_ui_amount(amount, TOKEN_DECIMALS),
+            guild.id
+        )
+        .as_str(),
+    );
+
+    // Transfer reward to recipient.
+    transfer_skill_signed(
+        treasury_info,
+        treasury_tokens_info,
+        recipient_info,
+        mint_info,
+        token_program,
+        amount,
+        &[TREASURY],
+    )?;
+
+    Ok(())
+}
@@ -1,34 +1,85 @@
 use skill_api::prelude::*;
-use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::{migrate_miner, token::*};
+
 /// Claims a block reward.
 pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program] =
+    let [signer_info, miner_info, mint_info, recipient_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, config_info, referrer_miner_info, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    migrate_miner(miner_info, signer_info)?;
     let miner = miner_info
         .as_account_mut::<Miner>(&skill_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
-    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+
+    // Miners with a claim recipient are paid to the recipient's token account.
+    let owner_info = if miner.has_claim_recipient() {
+        let [claim_recipient_info, ..] = optional_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        claim_recipient_info.has_address(&miner.claim_recipient)?
+    } else {
+        signer_info
+    };
+
+    claim_ore_to(
+        &clock,
+        signer_info,
+        miner_info,
+        owner_info,
+        mint_info,
+        recipient_info,
+        treasury_info,
+        treasury_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+        config_info,
+        referrer_miner_info,
+    )
+}
+
+/// Claims a miner's ORE rewards to the token account of `owner_info`, which the payer creates if
+/// it does not exist yet.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_ore_to<'info>(
+    clock: &Clock,
+    payer_info: &AccountInfo<'info>,
+    miner_info: &AccountInfo<'info>,
+    owner_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    recipient_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    treasury_tokens_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    config_info: &AccountInfo<'info>,
+    referrer_miner_info: &AccountInfo<'info>,
+) -> ProgramResult {
+    let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
+    load_skill_mint(mint_info, token_program)?;
     recipient_info.is_writable()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
-    treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    load_skill_token_account(treasury_tokens_info, treasury_info.key, token_program)?;
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
+    let config = config_info
+        .has_address(&CONFIG_ADDRESS)?
+        .as_account::<Config>(&skill_api::ID)?;
 
     // Load recipient.
     if recipient_info.data_is_empty() {
-        create_associated_token_account(
-            signer_info,
-            signer_info,
+        create_skill_token_account(
+            payer_info,
+            owner_info,
             recipient_info,
             mint_info,
             system_program,
@@ -36,25 +87,48 @@ pub fn process_claim_ore(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramR
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(signer_info.key, mint_info.key)?;
+        load_skill_token_account(recipient_info, owner_info.key, token_program)?;
     }
 
+    // Load referrer. The referrer's share of the claim fee is only paid if their miner exists.
+    let referral_fee = if miner.has_referrer() {
+        referrer_miner_info
+            .is_writable()?
+            .has_seeds(&[MINER, &miner.referrer.to_bytes()], &skill_api::ID)?;
+        if referrer_miner_info.data_is_empty() {
+            0
+        } else {
+            config.referral_fee
+        }
+    } else {
+        0
+    };
+
     // Normalize amount.
-    let amount = miner.claim_ore(&clock, treasury);
+    let (amount, referral_reward) = miner.claim_ore(clock, treasury, referral_fee);
+
+    // Credit the referrer.
+    if referral_reward > 0 {
+        migrate_miner(referrer_miner_info, payer_info)?;
+        let referrer_miner = referrer_miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
+        referrer_miner.accrue_referral(referral_reward);
+        debug_log!(
+            "Referral reward: {} ORE",
+            amount_to_ui_amount(referral_reward, TOKEN_DECIMALS)
+        );
+    }
 
-    sol_log(
-        &format!(
-            "Claiming {} ORE",
-            amount_to_ui_amount(amount, TOKEN_DECIMALS)
-        )
-        .as_str(),
+    debug_log!(
+        "Claiming {} ORE",
+        amount_to_ui_amount(amount, TOKEN_DECIMALS)
     );
 
     // Transfer reward to recipient.
-    transfer_signed(
+    transfer_skill_signed(
         treasury_info,
         treasury_tokens_info,
         recipient_info,
+        mint_info,
         token_program,
         amount,
         &[TREASURY],
@@ -0,0 +1,55 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::read_round;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Claims a leader's share of a season's prize pool. Claims open once the season's last round
+/// has expired, after which no more season points can be checkpointed.
+pub fn process_claim_season(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, board_info, round_info, season_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let season = season_info.as_account_mut::<Season>(&skill_api::ID)?;
+    board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == season.board_id)?
+        .assert(|b| b.round_id > season.end_round)?;
+    round_info.has_address(&round_pda(season.board_id, season.end_round).0)?;
+
+    // Closed rounds have already expired.
+    if !round_info.data_is_empty() && clock.slot < read_round(round_info)?.expires_at {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Find the signer on the leaderboard.
+    let Some(i) = season
+        .leaders
+        .iter()
+        .zip(season.leader_points.iter())
+        .position(|(l, &p)| *l == *signer_info.key && p > 0)
+    else {
+        return Err(OreError::NotAuthorized.into());
+    };
+    if season.claimed & (1 << i) != 0 {
+        sol_log("Season prize already claimed");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Pay out the leader's share.
+    let amount = season.prize_share(i);
+    season.claimed |= 1 << i;
+    season_info.send(amount, signer_info);
+    sol_log(&format!(
+        "Claimed {} SOL from season #{}",
+        amount as f64 / LAMPORTS_PER_SOL as f64,
+        season.id
+    ));
+
+    Ok(())
+}
@@ -1,29 +1,43 @@
 use skill_api::prelude::*;
-use solana_program::log::sol_log;
 use steel::*;
 
+use crate::migrate_miner;
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
 /// Claims a block reward.
 pub fn process_claim_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, miner_info, system_program] = accounts else {
+    let [signer_info, miner_info, system_program, optional_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    migrate_miner(miner_info, signer_info)?;
     let miner = miner_info
         .as_account_mut::<Miner>(&skill_api::ID)?
         .assert_mut(|m| m.authority == *signer_info.key)?;
     system_program.is_program(&system_program::ID)?;
 
+    // Miners with a claim recipient are paid to the recipient.
+    let recipient_info = if miner.has_claim_recipient() {
+        let [recipient_info, ..] = optional_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        recipient_info
+            .is_writable()?
+            .has_address(&miner.claim_recipient)?
+    } else {
+        signer_info
+    };
+
     // Normalize amount.
     let amount = miner.claim_sol(&clock);
 
-    sol_log(&format!("Claiming {} SOL", amount as f64 / LAMPORTS_PER_SOL as f64).as_str());
+    debug_log!("Claiming {} SOL", amount as f64 / LAMPORTS_PER_SOL as f64);
 
     // Transfer reward to recipient.
-    miner_info.send(amount, signer_info);
+    miner_info.send(amount, recipient_info);
 
     Ok(())
 }
@@ -3,6 +3,8 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::token::*;
+
 /// Claims yield from the staking contract.
 pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -17,22 +19,23 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+    load_skill_mint(mint_info, token_program)?;
     recipient_info.is_writable()?;
     let stake = stake_info
         .as_account_mut::<Stake>(&skill_api::ID)?
         .assert_mut(|s| s.authority == *signer_info.key)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
-    treasury_tokens_info
-        .is_writable()?
-        .as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    load_skill_token_account(
+        treasury_tokens_info.is_writable()?,
+        treasury_info.key,
+        token_program,
+    )?;
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
     // Open recipient token account.
     if recipient_info.data_is_empty() {
-        create_associated_token_account(
+        create_skill_token_account(
             signer_info,
             signer_info,
             recipient_info,
@@ -42,17 +45,18 @@ pub fn process_claim_yield(accounts: &[AccountInfo<'_>], data: &[u8]) -> Program
             associated_token_program,
         )?;
     } else {
-        recipient_info.as_associated_token_account(&signer_info.key, &mint_info.key)?;
+        load_skill_token_account(recipient_info, signer_info.key, token_program)?;
     }
 
     // Claim yield from stake account.
     let amount = stake.claim(amount, &clock, treasury);
 
     // Transfer ORE to recipient.
-    transfer_signed(
+    transfer_skill_signed(
         treasury_info,
         treasury_tokens_info,
         recipient_info,
+        mint_info,
         token_program,
         amount,
         &[TREASURY],
@@ -2,6 +2,8 @@ use skill_api::prelude::*;
 use solana_program::rent::Rent;
 use steel::*;
 
+use crate::read_round;
+
 /// Closes a round accound, and returns the rent to the rent payer.
 pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
@@ -14,17 +16,21 @@ pub fn process_close(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     signer_info.is_signer()?;
     let board = board_info.as_account_mut::<Board>(&skill_api::ID)?;
     rent_payer_info.is_writable()?;
-    round_info
-        .as_account_mut::<Round>(&skill_api::ID)?
-        .assert_mut(|r| r.id < board.round_id)?
-        .assert_mut(|r| r.expires_at < clock.slot)? // Ensure round has expired.
-        .assert_mut(|r| r.rent_payer == *rent_payer_info.key)?; // Ensure the rent payer is the correct one.
+
+    // Ensure the round has expired, belongs to this board and is closed to its rent payer.
+    let round = read_round(round_info)?;
+    if round.id >= board.round_id
+        || round.expires_at >= clock.slot
+        || round.rent_payer != *rent_payer_info.key
+        || round_pda(board.id, round.id).0 != *round_info.key
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
     system_program.is_program(&system_program::ID)?;
 
-    // Vault all unclaimed rewards.
-    let size = 8 + std::mem::size_of::<Round>();
-    let min_rent = Rent::get()?.minimum_balance(size);
+    // Vault all unclaimed rewards. Rounds from before 6x6 boards are closed without migrating.
+    let min_rent = Rent::get()?.minimum_balance(round_info.data_len());
     let unclaimed_sol = round_info.lamports() - min_rent;
     if unclaimed_sol > 0 {
         round_info.send(unclaimed_sol, treasury_info);
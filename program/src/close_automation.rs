@@ -0,0 +1,52 @@
+use skill_api::prelude::*;
+use solana_program::log::{sol_log, sol_log_data};
+use steel::*;
+
+/// Returns the guild-funded part of an automation's balance to the guild that funded it. The
+/// guild account is required whenever the automation holds guild funds.
+pub fn return_guild_balance<'info>(
+    automation_info: &AccountInfo<'info>,
+    automation: &mut Automation,
+    guild_info: Option<&AccountInfo<'info>>,
+) -> ProgramResult {
+    if automation.guild_balance == 0 {
+        automation.guild = Pubkey::default();
+        return Ok(());
+    }
+    let Some(guild_info) = guild_info else {
+        sol_log("Guild is required to return its funds");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let guild = guild_info
+        .is_writable()?
+        .has_address(&automation.guild)?
+        .as_account_mut::<Guild>(&skill_api::ID)?;
+    let amount = automation.take_guild_balance();
+    guild.balance += amount;
+    automation_info.send(amount, guild_info);
+    Ok(())
+}
+
+/// Closes an automation and logs the reason. Guild funds go back to the guild, and the rest to
+/// the authority.
+pub fn close_automation<'info>(
+    automation_info: &AccountInfo<'info>,
+    automation: &mut Automation,
+    authority_info: &AccountInfo<'info>,
+    guild_info: Option<&AccountInfo<'info>>,
+    reason: AutomationCloseReason,
+) -> ProgramResult {
+    debug_log!("Closing automation: {:?} ({})", reason, reason as u8);
+    sol_log_data(&[AutomationClosedEvent {
+        disc: 12,
+        authority: automation.authority,
+        board_id: automation.board_id,
+        reason: reason as u64,
+        rounds: automation.rounds,
+        balance: automation.balance,
+        ts: Clock::get()?.unix_timestamp,
+    }
+    .to_bytes()]);
+    return_guild_balance(automation_info, automation, guild_info)?;
+    automation_info.close(authority_info)
+}
@@ -1,7 +1,12 @@
 use skill_api::prelude::*;
-use solana_program::{keccak::hashv, log::sol_log};
+use solana_program::{
+    keccak::hashv,
+    log::{sol_log, sol_log_data},
+};
 use steel::*;
 
+use crate::{close_automation, migrate_miner, migrate_round};
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
 /// Deploys capital to prospect on a square.
@@ -9,13 +14,14 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     // Parse data.
     let args = Deploy::try_from_bytes(data)?;
     let mut amount = u64::from_le_bytes(args.amount);
-    let mask = u32::from_le_bytes(args.squares);
+    let mask = u64::from_le_bytes(args.squares);
+    let referrer = Pubkey::new_from_array(args.referrer);
 
     // TODO Need config account...
 
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program] =
+    let [signer_info, authority_info, automation_info, board_info, miner_info, round_info, system_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -30,11 +36,50 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         .as_account_mut::<Board>(&skill_api::ID)?
         .assert_mut(|b| b.end_slot == u64::MAX || (clock.slot >= b.start_slot && clock.slot < b.end_slot))?;
 
-    round_info.is_writable()?;
+    let board_id = board.id.to_le_bytes();
+    let round_id = board.round_id.to_le_bytes();
+    round_info
+        .is_writable()?
+        .has_seeds(&round_seeds(&board_id, &round_id), &skill_api::ID)?;
+    miner_info
+        .is_writable()?
+        .has_seeds(&[MINER, &authority_info.key.to_bytes()], &skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    migrate_miner(miner_info, signer_info)?;
+
+    // Check if signer is the automation executor.
+    let mut automation = if !automation_info.data_is_empty() {
+        let automation = automation_info
+            .as_account_mut::<Automation>(&skill_api::ID)?
+            .assert_mut(|a| a.executor == *signer_info.key)?
+            .assert_mut(|a| a.authority == *authority_info.key)?
+            .assert_mut(|a| a.board_id == board.id)?;
+        Some(automation)
+    } else {
+        None
+    };
+
+    // Enforce automation limits, before the deploy can open or start the round.
+    if let Some(automation) = &mut automation {
+        if let Ok(miner) = miner_info.as_account::<Miner>(&skill_api::ID) {
+            automation.sync_rewards(miner.lifetime_rewards_sol);
+        }
+        if let Some(reason) = automation.tripped_limit(clock.slot) {
+            let guild_info = optional_accounts.first();
+            close_automation(
+                automation_info,
+                automation,
+                authority_info,
+                guild_info,
+                reason,
+            )?;
+            return Ok(());
+        }
+    }
 
     // Create Round account if it doesn't exist (first deploy after init)
-    // Also handle v0.5 migration where old rounds had smaller size (560 vs 568 bytes)
-    let expected_size = 8 + std::mem::size_of::<Round>();
+    // Also migrate rounds created before the account was extended.
+    migrate_round(round_info, signer_info)?;
     let round = if round_info.data_is_empty() {
         // create_program_account validates the PDA seeds
         create_program_account::<Round>(
@@ -42,13 +87,13 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             system_program,
             signer_info,
             &skill_api::ID,
-            &[ROUND, &board.round_id.to_le_bytes()],
+            &round_seeds(&board_id, &round_id),
         )?;
         let round = round_info.as_account_mut::<Round>(&skill_api::ID)?;
         round.id = board.round_id;
-        round.deployed = [0; 25];
+        round.deployed = [0; MAX_SQUARES];
         round.slot_hash = [0; 32];
-        round.count = [0; 25];
+        round.count = [0; MAX_SQUARES];
         round.expires_at = u64::MAX;
         round.rent_payer = *signer_info.key;
         round.motherlode = 0;
@@ -58,76 +103,32 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         round.total_vaulted = 0;
         round.total_winnings = 0;
         round.winning_square = 0;
-        round.bonus_squares = [0; 3];
-        round._padding = [0; 4];
+        round.bonus_squares = [NO_BONUS_SQUARE; MAX_BONUS_SQUARES];
         // v0.6 commit-reveal fields (slots set when round starts)
         round.commit_start_slot = 0;
         round.reveal_start_slot = 0;
-        round.revealed_count = [0; 25];
+        round.revealed_count = [0; MAX_SQUARES];
         round.total_reveals = 0;
         round
-    } else if round_info.data_len() < expected_size {
-        // v0.5 Migration: Old round account needs reallocation
-        // Transfer additional rent from signer BEFORE resizing (using system program)
-        let rent = solana_program::rent::Rent::get()?;
-        let new_minimum_balance = rent.minimum_balance(expected_size);
-        let current_balance = round_info.lamports();
-        if current_balance < new_minimum_balance {
-            let diff = new_minimum_balance - current_balance;
-            // Use collect() to transfer from signer to round via system program
-            round_info.collect(diff, signer_info)?;
-        }
-
-        // Now resize the account
-        round_info.resize(expected_size)?;
-
-        // Initialize new fields with defaults
-        let mut data = round_info.try_borrow_mut_data()?;
-        // winning_square at offset 560, _padding at 561-567
-        if data.len() >= 568 {
-            data[560] = 0; // winning_square
-            data[561..568].copy_from_slice(&[0; 7]); // _padding
-        }
-        drop(data);
-
-        round_info
-            .as_account_mut::<Round>(&skill_api::ID)?
-            .assert_mut(|r| r.id == board.round_id)?
     } else {
         round_info
             .as_account_mut::<Round>(&skill_api::ID)?
             .assert_mut(|r| r.id == board.round_id)?
     };
 
-    miner_info
-        .is_writable()?
-        .has_seeds(&[MINER, &authority_info.key.to_bytes()], &skill_api::ID)?;
-    system_program.is_program(&system_program::ID)?;
-
     // Wait until first deploy to start round.
-    // v0.6 Commit-Reveal: deploy(60) -> commit(30) -> reveal(30) = 120 slots total (~48 seconds)
+    // v0.6 Commit-Reveal: deploy -> commit -> reveal, with phase lengths set per board.
     if board.end_slot == u64::MAX {
         board.start_slot = clock.slot;
-        // v0.6: Use Round timing constants for commit-reveal phases
-        round.commit_start_slot = board.start_slot + Round::DEPLOY_PHASE_SLOTS;
-        round.reveal_start_slot = round.commit_start_slot + Round::COMMIT_PHASE_SLOTS;
-        board.end_slot = round.reveal_start_slot + Round::REVEAL_PHASE_SLOTS;
+        round.commit_start_slot = board.start_slot + board.deploy_slots;
+        round.reveal_start_slot = round.commit_start_slot + board.commit_slots;
+        board.end_slot = round.reveal_start_slot + board.reveal_slots;
         round.expires_at = board.end_slot + ONE_DAY_SLOTS;
     }
 
-    // Check if signer is the automation executor.
-    let automation = if !automation_info.data_is_empty() {
-        let automation = automation_info
-            .as_account_mut::<Automation>(&skill_api::ID)?
-            .assert_mut(|a| a.executor == *signer_info.key)?
-            .assert_mut(|a| a.authority == *authority_info.key)?;
-        Some(automation)
-    } else {
-        None
-    };
-
     // Update amount and mask for automation.
-    let mut squares = [false; 25];
+    let num_squares = board.squares as usize;
+    let mut squares = [false; MAX_SQUARES];
     if let Some(automation) = &automation {
         // Set amount
         amount = automation.amount;
@@ -136,25 +137,54 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         match AutomationStrategy::from_u64(automation.strategy as u64) {
             AutomationStrategy::Preferred => {
                 // Preferred automation strategy. Use the miner authority's provided mask.
-                for i in 0..25 {
+                for i in 0..num_squares {
                     squares[i] = (automation.mask & (1 << i)) != 0;
                 }
             }
             AutomationStrategy::Random => {
                 // Random automation strategy. Generate a random mask based on number of squares user wants to deploy to.
-                let num_squares = ((automation.mask & 0xFF) as u64).min(25);
+                let k = ((automation.mask & 0xFF) as u64).min(num_squares as u64);
                 let r = hashv(&[&automation.authority.to_bytes(), &round.id.to_le_bytes()]).0;
-                squares = generate_random_mask(num_squares, &r);
+                squares = generate_random_mask(k, num_squares, &r);
+            }
+            AutomationStrategy::FollowCrowd => {
+                // Follow the crowd. Deploy to the current leading square.
+                squares[round.squares_by_deployed(num_squares)[0]] = true;
+            }
+            AutomationStrategy::TopK => {
+                // Deploy to the K most crowded squares at execution time.
+                let k = ((automation.mask & 0xFF) as usize).min(num_squares);
+                for &square_id in round.squares_by_deployed(num_squares)[..k].iter() {
+                    squares[square_id] = true;
+                }
+            }
+            AutomationStrategy::Bonus => {
+                // Deploy to the bonus squares for this round.
+                for square_id in round.active_bonus_squares() {
+                    squares[square_id as usize] = true;
+                }
+            }
+            AutomationStrategy::Contrarian => {
+                // Deploy to the K least crowded squares, never the leading square.
+                let k = ((automation.mask & 0xFF) as usize).min(num_squares - 1);
+                for &square_id in round.squares_by_deployed(num_squares)[num_squares - k..].iter() {
+                    squares[square_id] = true;
+                }
             }
         }
     } else {
-        // Convert provided 32-bit mask into array of 25 booleans, where each bit in the mask
+        // Convert provided 64-bit mask into array of booleans, where each bit in the mask
         // determines if that square index is selected (true) or not (false)
-        for i in 0..25 {
+        for i in 0..num_squares {
             squares[i] = (mask & (1 << i)) != 0;
         }
     }
 
+    // Enforce the board's minimum stake.
+    if amount < board.min_deploy {
+        return Err(OreError::AmountTooSmall.into());
+    }
+
     // Open miner account.
     let miner = if miner_info.data_is_empty() {
         create_program_account::<Miner>(
@@ -166,14 +196,20 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         )?;
         let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
         miner.authority = *signer_info.key;
-        miner.deployed = [0; 25];
-        miner.cumulative = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
+        miner.cumulative = [0; MAX_SQUARES];
         miner.rewards_sol = 0;
         miner.rewards_ore = 0;
         miner.round_id = 0;
         miner.checkpoint_id = 0;
         miner.lifetime_rewards_sol = 0;
         miner.lifetime_rewards_ore = 0;
+        miner.referrer = if referrer != *signer_info.key {
+            referrer
+        } else {
+            Pubkey::default()
+        };
+        miner.referral_rewards_ore = 0;
         miner
     } else {
         miner_info
@@ -188,7 +224,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
     };
 
     // Reset miner
-    if miner.round_id != round.id {
+    if miner.board_id != board.id || miner.round_id != round.id {
         // Assert miner has checkpointed prior round.
         assert!(
             miner.checkpoint_id == miner.round_id,
@@ -196,17 +232,35 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         );
 
         // Reset miner for new round.
-        miner.deployed = [0; 25];
+        miner.deployed = [0; MAX_SQUARES];
         miner.cumulative = round.deployed;
+        miner.board_id = board.id;
         miner.round_id = round.id;
     }
 
+    // Enforce the automation's daily spend cap.
+    let first_deploy = miner.deployed.iter().all(|&d| d == 0);
+    let fee = automation.as_ref().map_or(0, |a| a.fee);
+    let daily_remaining = automation
+        .as_ref()
+        .and_then(|a| a.daily_remaining(clock.slot));
+    if let Some(remaining) = daily_remaining {
+        if amount + fee > remaining {
+            sol_log("Automation has reached its daily spend cap");
+            return Err(OreError::DailyCapReached.into());
+        }
+    }
+    let spend_remaining = automation
+        .as_ref()
+        .and_then(|a| a.spend_remaining(clock.slot));
+
     // Calculate all deployments.
     let mut total_amount = 0;
     let mut total_squares = 0;
+    let mut deployed_mask = 0u64;
     for (square_id, &should_deploy) in squares.iter().enumerate() {
         // Skip if square index is out of bounds.
-        if square_id > 24 {
+        if square_id >= num_squares {
             break;
         }
 
@@ -220,6 +274,13 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
             continue;
         }
 
+        // Exit early if the automation's daily spend cap or stop-loss would be exceeded.
+        if let Some(remaining) = spend_remaining {
+            if total_amount + amount + fee > remaining {
+                break;
+            }
+        }
+
         // Record cumulative amount.
         miner.cumulative[square_id] = round.deployed[square_id];
 
@@ -234,6 +295,7 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
         // Update totals.
         total_amount += amount;
         total_squares += 1;
+        deployed_mask |= 1 << square_id;
 
         // Exit early if automation does not have enough balance for another square.
         if let Some(automation) = &automation {
@@ -251,45 +313,60 @@ pub fn process_deploy(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResul
 
     // Transfer SOL.
     if let Some(automation) = automation {
-        automation.balance -= total_amount + automation.fee;
+        automation.spend(total_amount + automation.fee);
+        automation.record_spend(total_amount + automation.fee, clock.slot);
+        if first_deploy && total_squares > 0 {
+            automation.rounds += 1;
+        }
         automation_info.send(total_amount, &round_info);
         automation_info.send(automation.fee, &signer_info);
 
-        // Close automation if balance is less than what's required to deploy 1 square.
-        if automation.balance < automation.amount + automation.fee {
-            automation_info.close(authority_info)?;
+        // Close automation if a limit has been reached, or if balance is less than what's
+        // required to deploy 1 square.
+        let reason = automation
+            .tripped_limit(clock.slot)
+            .or((automation.balance < automation.amount + automation.fee)
+                .then_some(AutomationCloseReason::Depleted));
+        if let Some(reason) = reason {
+            let guild_info = optional_accounts.first();
+            close_automation(
+                automation_info,
+                automation,
+                authority_info,
+                guild_info,
+                reason,
+            )?;
         }
     } else {
         round_info.collect(total_amount, &signer_info)?;
     }
 
     // Log
-    sol_log(
-        &format!(
-            "Round #{}: deploying {} SOL to {} squares",
-            round.id,
-            amount as f64 / LAMPORTS_PER_SOL as f64,
-            total_squares,
-        )
-        .as_str(),
+    debug_log!(
+        "Round #{}: deploying {} SOL to {} squares",
+        round.id,
+        amount as f64 / LAMPORTS_PER_SOL as f64,
+        total_squares
     );
+    sol_log_data(&[DeployEvent {
+        disc: 13,
+        authority: miner.authority,
+        amount,
+        mask: deployed_mask,
+        round_id: round.id,
+        ts: clock.unix_timestamp,
+        board_id: board.id,
+    }
+    .to_bytes()]);
 
     Ok(())
 }
 
-fn generate_random_mask(num_squares: u64, r: &[u8]) -> [bool; 25] {
-    let mut new_mask = [false; 25];
-    let mut selected = 0;
-    for i in 0..25 {
-        let rand_byte = r[i];
-        let remaining_needed = num_squares as u64 - selected as u64;
-        let remaining_positions = 25 - i;
-        if remaining_needed > 0
-            && (rand_byte as u64) * (remaining_positions as u64) < (remaining_needed * 256)
-        {
-            new_mask[i] = true;
-            selected += 1;
-        }
+/// Picks `k` distinct squares uniformly at random, seeded by `r`.
+fn generate_random_mask(k: u64, num_squares: usize, r: &[u8; 32]) -> [bool; MAX_SQUARES] {
+    let mut new_mask = [false; MAX_SQUARES];
+    for square_id in Round::sample_squares(r, num_squares, k as usize) {
+        new_mask[square_id as usize] = true;
     }
     new_mask
 }
@@ -3,6 +3,8 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::token::*;
+
 /// Deposits ORE into the staking contract.
 pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -18,14 +20,12 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     };
     signer_info.is_signer()?;
     payer_info.is_signer()?;
-    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
-    let sender = sender_info
-        .is_writable()?
-        .as_associated_token_account(&signer_info.key, &MINT_ADDRESS)?;
+    load_skill_mint(mint_info, token_program)?;
+    let sender =
+        load_skill_token_account(sender_info.is_writable()?, signer_info.key, token_program)?;
     stake_info.is_writable()?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
     // Open stake account.
@@ -55,7 +55,7 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
 
     // Create stake tokens account.
     if stake_tokens_info.data_is_empty() {
-        create_associated_token_account(
+        create_skill_token_account(
             signer_info,
             stake_info,
             stake_tokens_info,
@@ -65,20 +65,21 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             associated_token_program,
         )?;
     } else {
-        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+        load_skill_token_account(stake_tokens_info, stake_info.key, token_program)?;
     }
 
     // Only allow deposits from seekers.
     // assert!(stake.is_seeker == 1, "Only seekers can deposit stake");
 
     // Deposit into stake account.
-    let amount = stake.deposit(amount, &clock, treasury, &sender);
+    let amount = stake.deposit(amount, &clock, treasury, sender.amount);
 
     // Transfer ORE to treasury.
-    transfer(
+    transfer_skill(
         signer_info,
         sender_info,
         stake_tokens_info,
+        mint_info,
         token_program,
         amount,
     )?;
@@ -93,9 +94,8 @@ pub fn process_deposit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
     );
 
     // Safety check.
-    let stake_tokens =
-        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    let stake_tokens = load_skill_token_account(stake_tokens_info, stake_info.key, token_program)?;
+    assert!(stake_tokens.amount >= stake.balance);
 
     Ok(())
 }
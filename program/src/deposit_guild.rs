@@ -0,0 +1,23 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Deposits SOL into a guild's treasury.
+pub fn process_deposit_guild(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = DepositGuild::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let [signer_info, guild_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let guild = guild_info.as_account_mut::<Guild>(&skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Deposit SOL.
+    guild.balance += amount;
+    guild_info.collect(amount, signer_info)?;
+
+    Ok(())
+}
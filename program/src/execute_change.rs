@@ -0,0 +1,84 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Applies a scheduled change to the config, or to a board, once its effective slot has passed.
+pub fn process_execute_change(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, config_info, change_info, proposer_info, optional_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&skill_api::ID)?;
+    let change = change_info
+        .is_writable()?
+        .as_account::<Change>(&skill_api::ID)?
+        .assert(|c| c.proposer == *proposer_info.key)?;
+    proposer_info.is_writable()?;
+
+    // Assert change is effective.
+    if clock.slot < change.effective_slot {
+        sol_log(&format!(
+            "Change #{} is effective at slot {}",
+            change.id, change.effective_slot
+        ));
+        return Err(OreError::ChangeNotReady.into());
+    }
+
+    // Load the board a board change applies to.
+    let kind = ChangeKind::from_u64(change.kind);
+    let board = if kind.is_board_change() {
+        let [board_info, ..] = optional_accounts else {
+            sol_log("The board is required to execute a board change");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let board = board_info
+            .is_writable()?
+            .has_address(&board_pda(change.value_board_id()).0)?
+            .as_account_mut::<Board>(&skill_api::ID)?;
+        Some(board)
+    } else {
+        None
+    };
+
+    // Apply change.
+    match kind {
+        ChangeKind::SwapProgram => config.swap_program = change.value_address(),
+        ChangeKind::VarAddress => config.var_address = change.value_address(),
+        ChangeKind::FeeCollector => config.fee_collector = change.value_address(),
+        ChangeKind::AdminFee => {
+            let board = board.unwrap();
+            board.admin_fee = change.value_u64().min(MAX_BOARD_ADMIN_FEE);
+        }
+        ChangeKind::BoardWeight => {
+            let board = board.unwrap();
+            let weight = change.value_u64();
+            config.total_board_weight =
+                config.total_board_weight.saturating_sub(board.weight) + weight;
+            board.weight = weight;
+        }
+        ChangeKind::ChangeDelay => {
+            config.change_delay = change.value_u64().min(MAX_CHANGE_DELAY_SLOTS)
+        }
+        ChangeKind::BuybackCap => config.buyback_cap = change.value_u64(),
+        ChangeKind::LiqManager => config.liq_manager = change.value_address(),
+        ChangeKind::ReferralFee => config.referral_fee = change.value_u64().min(DENOMINATOR_BPS),
+        ChangeKind::VestingPeriod => config.vesting_period = change.value_u64(),
+        ChangeKind::MotherlodeOdds => config.motherlode_odds = change.value_u64(),
+        ChangeKind::MotherlodeRate => config.motherlode_rate = change.value_u64(),
+        ChangeKind::RngMode => config.rng_mode = change.value_u64(),
+        ChangeKind::BonusSquareCount => config.bonus_square_count = change.value_u64(),
+        ChangeKind::BonusSquareMultiplier => {
+            config.bonus_square_multiplier =
+                change.value_u64().clamp(100, MAX_BONUS_SQUARE_MULTIPLIER)
+        }
+    }
+    sol_log(&format!("Change #{}: {:?} executed", change.id, kind));
+
+    // Close change account.
+    change_info.close(proposer_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Moves SOL from a guild's treasury into a member's automation, which the guild's executor then
+/// deploys on the member's behalf. The automation must be run by the guild's executor. The funds
+/// stay the guild's: they return to it when the automation closes or the member leaves.
+pub fn process_fund_member(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = FundMember::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
+    // Load accounts.
+    let [signer_info, guild_info, miner_info, automation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let guild = guild_info
+        .as_account_mut::<Guild>(&skill_api::ID)?
+        .assert_mut_err(
+            |g| g.authority == *signer_info.key || g.executor == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?
+        .assert_mut(|g| g.balance >= amount)?;
+    let miner = miner_info
+        .as_account::<Miner>(&skill_api::ID)?
+        .assert(|m| m.guild == *guild_info.key)?;
+    let automation = automation_info
+        .as_account_mut::<Automation>(&skill_api::ID)?
+        .assert_mut(|a| a.authority == miner.authority)?
+        .assert_mut(|a| a.executor == guild.executor)?
+        .assert_mut(|a| a.guild_balance == 0 || a.guild == *guild_info.key)?;
+
+    // Fund automation.
+    guild.balance -= amount;
+    automation.fund_from_guild(*guild_info.key, amount);
+    guild_info.send(amount, automation_info);
+    sol_log(&format!(
+        "Funded {} with {} SOL from guild #{}",
+        miner.authority,
+        amount as f64 / LAMPORTS_PER_SOL as f64,
+        guild.id
+    ));
+
+    Ok(())
+}
@@ -1,16 +1,21 @@
 use skill_api::prelude::*;
 use solana_program::log::sol_log;
-use solana_program::program::invoke_signed;
+use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_pack::Pack;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::{metadata_pointer, ExtensionType};
+use spl_token_metadata_interface::state::TokenMetadata;
 use steel::*;
 
+use crate::token::*;
+
 /// Initializes the SKILL protocol by creating:
 /// - Board PDA (singleton for round tracking)
 /// - Config PDA (singleton for admin configuration)
 /// - Treasury PDA (singleton for treasury management)
-/// - SKILL token mint (with Treasury as mint authority)
+/// - SKILL token mint (with Treasury as mint authority), owned by spl-token or Token-2022.
+///   Token-2022 mints carry on-chain metadata with Treasury as update authority.
 /// - Treasury's associated token account for SKILL
 pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -18,6 +23,9 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     let admin = Pubkey::new_from_array(args.admin);
     let fee_collector = Pubkey::new_from_array(args.fee_collector);
     let var_address = Pubkey::new_from_array(args.var_address);
+    let name = parse_metadata_str(&args.name)?;
+    let symbol = parse_metadata_str(&args.symbol)?;
+    let uri = parse_metadata_str(&args.uri)?;
 
     // Load accounts.
     let [signer_info, board_info, config_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, rent_sysvar] =
@@ -32,7 +40,11 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
 
     // Validate system programs.
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
+    if *token_program.key == spl_token_2022::ID {
+        token_program.is_program(&spl_token_2022::ID)?;
+    } else {
+        token_program.is_program(&spl_token::ID)?;
+    }
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
     rent_sysvar.is_sysvar(&sysvar::rent::ID)?;
 
@@ -67,6 +79,14 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     board.round_id = 0;
     board.start_slot = u64::MAX;
     board.end_slot = u64::MAX;
+    board.id = 0;
+    board.min_deploy = 0;
+    board.deploy_slots = Round::DEPLOY_PHASE_SLOTS;
+    board.commit_slots = Round::COMMIT_PHASE_SLOTS;
+    board.reveal_slots = Round::REVEAL_PHASE_SLOTS;
+    board.admin_fee = DEFAULT_BOARD_ADMIN_FEE;
+    board.weight = DEFAULT_BOARD_WEIGHT;
+    board.squares = LEGACY_MAX_SQUARES as u64;
 
     // Create Config account.
     sol_log("Creating Config account");
@@ -84,6 +104,26 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     config.swap_program = Pubkey::default();
     config.var_address = var_address;
     config.admin_fee = 0;
+    config.pending_admin = Pubkey::default();
+    config.fee_authority = admin;
+    config.pause_authority = admin;
+    config.change_delay = DEFAULT_CHANGE_DELAY_SLOTS;
+    config.change_count = 0;
+    config.paused = 0;
+    config.buyback_cap = DEFAULT_BUYBACK_CAP;
+    config.liq_manager = Pubkey::default();
+    config.referral_fee = DEFAULT_REFERRAL_FEE;
+    config.board_count = 1;
+    config.total_board_weight = DEFAULT_BOARD_WEIGHT;
+    config.season_count = 0;
+    config.guild_count = 0;
+    config.tournament_count = 0;
+    config.vesting_period = DEFAULT_VESTING_PERIOD;
+    config.motherlode_odds = DEFAULT_MOTHERLODE_ODDS;
+    config.motherlode_rate = DEFAULT_MOTHERLODE_RATE;
+    config.rng_mode = RNG_MODE_SLOT_HASHES;
+    config.bonus_square_count = DEFAULT_BONUS_SQUARE_COUNT;
+    config.bonus_square_multiplier = DEFAULT_BONUS_SQUARE_MULTIPLIER;
 
     // Create Treasury account.
     sol_log("Creating Treasury account");
@@ -110,39 +150,116 @@ pub fn process_initialize(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramR
     let (mint_pda, mint_bump) = Pubkey::find_program_address(&[MINT], &skill_api::ID);
     assert_eq!(*mint_info.key, mint_pda, "Mint address mismatch");
 
-    // Calculate rent and allocate
+    let treasury_bump = Pubkey::find_program_address(&[TREASURY], &skill_api::ID).1;
     let rent = Rent::get()?;
-    let mint_lamports = rent.minimum_balance(spl_token::state::Mint::LEN);
-
-    // Create account with system program using PDA signer
-    invoke_signed(
-        &solana_program::system_instruction::create_account(
-            signer_info.key,
-            mint_info.key,
-            mint_lamports,
-            spl_token::state::Mint::LEN as u64,
-            &spl_token::ID,
-        ),
-        &[signer_info.clone(), mint_info.clone(), system_program.clone()],
-        &[&[MINT, &[mint_bump]]],
-    )?;
+    if *token_program.key == spl_token_2022::ID {
+        // Allocate space for the metadata pointer, and fund the metadata written after it.
+        let metadata = TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            ..Default::default()
+        };
+        let mint_space =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                ExtensionType::MetadataPointer,
+            ])?;
+        let mint_lamports = rent.minimum_balance(mint_space + metadata.tlv_size_of()?);
+        invoke_signed(
+            &solana_program::system_instruction::create_account(
+                signer_info.key,
+                mint_info.key,
+                mint_lamports,
+                mint_space as u64,
+                &spl_token_2022::ID,
+            ),
+            &[
+                signer_info.clone(),
+                mint_info.clone(),
+                system_program.clone(),
+            ],
+            &[&[MINT, &[mint_bump]]],
+        )?;
 
-    // Initialize the mint with Treasury as mint/freeze authority.
-    invoke_signed(
-        &spl_token::instruction::initialize_mint2(
-            &spl_token::ID,
-            mint_info.key,
-            treasury_info.key,       // mint authority
-            Some(treasury_info.key), // freeze authority
-            TOKEN_DECIMALS,
-        )?,
-        &[mint_info.clone()],
-        &[],
-    )?;
+        // Point the metadata at the mint itself.
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                &spl_token_2022::ID,
+                mint_info.key,
+                Some(*treasury_info.key),
+                Some(*mint_info.key),
+            )?,
+            &[mint_info.clone()],
+        )?;
+
+        // Initialize the mint with Treasury as mint/freeze authority.
+        invoke(
+            &spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                mint_info.key,
+                treasury_info.key,
+                Some(treasury_info.key),
+                TOKEN_DECIMALS,
+            )?,
+            &[mint_info.clone()],
+        )?;
+
+        // Write the metadata, signed by Treasury as mint authority.
+        sol_log("Initializing SKILL metadata");
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                &spl_token_2022::ID,
+                mint_info.key,
+                treasury_info.key,
+                mint_info.key,
+                treasury_info.key,
+                name,
+                symbol,
+                uri,
+            ),
+            &[
+                mint_info.clone(),
+                treasury_info.clone(),
+                token_program.clone(),
+            ],
+            &[&[TREASURY, &[treasury_bump]]],
+        )?;
+    } else {
+        // Create account with system program using PDA signer
+        let mint_lamports = rent.minimum_balance(spl_token::state::Mint::LEN);
+        invoke_signed(
+            &solana_program::system_instruction::create_account(
+                signer_info.key,
+                mint_info.key,
+                mint_lamports,
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            &[
+                signer_info.clone(),
+                mint_info.clone(),
+                system_program.clone(),
+            ],
+            &[&[MINT, &[mint_bump]]],
+        )?;
+
+        // Initialize the mint with Treasury as mint/freeze authority.
+        invoke_signed(
+            &spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                mint_info.key,
+                treasury_info.key,       // mint authority
+                Some(treasury_info.key), // freeze authority
+                TOKEN_DECIMALS,
+            )?,
+            &[mint_info.clone()],
+            &[],
+        )?;
+    }
 
     // Create Treasury's associated token account for SKILL.
     sol_log("Creating Treasury token account");
-    create_associated_token_account(
+    create_skill_token_account(
         signer_info,
         treasury_info,
         treasury_tokens_info,
@@ -0,0 +1,29 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::migrate_miner;
+
+/// Adds the signer's miner to a guild. Miners must checkpoint their last round before joining,
+/// so the guild only shares in rounds played as a member.
+pub fn process_join_guild(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, miner_info, guild_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    migrate_miner(miner_info, signer_info)?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&skill_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut(|m| !m.has_guild())?
+        .assert_mut(|m| m.checkpoint_id == m.round_id)?;
+    let guild = guild_info.as_account_mut::<Guild>(&skill_api::ID)?;
+
+    // Join guild.
+    miner.guild = *guild_info.key;
+    guild.member_count += 1;
+    sol_log(&format!("Joined guild #{}: {}", guild.id, guild.name()));
+
+    Ok(())
+}
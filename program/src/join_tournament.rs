@@ -0,0 +1,36 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Enters the signer into a tournament, escrowing their entry fee. The roster closes once it is
+/// full or the first matchday has started.
+pub fn process_join_tournament(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, board_info, tournament_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let tournament = tournament_info
+        .as_account_mut::<Tournament>(&skill_api::ID)?
+        .assert_mut(|t| !t.is_full())?
+        .assert_mut(|t| t.player_index(signer_info.key).is_none())?;
+    board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == tournament.board_id)?
+        .assert(|b| b.round_id < tournament.start_round)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Join tournament.
+    let player = tournament.player_count as usize;
+    tournament.players[player] = *signer_info.key;
+    tournament.picks[player] = [NO_PICK; MAX_TOURNAMENT_PLAYERS];
+    tournament.player_count += 1;
+    tournament.prize += tournament.entry_fee;
+    tournament_info.collect(tournament.entry_fee, signer_info)?;
+    sol_log(&format!(
+        "Joined tournament #{} ({}/{})",
+        tournament.id, tournament.player_count, tournament.max_players
+    ));
+
+    Ok(())
+}
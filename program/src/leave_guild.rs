@@ -0,0 +1,38 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::return_guild_balance;
+
+/// Removes the signer's miner from its guild. Miners must checkpoint their last round before
+/// leaving, so the guild receives its share of it. Any guild funds left in the miner's automation
+/// are returned to the guild.
+pub fn process_leave_guild(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, miner_info, guild_info, automation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&skill_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?
+        .assert_mut(|m| m.guild == *guild_info.key)?
+        .assert_mut(|m| m.checkpoint_id == m.round_id)?;
+    automation_info
+        .is_writable()?
+        .has_seeds(&[AUTOMATION, &signer_info.key.to_bytes()], &skill_api::ID)?;
+
+    // Return guild funds.
+    if !automation_info.data_is_empty() {
+        let automation = automation_info.as_account_mut::<Automation>(&skill_api::ID)?;
+        return_guild_balance(automation_info, automation, Some(guild_info))?;
+    }
+    let guild = guild_info.as_account_mut::<Guild>(&skill_api::ID)?;
+
+    // Leave guild.
+    miner.guild = Pubkey::default();
+    guild.member_count -= 1;
+    sol_log(&format!("Left guild #{}: {}", guild.id, guild.name()));
+
+    Ok(())
+}
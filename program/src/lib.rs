@@ -1,60 +1,167 @@
+/// Logs a formatted message only when built with the `verbose-logs` feature. Without it the
+/// arguments are type-checked but never formatted, so hot paths pay no compute for them.
+macro_rules! debug_log {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "verbose-logs")]
+        solana_program::log::sol_log(&format!($($arg)*));
+        #[cfg(not(feature = "verbose-logs"))]
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
+
+mod accept_admin;
+mod arm_entropy;
 mod automate;
 mod bury;
 mod buyback;
+mod cancel_change;
 mod checkpoint;
+mod checkpoint_many;
+mod claim_guild;
 mod claim_ore;
+mod claim_season;
 mod claim_sol;
 mod claim_yield;
 mod close;
+mod close_automation;
 mod deploy;
 mod deposit;
+mod deposit_guild;
+mod execute_change;
+mod fund_member;
 mod initialize;
+mod join_guild;
+mod join_tournament;
+mod leave_guild;
 mod liq;
 mod log;
 mod migrate_automation;
+mod migrate_config;
+mod migrate_miner;
+mod migrate_round;
+mod new_board;
+mod new_guild;
+mod new_season;
+mod new_tournament;
 mod new_var;
+mod open_achievements;
 mod reload_sol;
 mod reset;
 mod reveal_choice;
+mod schedule_change;
+mod score_tournament;
 mod set_admin;
 mod set_admin_fee;
+mod set_automation_limits;
+mod set_automation_reveal;
+mod set_board_weight;
+mod set_bonus_square_count;
+mod set_bonus_square_multiplier;
+mod set_bury_authority;
+mod set_buyback_cap;
+mod set_change_delay;
+mod set_claim_recipient;
+mod set_fee_authority;
 mod set_fee_collector;
+mod set_liq_manager;
+mod set_motherlode_odds;
+mod set_motherlode_rate;
+mod set_pause;
+mod set_pause_authority;
+mod set_referral_fee;
+mod set_rng_mode;
 mod set_swap_program;
 mod set_var_address;
+mod set_vesting_period;
+mod settle_tournament;
 mod submit_commit;
 mod submit_prediction;
+mod sweep;
+mod token;
+mod update_board;
+mod update_guild;
+mod update_metadata_uri;
 mod withdraw;
 mod wrap;
 
+use accept_admin::*;
+use arm_entropy::*;
 use automate::*;
 use bury::*;
 use buyback::*;
+use cancel_change::*;
 use checkpoint::*;
+use checkpoint_many::*;
+use claim_guild::*;
 use claim_ore::*;
+use claim_season::*;
 use claim_sol::*;
 use claim_yield::*;
 use close::*;
+use close_automation::*;
 use deploy::*;
 use deposit::*;
+use deposit_guild::*;
+use execute_change::*;
+use fund_member::*;
 use initialize::*;
+use join_guild::*;
+use join_tournament::*;
+use leave_guild::*;
 use liq::*;
 use log::*;
 use migrate_automation::*;
+use migrate_config::*;
+use migrate_miner::*;
+use migrate_round::*;
+use new_board::*;
+use new_guild::*;
+use new_season::*;
+use new_tournament::*;
 use new_var::*;
+use open_achievements::*;
 use reload_sol::*;
 use reset::*;
 use reveal_choice::*;
+use schedule_change::*;
+use score_tournament::*;
 use set_admin::*;
 use set_admin_fee::*;
+use set_automation_limits::*;
+use set_automation_reveal::*;
+use set_board_weight::*;
+use set_bonus_square_count::*;
+use set_bonus_square_multiplier::*;
+use set_bury_authority::*;
+use set_buyback_cap::*;
+use set_change_delay::*;
+use set_claim_recipient::*;
+use set_fee_authority::*;
 use set_fee_collector::*;
+use set_liq_manager::*;
+use set_motherlode_odds::*;
+use set_motherlode_rate::*;
+use set_pause::*;
+use set_pause_authority::*;
+use set_referral_fee::*;
+use set_rng_mode::*;
 use set_swap_program::*;
 use set_var_address::*;
+use set_vesting_period::*;
+use settle_tournament::*;
 use submit_commit::*;
 use submit_prediction::*;
+use sweep::*;
+use update_board::*;
+use update_guild::*;
+use update_metadata_uri::*;
 use withdraw::*;
 use wrap::*;
 
-use skill_api::instruction::*;
+use skill_api::{consts::CONFIG_ADDRESS, error::OreError, instruction::*, state::Config};
+use solana_program::log::sol_log;
 use steel::*;
 
 pub fn process_instruction(
@@ -64,10 +171,29 @@ pub fn process_instruction(
 ) -> ProgramResult {
     let (ix, data) = parse_instruction(&skill_api::ID, program_id, data)?;
 
+    // Reject paused instructions. Pausable instructions take the config account last.
+    let accounts = match ix.pause_flag() {
+        0 => accounts,
+        flag => {
+            let Some((config_info, accounts)) = accounts.split_last() else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            let config = config_info
+                .has_address(&CONFIG_ADDRESS)?
+                .as_account::<Config>(&skill_api::ID)?;
+            if config.is_paused(flag) {
+                sol_log(&format!("{:?} is paused", ix));
+                return Err(OreError::Paused.into());
+            }
+            accounts
+        }
+    };
+
     match ix {
         // Miner
         OreInstruction::Automate => process_automate(accounts, data)?,
         OreInstruction::Checkpoint => process_checkpoint(accounts, data)?,
+        OreInstruction::CheckpointMany => process_checkpoint_many(accounts, data)?,
         OreInstruction::ClaimSOL => process_claim_sol(accounts, data)?,
         OreInstruction::ClaimORE => process_claim_ore(accounts, data)?,
         OreInstruction::Deploy => process_deploy(accounts, data)?,
@@ -75,6 +201,12 @@ pub fn process_instruction(
         OreInstruction::Close => process_close(accounts, data)?,
         OreInstruction::Reset => process_reset(accounts, data)?,
         OreInstruction::ReloadSOL => process_reload_sol(accounts, data)?,
+        OreInstruction::SetAutomationLimits => process_set_automation_limits(accounts, data)?,
+        OreInstruction::ClaimSeason => process_claim_season(accounts, data)?,
+        OreInstruction::OpenAchievements => process_open_achievements(accounts, data)?,
+        OreInstruction::SetClaimRecipient => process_set_claim_recipient(accounts, data)?,
+        OreInstruction::Sweep => process_sweep(accounts, data)?,
+        OreInstruction::ArmEntropy => process_arm_entropy(accounts, data)?,
 
         // Staker
         OreInstruction::Deposit => process_deposit(accounts, data)?,
@@ -94,6 +226,46 @@ pub fn process_instruction(
         OreInstruction::MigrateAutomation => process_migrate_automation(accounts, data)?,
         OreInstruction::Liq => process_liq(accounts, data)?,
         OreInstruction::Initialize => process_initialize(accounts, data)?,
+        OreInstruction::AcceptAdmin => process_accept_admin(accounts, data)?,
+        OreInstruction::SetBuryAuthority => process_set_bury_authority(accounts, data)?,
+        OreInstruction::SetFeeAuthority => process_set_fee_authority(accounts, data)?,
+        OreInstruction::SetPauseAuthority => process_set_pause_authority(accounts, data)?,
+        OreInstruction::MigrateConfig => process_migrate_config(accounts, data)?,
+        OreInstruction::SetChangeDelay => process_set_change_delay(accounts, data)?,
+        OreInstruction::ExecuteChange => process_execute_change(accounts, data)?,
+        OreInstruction::CancelChange => process_cancel_change(accounts, data)?,
+        OreInstruction::SetPause => process_set_pause(accounts, data)?,
+        OreInstruction::SetBuybackCap => process_set_buyback_cap(accounts, data)?,
+        OreInstruction::SetLiqManager => process_set_liq_manager(accounts, data)?,
+        OreInstruction::UpdateMetadataUri => process_update_metadata_uri(accounts, data)?,
+        OreInstruction::SetReferralFee => process_set_referral_fee(accounts, data)?,
+        OreInstruction::NewBoard => process_new_board(accounts, data)?,
+        OreInstruction::UpdateBoard => process_update_board(accounts, data)?,
+        OreInstruction::SetBoardWeight => process_set_board_weight(accounts, data)?,
+        OreInstruction::NewSeason => process_new_season(accounts, data)?,
+        OreInstruction::SetVestingPeriod => process_set_vesting_period(accounts, data)?,
+        OreInstruction::SetMotherlodeOdds => process_set_motherlode_odds(accounts, data)?,
+        OreInstruction::SetMotherlodeRate => process_set_motherlode_rate(accounts, data)?,
+        OreInstruction::SetRngMode => process_set_rng_mode(accounts, data)?,
+        OreInstruction::SetBonusSquareCount => process_set_bonus_square_count(accounts, data)?,
+        OreInstruction::SetBonusSquareMultiplier => {
+            process_set_bonus_square_multiplier(accounts, data)?
+        }
+
+        // Guild
+        OreInstruction::NewGuild => process_new_guild(accounts, data)?,
+        OreInstruction::UpdateGuild => process_update_guild(accounts, data)?,
+        OreInstruction::JoinGuild => process_join_guild(accounts, data)?,
+        OreInstruction::LeaveGuild => process_leave_guild(accounts, data)?,
+        OreInstruction::DepositGuild => process_deposit_guild(accounts, data)?,
+        OreInstruction::FundMember => process_fund_member(accounts, data)?,
+        OreInstruction::ClaimGuild => process_claim_guild(accounts, data)?,
+
+        // Tournament
+        OreInstruction::NewTournament => process_new_tournament(accounts, data)?,
+        OreInstruction::JoinTournament => process_join_tournament(accounts, data)?,
+        OreInstruction::ScoreTournament => process_score_tournament(accounts, data)?,
+        OreInstruction::SettleTournament => process_settle_tournament(accounts, data)?,
 
         // Skill System (v0.2)
         OreInstruction::SubmitPrediction => process_submit_prediction(accounts, data)?,
@@ -101,6 +273,7 @@ pub fn process_instruction(
         // Commit-Reveal System (v0.6)
         OreInstruction::SubmitCommit => process_submit_commit(accounts, data)?,
         OreInstruction::RevealChoice => process_reveal_choice(accounts, data)?,
+        OreInstruction::SetAutomationReveal => process_set_automation_reveal(accounts, data)?,
     }
 
     Ok(())
@@ -1,14 +1,15 @@
 use skill_api::prelude::*;
 use solana_program::log::sol_log;
-use solana_program::pubkey;
 use steel::*;
 
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
-const LIQ_MANAGER: Pubkey = pubkey!("DJqfQWB8tZE6fzqWa8okncDh7ciTuD8QQKp1ssNETWee");
-
 /// Send SOL to the liq manager.
-pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+pub fn process_liq(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = Liq::try_from_bytes(data)?;
+    let amount = u64::from_le_bytes(args.amount);
+
     // Load accounts.
     let [signer_info, board_info, config_info, manager_info, manager_sol_info, treasury_info, treasury_sol_info, token_program, ore_program] =
         accounts
@@ -17,10 +18,11 @@ pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     };
     signer_info.is_signer()?;
     board_info.as_account_mut::<Board>(&skill_api::ID)?;
-    config_info
+    let config = config_info
         .as_account::<Config>(&skill_api::ID)?
-        .assert(|c| c.bury_authority == *signer_info.key)?;
-    manager_info.has_address(&LIQ_MANAGER)?;
+        .assert(|c| c.bury_authority == *signer_info.key)?
+        .assert(|c| c.liq_manager != Pubkey::default())?;
+    manager_info.has_address(&config.liq_manager)?;
     manager_sol_info
         .is_writable()?
         .as_associated_token_account(&manager_info.key, &SOL_MINT)?;
@@ -32,36 +34,49 @@ pub fn process_liq(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     // Sync native token balance.
     sync_native(treasury_sol_info)?;
 
-    // Record pre-swap balances.
+    // Record pre-transfer balance.
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    let liq_amount = treasury_sol.amount();
-    assert!(liq_amount > 0);
+    let treasury_sol_balance = treasury_sol.amount();
+    assert!(amount > 0);
+    if amount > treasury_sol_balance {
+        return Err(ProgramError::InsufficientFunds);
+    }
 
-    // Build swap accounts.
+    // Transfer SOL to the liq manager.
     transfer_signed(
         treasury_info,
         treasury_sol_info,
         manager_sol_info,
         token_program,
-        liq_amount,
+        amount,
         &[TREASURY],
     )?;
 
-    // Record post-swap balances.
+    // Record post-transfer balance.
     let treasury_sol =
         treasury_sol_info.as_associated_token_account(treasury_info.key, &SOL_MINT)?;
-    assert_eq!(treasury_sol.amount(), 0);
-    sol_log(&format!("💦 Sent {} SOL to liq manager", liq_amount as f64 / LAMPORTS_PER_SOL as f64).as_str());
+    assert_eq!(treasury_sol.amount(), treasury_sol_balance - amount);
+    let share_bps = (amount as u128 * 10_000 / treasury_sol_balance as u128) as u64;
+    sol_log(
+        &format!(
+            "💦 Sent {} SOL ({} bps of treasury) to liq manager",
+            amount as f64 / LAMPORTS_PER_SOL as f64,
+            share_bps
+        )
+        .as_str(),
+    );
 
     // Emit event.
     program_log(
         &[board_info.clone(), ore_program.clone()],
         LiqEvent {
-            disc: 3,
-            sol_amount: liq_amount,
+            disc: 4,
+            sol_amount: amount,
             recipient: *manager_info.key,
             ts: Clock::get()?.unix_timestamp,
+            treasury_sol_balance,
+            share_bps,
         }
         .to_bytes(),
     )?;
@@ -2,7 +2,7 @@ use skill_api::prelude::*;
 use solana_program::rent::Rent;
 use steel::*;
 
-/// Sets the admin.
+/// Resizes an automation account to the current layout, closing it if it is out of funds.
 pub fn process_migrate_automation(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let [signer_info, authority_info, config_info, automation_info, system_program] = accounts
@@ -19,9 +19,10 @@ pub fn process_migrate_automation(accounts: &[AccountInfo<'_>], _data: &[u8]) ->
     if automation_info.data_is_empty() {
         return Ok(());
     }
-    automation_info
-        .as_account_mut::<Automation>(&skill_api::ID)?
-        .assert_mut(|a| a.authority == *authority_info.key)?;
+    automation_info.is_writable()?.has_seeds(
+        &[AUTOMATION, &authority_info.key.to_bytes()],
+        &skill_api::ID,
+    )?;
     system_program.is_program(&system_program::ID)?;
 
     // Close automation if its out of funds.
@@ -32,5 +33,17 @@ pub fn process_migrate_automation(accounts: &[AccountInfo<'_>], _data: &[u8]) ->
         return Ok(());
     }
 
+    // Resize automation to the current layout. The signer covers the additional rent.
+    let old_size = automation_info.data_len();
+    if old_size < new_size {
+        let old_rent = Rent::get()?.minimum_balance(old_size);
+        automation_info.collect(new_rent - old_rent, signer_info)?;
+        automation_info.resize(new_size)?;
+        automation_info.try_borrow_mut_data()?[old_size..].fill(0);
+    }
+    automation_info
+        .as_account_mut::<Automation>(&skill_api::ID)?
+        .assert_mut(|a| a.authority == *authority_info.key)?;
+
     Ok(())
 }
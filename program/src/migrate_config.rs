@@ -0,0 +1,89 @@
+use std::mem::offset_of;
+
+use skill_api::prelude::*;
+use solana_program::rent::Rent;
+use steel::*;
+
+/// Resizes the config account to the current layout.
+pub fn process_migrate_config(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, config_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .is_writable()?
+        .has_seeds(&[CONFIG], &skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Nothing to do if the config is already the current size.
+    let old_size = config_info.data_len();
+    let new_size = 8 + std::mem::size_of::<Config>();
+    if old_size >= new_size {
+        return Ok(());
+    }
+
+    // Only the admin (first field after the discriminator) may migrate.
+    let admin = Pubkey::try_from(&config_info.try_borrow_data()?[8..40]).unwrap();
+    if admin != *signer_info.key {
+        return Err(OreError::NotAuthorized.into());
+    }
+
+    // Resize config. The signer covers the additional rent.
+    let rent = Rent::get()?;
+    let diff = rent.minimum_balance(new_size) - rent.minimum_balance(old_size);
+    config_info.collect(diff, signer_info)?;
+    config_info.resize(new_size)?;
+    config_info.try_borrow_mut_data()?[old_size..].fill(0);
+
+    // Initialize the fields added since the account was created.
+    let config = config_info.as_account_mut::<Config>(&skill_api::ID)?;
+    if old_size <= 8 + offset_of!(Config, pending_admin) {
+        config.pending_admin = Pubkey::default();
+        config.fee_authority = admin;
+        config.pause_authority = admin;
+    }
+    if old_size <= 8 + offset_of!(Config, change_delay) {
+        config.change_delay = DEFAULT_CHANGE_DELAY_SLOTS;
+        config.change_count = 0;
+    }
+    if old_size <= 8 + offset_of!(Config, buyback_cap) {
+        config.buyback_cap = DEFAULT_BUYBACK_CAP;
+    }
+    if old_size <= 8 + offset_of!(Config, liq_manager) {
+        config.liq_manager = Pubkey::default();
+    }
+    if old_size <= 8 + offset_of!(Config, referral_fee) {
+        config.referral_fee = DEFAULT_REFERRAL_FEE;
+    }
+    if old_size <= 8 + offset_of!(Config, board_count) {
+        // Only the main board exists. Its weight is set when the board itself is migrated.
+        config.board_count = 1;
+        config.total_board_weight = DEFAULT_BOARD_WEIGHT;
+    }
+    if old_size <= 8 + offset_of!(Config, season_count) {
+        config.season_count = 0;
+    }
+    if old_size <= 8 + offset_of!(Config, guild_count) {
+        config.guild_count = 0;
+    }
+    if old_size <= 8 + offset_of!(Config, tournament_count) {
+        config.tournament_count = 0;
+    }
+    if old_size <= 8 + offset_of!(Config, vesting_period) {
+        config.vesting_period = DEFAULT_VESTING_PERIOD;
+    }
+    if old_size <= 8 + offset_of!(Config, motherlode_odds) {
+        config.motherlode_odds = DEFAULT_MOTHERLODE_ODDS;
+        config.motherlode_rate = DEFAULT_MOTHERLODE_RATE;
+    }
+    if old_size <= 8 + offset_of!(Config, rng_mode) {
+        config.rng_mode = RNG_MODE_SLOT_HASHES;
+    }
+    if old_size <= 8 + offset_of!(Config, bonus_square_count) {
+        config.bonus_square_count = DEFAULT_BONUS_SQUARE_COUNT;
+        config.bonus_square_multiplier = DEFAULT_BONUS_SQUARE_MULTIPLIER;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use skill_api::prelude::*;
+use solana_program::rent::Rent;
+use steel::*;
+
+/// Resizes a miner account created before the account was extended. The payer covers the
+/// additional rent, the square arrays are widened and the new fields are zeroed.
+pub fn migrate_miner<'info>(
+    miner_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+) -> ProgramResult {
+    let expected_size = 8 + std::mem::size_of::<Miner>();
+    if miner_info.data_is_empty() || miner_info.data_len() >= expected_size {
+        return Ok(());
+    }
+    miner_info.is_writable()?.has_owner(&skill_api::ID)?;
+    if miner_info.try_borrow_data()?[0] != Miner::discriminator() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let old_size = miner_info.data_len();
+    let rent = Rent::get()?;
+    let diff = rent.minimum_balance(expected_size) - rent.minimum_balance(old_size);
+    miner_info.collect(diff, payer_info)?;
+    miner_info.resize(expected_size)?;
+    Miner::migrate_legacy(&mut miner_info.try_borrow_mut_data()?, old_size);
+    Ok(())
+}
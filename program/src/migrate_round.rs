@@ -0,0 +1,37 @@
+use skill_api::prelude::*;
+use solana_program::rent::Rent;
+use steel::*;
+
+/// Resizes a round account created before the account was extended. The payer covers
+/// the additional rent, so SOL deployed in the round is left untouched.
+pub fn migrate_round<'info>(
+    round_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+) -> ProgramResult {
+    let expected_size = 8 + std::mem::size_of::<Round>();
+    if round_info.data_is_empty() || round_info.data_len() >= expected_size {
+        return Ok(());
+    }
+    round_info.is_writable()?.has_owner(&skill_api::ID)?;
+    if round_info.try_borrow_data()?[0] != Round::discriminator() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let old_size = round_info.data_len();
+    let rent = Rent::get()?;
+    let diff = rent.minimum_balance(expected_size) - rent.minimum_balance(old_size);
+    round_info.collect(diff, payer_info)?;
+    round_info.resize(expected_size)?;
+    Round::migrate_legacy(&mut round_info.try_borrow_mut_data()?, old_size);
+    Ok(())
+}
+
+/// Reads a round account that may be in the legacy layout, without migrating it. For
+/// instructions that only look at past rounds.
+pub fn read_round(round_info: &AccountInfo<'_>) -> Result<Round, ProgramError> {
+    round_info.has_owner(&skill_api::ID)?;
+    let data = round_info.try_borrow_data()?;
+    if data.first() != Some(&Round::discriminator()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(Round::read_any_layout(&data))
+}
@@ -0,0 +1,62 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Creates a board with its own round sequence and parameters.
+pub fn process_new_board(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = NewBoard::try_from_bytes(data)?;
+    let min_deploy = u64::from_le_bytes(args.min_deploy);
+    let deploy_slots = u64::from_le_bytes(args.deploy_slots);
+    let commit_slots = u64::from_le_bytes(args.commit_slots);
+    let reveal_slots = u64::from_le_bytes(args.reveal_slots);
+    let admin_fee = u64::from_le_bytes(args.admin_fee);
+    let weight = u64::from_le_bytes(args.weight);
+    let squares = u64::from_le_bytes(args.squares);
+
+    // Load accounts.
+    let [signer_info, config_info, board_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    board_info.is_empty()?.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    if deploy_slots == 0 || !Board::is_valid_geometry(squares) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create board.
+    let id = config.board_count;
+    create_program_account::<Board>(
+        board_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &board_seeds(&id.to_le_bytes()),
+    )?;
+    let board = board_info.as_account_mut::<Board>(&skill_api::ID)?;
+    board.round_id = 0;
+    board.start_slot = u64::MAX;
+    board.end_slot = u64::MAX;
+    board.id = id;
+    board.min_deploy = min_deploy;
+    board.deploy_slots = deploy_slots;
+    board.commit_slots = commit_slots;
+    board.reveal_slots = reveal_slots;
+    board.admin_fee = admin_fee.min(MAX_BOARD_ADMIN_FEE);
+    board.weight = weight;
+    board.squares = squares;
+
+    // Update config.
+    config.board_count += 1;
+    config.total_board_weight += weight;
+    sol_log(&format!("Board #{} created", id));
+
+    Ok(())
+}
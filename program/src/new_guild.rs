@@ -0,0 +1,56 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Creates a guild managed by the signer.
+pub fn process_new_guild(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = NewGuild::try_from_bytes(data)?;
+    let executor = Pubkey::new_from_array(args.executor);
+    let revenue_share = u64::from_le_bytes(args.revenue_share);
+
+    // Load accounts.
+    let [signer_info, config_info, guild_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&skill_api::ID)?;
+    guild_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[GUILD, &config.guild_count.to_le_bytes()], &skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+    if revenue_share > MAX_GUILD_REVENUE_SHARE || std::str::from_utf8(&args.name).is_err() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create guild.
+    let id = config.guild_count;
+    create_program_account::<Guild>(
+        guild_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &[GUILD, &id.to_le_bytes()],
+    )?;
+    let guild = guild_info.as_account_mut::<Guild>(&skill_api::ID)?;
+    guild.id = id;
+    guild.authority = *signer_info.key;
+    guild.executor = executor;
+    guild.name = args.name;
+    guild.revenue_share = revenue_share;
+    guild.member_count = 0;
+    guild.balance = 0;
+    guild.rewards_ore = 0;
+    guild.lifetime_rewards_ore = 0;
+    guild.wins = 0;
+    guild.rounds = 0;
+    guild.volume = 0;
+    guild.skill_points = 0;
+
+    // Update config.
+    config.guild_count += 1;
+    sol_log(&format!("Guild #{} created: {}", id, guild.name()));
+
+    Ok(())
+}
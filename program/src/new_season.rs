@@ -0,0 +1,83 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Creates a season and funds its prize pool from the treasury's SOL. Seasons of a board start
+/// after its current round and may not overlap, so each round is stamped with its season when it
+/// is opened.
+pub fn process_new_season(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = NewSeason::try_from_bytes(data)?;
+    let board_id = u64::from_le_bytes(args.board_id);
+    let start_round = u64::from_le_bytes(args.start_round);
+    let end_round = u64::from_le_bytes(args.end_round);
+    let prize = u64::from_le_bytes(args.prize);
+
+    // Load accounts.
+    let [signer_info, config_info, board_info, season_info, treasury_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let board = board_info
+        .has_address(&board_pda(board_id).0)?
+        .as_account_mut::<Board>(&skill_api::ID)?;
+    season_info.is_empty()?.is_writable()?.has_seeds(
+        &[SEASON, &config.season_count.to_le_bytes()],
+        &skill_api::ID,
+    )?;
+    let treasury = treasury_info
+        .as_account_mut::<Treasury>(&skill_api::ID)?
+        .assert_mut(|t| t.balance >= prize)?;
+    system_program.is_program(&system_program::ID)?;
+    if board_id >= config.board_count || end_round < start_round {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if start_round <= board.round_id || (board.season > 0 && start_round <= board.season_end_round)
+    {
+        sol_log("Seasons must start after the current round and the board's latest season");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create season.
+    let id = config.season_count;
+    create_program_account::<Season>(
+        season_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &[SEASON, &id.to_le_bytes()],
+    )?;
+    let season = season_info.as_account_mut::<Season>(&skill_api::ID)?;
+    season.id = id;
+    season.board_id = board_id;
+    season.start_round = start_round;
+    season.end_round = end_round;
+    season.prize = prize;
+    season.claimed = 0;
+    season.leaders = [Pubkey::default(); SEASON_WINNERS];
+    season.leader_points = [0; SEASON_WINNERS];
+
+    // Fund the prize pool.
+    treasury.balance -= prize;
+    treasury_info.send(prize, season_info);
+
+    // Update board and config.
+    board.season = id + 1;
+    board.season_start_round = start_round;
+    board.season_end_round = end_round;
+    config.season_count += 1;
+    sol_log(&format!(
+        "Season #{} created for rounds {}-{} of board #{}",
+        id, start_round, end_round, board_id
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Creates a round-robin tournament on upcoming rounds of a board. The creator may sponsor the
+/// prize pool, which grows with each player's entry fee.
+pub fn process_new_tournament(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = NewTournament::try_from_bytes(data)?;
+    let board_id = u64::from_le_bytes(args.board_id);
+    let start_round = u64::from_le_bytes(args.start_round);
+    let max_players = u64::from_le_bytes(args.max_players);
+    let entry_fee = u64::from_le_bytes(args.entry_fee);
+    let prize = u64::from_le_bytes(args.prize);
+
+    // Load accounts.
+    let [signer_info, config_info, board_info, tournament_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info.as_account_mut::<Config>(&skill_api::ID)?;
+    board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == board_id)?
+        .assert(|b| b.round_id < start_round)?;
+    tournament_info.is_empty()?.is_writable()?.has_seeds(
+        &[TOURNAMENT, &config.tournament_count.to_le_bytes()],
+        &skill_api::ID,
+    )?;
+    system_program.is_program(&system_program::ID)?;
+    if !Tournament::is_valid_roster(max_players) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create tournament.
+    let id = config.tournament_count;
+    create_program_account::<Tournament>(
+        tournament_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &[TOURNAMENT, &id.to_le_bytes()],
+    )?;
+    let tournament = tournament_info.as_account_mut::<Tournament>(&skill_api::ID)?;
+    tournament.id = id;
+    tournament.board_id = board_id;
+    tournament.authority = *signer_info.key;
+    tournament.start_round = start_round;
+    tournament.entry_fee = entry_fee;
+    tournament.prize = prize;
+    tournament.max_players = max_players;
+    tournament.player_count = 0;
+    tournament.scored = 0;
+    tournament.settled = 0;
+    tournament.players = [Pubkey::default(); MAX_TOURNAMENT_PLAYERS];
+    tournament.points = [0; MAX_TOURNAMENT_PLAYERS];
+    tournament.hits = [0; MAX_TOURNAMENT_PLAYERS];
+    tournament.picks = [[NO_PICK; MAX_TOURNAMENT_PLAYERS]; MAX_TOURNAMENT_PLAYERS];
+
+    // Escrow the sponsored prize.
+    tournament_info.collect(prize, signer_info)?;
+
+    // Update config.
+    config.tournament_count += 1;
+    sol_log(&format!(
+        "Tournament #{} created for {} players from round {} of board #{}",
+        id, max_players, start_round, board_id
+    ));
+
+    Ok(())
+}
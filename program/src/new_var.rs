@@ -1,7 +1,8 @@
 use skill_api::prelude::*;
 use steel::*;
 
-/// Creates a new var account.
+/// Creates a new var account with the board as its authority. The var with id 0 is the one the
+/// board draws its round randomness from in entropy RNG mode.
 pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = NewVar::try_from_bytes(data)?;
@@ -45,7 +46,7 @@ pub fn process_new_var(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResu
             system_program.clone(),
         ],
         &entropy_api::ID,
-        &[BOARD],
+        &board_seeds(&board.id.to_le_bytes()),
     )?;
 
     Ok(())
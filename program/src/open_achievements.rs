@@ -0,0 +1,33 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Opens an achievements account for the signer. Checkpoints record achievements for miners
+/// who have one.
+pub fn process_open_achievements(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, achievements_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    achievements_info
+        .is_empty()?
+        .is_writable()?
+        .has_seeds(&[ACHIEVEMENTS, &signer_info.key.to_bytes()], &skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Create achievements.
+    create_program_account::<Achievements>(
+        achievements_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &[ACHIEVEMENTS, &signer_info.key.to_bytes()],
+    )?;
+    let achievements = achievements_info.as_account_mut::<Achievements>(&skill_api::ID)?;
+    achievements.authority = *signer_info.key;
+    achievements.unlocked = 0;
+    achievements.correct_reveals = 0;
+    achievements.unlocked_round = [0; 16];
+
+    Ok(())
+}
@@ -1,20 +1,25 @@
 use skill_api::prelude::*;
-use solana_program::log::sol_log;
 use steel::*;
 
+use crate::close_automation;
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
 /// Claims a block reward.
 pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, automation_info, miner_info, system_program] = accounts else {
+    let [signer_info, authority_info, automation_info, miner_info, system_program, optional_accounts @ ..] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
+    authority_info.is_writable()?;
     let automation = automation_info
         .as_account_mut::<Automation>(&skill_api::ID)?
         .assert_mut(|a| a.executor == *signer_info.key)?
+        .assert_mut(|a| a.authority == *authority_info.key)?
         .assert_mut(|a| a.reload > 0)?;
     let miner = miner_info
         .as_account_mut::<Miner>(&skill_api::ID)?
@@ -26,12 +31,25 @@ pub fn process_reload_sol(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Program
 
     // Increment automation balance.
     automation.balance += amount;
+    automation.sync_rewards(miner.lifetime_rewards_sol);
 
     // Transfer SOL to automation.
     miner_info.send(amount, automation_info);
 
     // Log
-    sol_log(&format!("Reloading {} SOL", amount as f64 / LAMPORTS_PER_SOL as f64).as_str());
+    debug_log!("Reloading {} SOL", amount as f64 / LAMPORTS_PER_SOL as f64);
+
+    // Close automation if a limit has been reached.
+    if let Some(reason) = automation.tripped_limit(clock.slot) {
+        let guild_info = optional_accounts.first();
+        close_automation(
+            automation_info,
+            automation,
+            authority_info,
+            guild_info,
+            reason,
+        )?;
+    }
 
     Ok(())
 }
@@ -1,13 +1,17 @@
+use entropy_api::state::Var;
 use skill_api::prelude::*;
 use solana_program::log::sol_log;
+use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::{migrate_round, token::*};
+
 /// Pays out the winners and block reward.
 /// Schelling Point: Winner = square with most SOL deployed (majority coordination).
 pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
     let clock = Clock::get()?;
-    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, _top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar] =
+    let [signer_info, board_info, config_info, fee_collector_info, mint_info, round_info, round_next_info, _top_miner_info, treasury_info, treasury_tokens_info, system_program, token_program, ore_program, slot_hashes_sysvar, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -20,18 +24,21 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     fee_collector_info
         .is_writable()?
         .has_address(&config.fee_collector)?;
+    let board_id = board.id.to_le_bytes();
+    let next_round_id = (board.round_id + 1).to_le_bytes();
+    round_info.has_address(&round_pda(board.id, board.round_id).0)?;
+    migrate_round(round_info, signer_info)?;
     let round = round_info
         .as_account_mut::<Round>(&skill_api::ID)?
         .assert_mut(|r| r.id == board.round_id)?;
     round_next_info
         .is_empty()?
         .is_writable()?
-        .has_seeds(&[ROUND, &(board.round_id + 1).to_le_bytes()], &skill_api::ID)?;
-    let mint = mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
+        .has_seeds(&round_seeds(&board_id, &next_round_id), &skill_api::ID)?;
+    let mint = load_skill_mint(mint_info, token_program)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
-    treasury_tokens_info.as_associated_token_account(&treasury_info.key, &mint_info.key)?;
+    load_skill_token_account(treasury_tokens_info, treasury_info.key, token_program)?;
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
     ore_program.is_program(&skill_api::ID)?;
     // Keep slot_hashes for backwards compatibility (SDK still passes it)
     slot_hashes_sysvar.is_sysvar(&sysvar::slot_hashes::ID)?;
@@ -42,13 +49,13 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         ore_program,
         signer_info,
         &skill_api::ID,
-        &[ROUND, &(board.round_id + 1).to_le_bytes()],
+        &round_seeds(&board_id, &next_round_id),
     )?;
     let round_next = round_next_info.as_account_mut::<Round>(&skill_api::ID)?;
     round_next.id = board.round_id + 1;
-    round_next.deployed = [0; 25];
+    round_next.deployed = [0; MAX_SQUARES];
     round_next.slot_hash = [0; 32];
-    round_next.count = [0; 25];
+    round_next.count = [0; MAX_SQUARES];
     round_next.expires_at = u64::MAX; // Set to max, to indicate round is waiting for first deploy to begin.
     round_next.rent_payer = *signer_info.key;
     round_next.motherlode = 0;
@@ -58,14 +65,12 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     round_next.total_vaulted = 0;
     round_next.total_winnings = 0;
     round_next.winning_square = 0;
-    // Generate bonus squares from current round's slot_hash (will be set below)
-    round_next.bonus_squares = Round::generate_bonus_squares(&round.slot_hash);
-    round_next._padding = [0; 4];
     // v0.6 commit-reveal fields - initialized when deploy starts
     round_next.commit_start_slot = 0;
     round_next.reveal_start_slot = 0;
-    round_next.revealed_count = [0; 25];
+    round_next.revealed_count = [0; MAX_SQUARES];
     round_next.total_reveals = 0;
+    round_next.season = board.round_season(board.round_id + 1);
 
     // ============ SCHELLING POINT: Commit-Reveal Coordination ============
     // v0.6: Winner = argmax(revealed_count) if reveals exist, else argmax(deployed)
@@ -74,38 +79,91 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     let max_deployed = round.deployed[winning_square];
 
     if round.total_reveals > 0 {
-        sol_log(&format!(
+        debug_log!(
             "Commit-Reveal: Square #{} wins with {} reveals ({} lamports)",
-            winning_square, round.revealed_count[winning_square], max_deployed
-        ));
+            winning_square,
+            round.revealed_count[winning_square],
+            max_deployed
+        );
     } else {
-        sol_log(&format!(
+        debug_log!(
             "Schelling Point: Square #{} wins with {} lamports",
-            winning_square, max_deployed
-        ));
+            winning_square,
+            max_deployed
+        );
     }
 
     // Store winning square directly (fixes square 0 bug)
     round.winning_square = winning_square as u8;
 
-    // Sample slot_hash from SlotHashes sysvar for unpredictable RNG (split, motherlode, top_miner)
-    // SlotHashes layout: first 8 bytes = length, then (slot: u64, hash: [u8;32]) entries
-    // We extract raw bytes from the sysvar and hash them with other unpredictable data
-    let slot_hashes_data = slot_hashes_sysvar.data.borrow();
-    round.slot_hash = solana_program::keccak::hashv(&[
-        &slot_hashes_data[..slot_hashes_data.len().min(256)], // First 256 bytes of slot hashes
-        &board.end_slot.to_le_bytes(),
-        &round.total_deployed.to_le_bytes(),
-        &clock.slot.to_le_bytes(),
-        &round.deployed[winning_square].to_le_bytes(),
-    ])
-    .to_bytes();
+    // In entropy mode, take the slot_hash from the board's entropy var, which neither the leader
+    // of the current slot nor the caller can bias. The var is required, and the reset waits for
+    // it to be revealed so the caller cannot choose between it and SlotHashes. Only once the
+    // timeout has passed does the round fall back to SlotHashes.
+    let entropy_slot_hash = if config.uses_entropy() {
+        let [var_info, ..] = optional_accounts else {
+            sol_log("Entropy var is required");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        let var = var_info
+            .has_address(&board_var_pda(*board_info.key).0)?
+            .as_account::<Var>(&entropy_api::ID)?;
+        match Round::entropy_slot_hash(var, board_info.key, board.end_slot) {
+            Some(slot_hash) => Some(slot_hash),
+            None if clock.slot < board.end_slot + ENTROPY_TIMEOUT_SLOTS => {
+                sol_log("Entropy var has not been revealed");
+                return Err(OreError::EntropyNotReady.into());
+            }
+            None => {
+                board_log(
+                    board.id,
+                    &[board_info.clone(), ore_program.clone()],
+                    EntropyFallbackEvent {
+                        disc: 8,
+                        round_id: round.id,
+                        board_id: board.id,
+                        end_slot: board.end_slot,
+                        var: *var_info.key,
+                        ts: clock.unix_timestamp,
+                    }
+                    .to_bytes(),
+                )?;
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    if let Some(slot_hash) = entropy_slot_hash {
+        round.slot_hash = slot_hash;
+    } else {
+        // Sample slot_hash from SlotHashes sysvar for unpredictable RNG (split, motherlode, top_miner)
+        // SlotHashes layout: first 8 bytes = length, then (slot: u64, hash: [u8;32]) entries
+        // We extract raw bytes from the sysvar and hash them with other unpredictable data
+        let slot_hashes_data = slot_hashes_sysvar.data.borrow();
+        round.slot_hash = solana_program::keccak::hashv(&[
+            &slot_hashes_data[..slot_hashes_data.len().min(256)], // First 256 bytes of slot hashes
+            &board.end_slot.to_le_bytes(),
+            &round.total_deployed.to_le_bytes(),
+            &clock.slot.to_le_bytes(),
+            &round.deployed[winning_square].to_le_bytes(),
+        ])
+        .to_bytes();
+    }
+
+    // Generate the next round's bonus squares from this round's slot_hash.
+    round_next.bonus_squares = Round::generate_bonus_squares(
+        &round.slot_hash,
+        board.squares as u8,
+        config.bonus_square_count,
+    );
 
     // Get RNG from slot_hash for split/motherlode decisions
     let r = round.rng().unwrap_or(0);
 
     // Calculate admin fees.
-    let total_admin_fee = round.total_deployed / 100;
+    let total_admin_fee = board.admin_fee(round.total_deployed);
 
     // If no one deployed on the winning square, vault all deployed.
     if round.deployed[winning_square] == 0 {
@@ -114,10 +172,11 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         treasury.balance += round.total_deployed - total_admin_fee;
 
         // Emit event.
-        program_log(
+        board_log(
+            board.id,
             &[board_info.clone(), ore_program.clone()],
             ResetEvent {
-                disc: 0,
+                disc: 5,
                 round_id: round.id,
                 start_slot: board.start_slot,
                 end_slot: board.end_slot,
@@ -130,6 +189,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 total_winnings: round.total_winnings,
                 total_minted: 0,
                 ts: clock.unix_timestamp,
+                board_id: board.id,
             }
             .to_bytes(),
         )?;
@@ -147,7 +207,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
 
     // Get winnings amount (total deployed on all non-winning squares, minus admin fee).
     let winnings = round.calculate_total_winnings(winning_square);
-    let winnings_admin_fee = winnings / 100; // 1% admin fee.
+    let winnings_admin_fee = board.admin_fee(winnings);
     let winnings = winnings - winnings_admin_fee;
 
     // Subtract vault amount from winnings.
@@ -166,16 +226,17 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
                 + winnings_admin_fee
     );
 
-    // Mint +1 ORE for the winning miner(s).
-    let mint_amount = MAX_SUPPLY.saturating_sub(mint.supply()).min(ONE_ORE);
+    // Mint this board's share of the +1 ORE reward for the winning miner(s).
+    let mint_amount = MAX_SUPPLY
+        .saturating_sub(mint.supply)
+        .min(board.emission(ONE_ORE, config.total_board_weight));
     round.top_miner_reward = mint_amount;
-    mint_to_signed(
+    mint_skill_signed(
         mint_info,
         treasury_tokens_info,
         treasury_info,
         token_program,
         mint_amount,
-        &[TREASURY],
     )?;
 
     // With 1 in 2 odds, split the +1 ORE reward.
@@ -183,25 +244,55 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
         round.top_miner = SPLIT_ADDRESS;
     }
 
-    // Payout the motherlode if it was activated.
-    if round.did_hit_motherlode(r) {
-        round.motherlode = treasury.motherlode;
-        treasury.motherlode = 0;
+    // Payout the board's motherlode if it was activated. The main board also pays out the pool
+    // shared by all boards before they had their own.
+    if round.did_hit_motherlode(r, config.motherlode_odds) {
+        round.motherlode = board.motherlode;
+        board.motherlode = 0;
+        if board.id == 0 {
+            round.motherlode += treasury.motherlode;
+            treasury.motherlode = 0;
+        }
+        let winners_deployed = round.deployed[winning_square];
+        debug_log!(
+            "Motherlode: {} ORE shared by {} miners",
+            amount_to_ui_amount(round.motherlode, TOKEN_DECIMALS),
+            round.count[winning_square]
+        );
+        board_log(
+            board.id,
+            &[board_info.clone(), ore_program.clone()],
+            MotherlodeEvent {
+                disc: 7,
+                round_id: round.id,
+                board_id: board.id,
+                amount: round.motherlode,
+                winning_square: winning_square as u64,
+                num_winners: round.count[winning_square],
+                winners_deployed,
+                ore_per_sol: (round.motherlode as u128 * 1_000_000_000 / winners_deployed as u128)
+                    as u64,
+                odds: config.motherlode_odds,
+                ts: clock.unix_timestamp,
+            }
+            .to_bytes(),
+        )?;
     }
 
-    // Mint +0.2 ORE to the motherlode rewards pool.
-    let mint = mint_info.as_mint()?;
-    let motherlode_mint_amount = MAX_SUPPLY.saturating_sub(mint.supply()).min(ONE_ORE / 5);
+    // Mint this board's share of the motherlode rate to its motherlode rewards pool.
+    let mint = load_skill_mint(mint_info, token_program)?;
+    let motherlode_mint_amount = MAX_SUPPLY
+        .saturating_sub(mint.supply)
+        .min(board.emission(config.motherlode_rate, config.total_board_weight));
     if motherlode_mint_amount > 0 {
-        mint_to_signed(
+        mint_skill_signed(
             mint_info,
             treasury_tokens_info,
             treasury_info,
             token_program,
             motherlode_mint_amount,
-            &[TREASURY],
         )?;
-        treasury.motherlode += motherlode_mint_amount;
+        board.motherlode += motherlode_mint_amount;
     }
 
     // Validate top miner.
@@ -217,10 +308,11 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
     //     })?;
 
     // Emit event.
-    program_log(
+    board_log(
+        board.id,
         &[board_info.clone(), ore_program.clone()],
         ResetEvent {
-            disc: 0,
+            disc: 5,
             round_id: round.id,
             start_slot: board.start_slot,
             end_slot: board.end_slot,
@@ -233,6 +325,7 @@ pub fn process_reset(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResul
             total_winnings: round.total_winnings,
             total_minted: mint_amount + motherlode_mint_amount,
             ts: clock.unix_timestamp,
+            board_id: board.id,
         }
         .to_bytes(),
     )?;
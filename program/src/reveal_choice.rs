@@ -1,25 +1,23 @@
 use skill_api::prelude::*;
-use solana_program::log::sol_log;
+use solana_program::log::{sol_log, sol_log_data};
 use steel::*;
 
 /// Allows a miner to reveal their committed choice.
 /// Must be called during the reveal phase (after commit phase, before round ends).
 /// Verifies: keccak256(square || salt || authority) == commitment
+/// The automation executor may also reveal on the authority's behalf.
+/// If a tournament is provided, the reveal is recorded as the authority's pick for its matchday.
 pub fn process_reveal_choice(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse instruction data
     let args = RevealChoice::try_from_bytes(data)?;
     let square = args.square;
     let salt: [u8; 16] = args.salt;
 
-    // Validate square is in valid range (0-24)
-    if square > 24 {
-        sol_log("Invalid reveal: square must be 0-24");
-        return Err(ProgramError::InvalidArgument);
-    }
-
     // Load accounts
     let clock = Clock::get()?;
-    let [signer_info, miner_info, board_info, round_info] = accounts else {
+    let [signer_info, authority_info, automation_info, miner_info, board_info, round_info, tournament_info @ ..] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -29,25 +27,49 @@ pub fn process_reveal_choice(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     // Validate miner account
     miner_info
         .is_writable()?
-        .has_seeds(&[MINER, signer_info.key.as_ref()], &skill_api::ID)?;
-
-    // Validate board account
-    board_info.has_seeds(&[BOARD], &skill_api::ID)?;
+        .has_seeds(&[MINER, authority_info.key.as_ref()], &skill_api::ID)?;
 
     // Parse accounts
     let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
     let board = board_info.as_account::<Board>(&skill_api::ID)?;
 
+    // Validate square is on the board
+    if square as u64 >= board.squares {
+        sol_log("Invalid reveal: square is not on the board");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // If the executor is revealing, ensure it follows the automation's reveal rule.
+    if signer_info.key != authority_info.key {
+        let automation = automation_info
+            .as_account::<Automation>(&skill_api::ID)?
+            .assert_err(
+                |a| a.executor == *signer_info.key && a.authority == *authority_info.key,
+                OreError::NotAuthorized.into(),
+            )?;
+        match AutomationReveal::from_u64(automation.reveal_mode) {
+            AutomationReveal::None => {
+                sol_log("Automation does not reveal on behalf of the authority");
+                return Err(OreError::NotAuthorized.into());
+            }
+            AutomationReveal::Committed => {}
+            AutomationReveal::Deployed => {
+                if square != miner.max_deployed_square() {
+                    sol_log("Reveal does not match the automation's reveal rule");
+                    return Err(ProgramError::InvalidArgument);
+                }
+            }
+        }
+    }
+
     // Get current round
     let current_round_id = board.round_id;
 
     // Validate round account
-    round_info
-        .is_writable()?
-        .has_seeds(
-            &[ROUND, current_round_id.to_le_bytes().as_ref()],
-            &skill_api::ID,
-        )?;
+    round_info.is_writable()?.has_seeds(
+        &round_seeds(&board.id.to_le_bytes(), &current_round_id.to_le_bytes()),
+        &skill_api::ID,
+    )?;
     let round = round_info.as_account_mut::<Round>(&skill_api::ID)?;
 
     // Get current slot from clock
@@ -63,7 +85,7 @@ pub fn process_reveal_choice(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     }
 
     // Check if miner has a commitment for this round
-    if !miner.has_commitment_for_round(current_round_id) {
+    if miner.board_id != board.id || !miner.has_commitment_for_round(current_round_id) {
         sol_log("No commitment found for this round");
         return Err(ProgramError::InvalidAccountData);
     }
@@ -87,10 +109,31 @@ pub fn process_reveal_choice(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     round.revealed_count[square as usize] += 1;
     round.total_reveals += 1;
 
-    sol_log(&format!(
+    debug_log!(
         "Choice revealed: square {} for round {} (total reveals: {})",
-        square, current_round_id, round.total_reveals
-    ));
+        square,
+        current_round_id,
+        round.total_reveals
+    );
+    sol_log_data(&[RevealEvent {
+        disc: 11,
+        authority: *authority_info.key,
+        round_id: current_round_id,
+        board_id: board.id,
+        square: square as u64,
+        ts: clock.unix_timestamp,
+    }
+    .to_bytes()]);
+
+    // Record the pick for a tournament the authority is playing in.
+    if let Some(tournament_info) = tournament_info.first() {
+        let tournament = tournament_info
+            .is_writable()?
+            .as_account_mut::<Tournament>(&skill_api::ID)?;
+        if tournament.record_pick(authority_info.key, board.id, current_round_id, square) {
+            debug_log!("Tournament #{} pick recorded", tournament.id);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,38 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Opens a change account that applies `value` to the config once the change delay has passed.
+pub fn schedule_change<'a, 'info>(
+    config: &mut Config,
+    signer_info: &'a AccountInfo<'info>,
+    change_info: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    kind: ChangeKind,
+    value: [u8; 32],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let id = config.change_count;
+    create_program_account::<Change>(
+        change_info,
+        system_program,
+        signer_info,
+        &skill_api::ID,
+        &[CHANGE, &id.to_le_bytes()],
+    )?;
+    let change = change_info.as_account_mut::<Change>(&skill_api::ID)?;
+    change.id = id;
+    change.kind = kind as u64;
+    change.value = value;
+    change.proposer = *signer_info.key;
+    change.created_at = clock.slot;
+    change.effective_slot = clock.slot.saturating_add(config.change_delay);
+    config.change_count += 1;
+
+    sol_log(&format!(
+        "Change #{}: {:?} scheduled for slot {}",
+        id, kind, change.effective_slot
+    ));
+
+    Ok(())
+}
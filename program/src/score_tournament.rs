@@ -0,0 +1,44 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+use crate::read_round;
+
+/// Scores a tournament's next matchday from the outcome of its board round. Anyone may score a
+/// matchday once the round has been reset. If the round account was already closed, the matchday
+/// is scored as having no winner.
+pub fn process_score_tournament(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, board_info, tournament_info, round_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let tournament = tournament_info
+        .as_account_mut::<Tournament>(&skill_api::ID)?
+        .assert_mut(|t| t.is_full())?
+        .assert_mut(|t| !t.is_complete())?;
+    let matchday = tournament.scored.count_ones() as usize;
+    let round_id = tournament.start_round + matchday as u64;
+    board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == tournament.board_id)?
+        .assert(|b| b.round_id > round_id)?;
+    round_info.has_address(&round_pda(tournament.board_id, round_id).0)?;
+
+    // Get the round's winning square.
+    let winning_square = if round_info.data_is_empty() {
+        None
+    } else {
+        let round = read_round(round_info)?;
+        round.rng().map(|_| round.get_winning_square() as u8)
+    };
+
+    // Score matchday.
+    tournament.score(matchday, winning_square);
+    sol_log(&format!(
+        "Tournament #{} matchday {} scored (round {}, winning square {:?})",
+        tournament.id, matchday, round_id, winning_square
+    ));
+
+    Ok(())
+}
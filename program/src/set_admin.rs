@@ -1,7 +1,7 @@
 use skill_api::prelude::*;
 use steel::*;
 
-/// Sets the admin.
+/// Proposes a new admin. The transfer takes effect once the new admin accepts it.
 pub fn process_set_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetAdmin::try_from_bytes(data)?;
@@ -20,8 +20,8 @@ pub fn process_set_admin(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRe
         )?;
     system_program.is_program(&system_program::ID)?;
 
-    // Set admin.
-    config.admin = new_admin;
+    // Propose admin. Proposing the default address cancels a pending transfer.
+    config.pending_admin = new_admin;
 
     Ok(())
 }
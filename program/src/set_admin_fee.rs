@@ -1,30 +1,40 @@
 use skill_api::prelude::*;
 use steel::*;
 
-/// Sets the admin fee.
+use crate::schedule_change;
+
+/// Schedules a change of a board's admin fee.
 pub fn process_set_admin_fee(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
     let args = SetAdminFee::try_from_bytes(data)?;
+    let board_id = u64::from_le_bytes(args.board_id);
     let new_admin_fee = u64::from_le_bytes(args.admin_fee);
 
     // Load accounts.
-    let [signer_info, config_info, system_program] = accounts else {
+    let [signer_info, config_info, change_info, system_program] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
     let config = config_info
         .as_account_mut::<Config>(&skill_api::ID)?
         .assert_mut_err(
-            |c| c.admin == *signer_info.key,
+            |c| c.fee_authority == *signer_info.key,
             OreError::NotAuthorized.into(),
         )?;
     system_program.is_program(&system_program::ID)?;
 
-    // Cap admin fee at 1%.
-    let new_admin_fee = new_admin_fee.min(100);
+    // Cap admin fee.
+    let new_admin_fee = new_admin_fee.min(MAX_BOARD_ADMIN_FEE);
 
-    // Set admin fee.
-    config.admin_fee = new_admin_fee;
+    // Schedule admin fee change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::AdminFee,
+        Change::encode_board_u64(board_id, new_admin_fee),
+    )?;
 
     Ok(())
 }
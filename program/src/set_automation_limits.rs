@@ -0,0 +1,52 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Sets the optional limits of an automation and resets its limit counters.
+pub fn process_set_automation_limits(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetAutomationLimits::try_from_bytes(data)?;
+    let max_rounds = u64::from_le_bytes(args.max_rounds);
+    let expires_at = u64::from_le_bytes(args.expires_at);
+    let daily_cap = u64::from_le_bytes(args.daily_cap);
+    let stop_loss = u64::from_le_bytes(args.stop_loss);
+    let take_profit = u64::from_le_bytes(args.take_profit);
+
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, automation_info, miner_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let automation = automation_info
+        .is_writable()?
+        .as_account_mut::<Automation>(&skill_api::ID)?
+        .assert_mut_err(
+            |a| a.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    let miner = miner_info
+        .as_account::<Miner>(&skill_api::ID)?
+        .assert(|m| m.authority == *signer_info.key)?;
+
+    // Reject an expiry that has already passed.
+    if expires_at > 0 && expires_at <= clock.slot {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Set limits.
+    automation.max_rounds = max_rounds;
+    automation.expires_at = expires_at;
+    automation.daily_cap = daily_cap;
+    automation.stop_loss = stop_loss;
+    automation.take_profit = take_profit;
+
+    // Reset counters, measuring winnings from the miner's current lifetime rewards.
+    automation.rounds = 0;
+    automation.day = clock.slot / ONE_DAY_SLOTS;
+    automation.day_spent = 0;
+    automation.total_spent = 0;
+    automation.total_won = 0;
+    automation.rewards_snapshot = miner.lifetime_rewards_sol;
+
+    Ok(())
+}
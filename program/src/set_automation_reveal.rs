@@ -0,0 +1,37 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Configures how the automation executor commits and reveals on the authority's behalf.
+pub fn process_set_automation_reveal(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetAutomationReveal::try_from_bytes(data)?;
+    let mode = AutomationReveal::try_from(args.mode).or(Err(ProgramError::InvalidArgument))?;
+    let count = (args.count as usize).min(Automation::MAX_COMMITMENTS);
+
+    // Load accounts.
+    let [signer_info, automation_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let automation = automation_info
+        .is_writable()?
+        .as_account_mut::<Automation>(&skill_api::ID)?
+        .assert_mut_err(
+            |a| a.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+
+    // Pre-committed hashes must be non-zero.
+    if args.commitments[..count].iter().any(|c| *c == [0u8; 32]) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Replace the reveal configuration.
+    automation.reveal_mode = mode as u64;
+    automation.commit_index = 0;
+    automation.commit_count = count as u64;
+    automation.commitments = [[0u8; 32]; Automation::MAX_COMMITMENTS];
+    automation.commitments[..count].copy_from_slice(&args.commitments[..count]);
+
+    Ok(())
+}
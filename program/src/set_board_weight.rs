@@ -0,0 +1,37 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of a board's weight.
+pub fn process_set_board_weight(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetBoardWeight::try_from_bytes(data)?;
+    let board_id = u64::from_le_bytes(args.board_id);
+    let new_weight = u64::from_le_bytes(args.weight);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Schedule board weight change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::BoardWeight,
+        Change::encode_board_u64(board_id, new_weight),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the bonus square count.
+pub fn process_set_bonus_square_count(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetBonusSquareCount::try_from_bytes(data)?;
+    let new_bonus_square_count = u64::from_le_bytes(args.bonus_square_count);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Cap the count at the bonus squares a round has room for.
+    let new_bonus_square_count = new_bonus_square_count.min(MAX_BONUS_SQUARES as u64);
+
+    // Schedule bonus square count change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::BonusSquareCount,
+        Change::encode_u64(new_bonus_square_count),
+    )?;
+
+    Ok(())
+}
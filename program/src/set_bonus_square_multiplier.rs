@@ -0,0 +1,43 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the bonus square multiplier.
+pub fn process_set_bonus_square_multiplier(
+    accounts: &[AccountInfo<'_>],
+    data: &[u8],
+) -> ProgramResult {
+    // Parse data.
+    let args = SetBonusSquareMultiplier::try_from_bytes(data)?;
+    let new_bonus_square_multiplier = u64::from_le_bytes(args.bonus_square_multiplier);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // A bonus square never pays less than a regular one, nor more than the cap.
+    let new_bonus_square_multiplier =
+        new_bonus_square_multiplier.clamp(100, MAX_BONUS_SQUARE_MULTIPLIER);
+
+    // Schedule bonus square multiplier change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::BonusSquareMultiplier,
+        Change::encode_u64(new_bonus_square_multiplier),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the buyback cap.
+pub fn process_set_buyback_cap(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetBuybackCap::try_from_bytes(data)?;
+    let new_buyback_cap = u64::from_le_bytes(args.buyback_cap);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Schedule buyback cap change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::BuybackCap,
+        Change::encode_u64(new_buyback_cap),
+    )?;
+
+    Ok(())
+}
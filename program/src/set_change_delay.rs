@@ -0,0 +1,41 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the change delay.
+pub fn process_set_change_delay(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetChangeDelay::try_from_bytes(data)?;
+    let new_change_delay = u64::from_le_bytes(args.delay);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Reject a delay so long it would lock out further changes.
+    if new_change_delay > MAX_CHANGE_DELAY_SLOTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Schedule change delay change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::ChangeDelay,
+        Change::encode_u64(new_change_delay),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::migrate_miner;
+
+/// Sets the address the signer's rewards are claimed to. The default address pays the authority.
+pub fn process_set_claim_recipient(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetClaimRecipient::try_from_bytes(data)?;
+    let claim_recipient = Pubkey::new_from_array(args.claim_recipient);
+
+    // Load accounts.
+    let [signer_info, miner_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    migrate_miner(miner_info, signer_info)?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&skill_api::ID)?
+        .assert_mut(|m| m.authority == *signer_info.key)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Set claim recipient. Claiming to the authority itself needs no recipient.
+    miner.claim_recipient = if claim_recipient != *signer_info.key {
+        claim_recipient
+    } else {
+        Pubkey::default()
+    };
+
+    Ok(())
+}
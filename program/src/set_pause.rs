@@ -0,0 +1,28 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Sets the pause bitmap.
+pub fn process_set_pause(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPause::try_from_bytes(data)?;
+    let paused = u64::from_le_bytes(args.paused);
+
+    // Load accounts.
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.pause_authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+
+    // Set pause bitmap.
+    config.paused = paused;
+    sol_log(&format!("Paused: {:#b}", paused));
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use skill_api::prelude::*;
+use steel::*;
+
+/// Sets the pause authority.
+pub fn process_set_pause_authority(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetPauseAuthority::try_from_bytes(data)?;
+    let new_authority = Pubkey::new_from_array(args.authority);
+
+    // Load accounts.
+    let [signer_info, config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+
+    // Set pause authority.
+    config.pause_authority = new_authority;
+
+    Ok(())
+}
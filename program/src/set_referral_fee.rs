@@ -0,0 +1,39 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the referral fee.
+pub fn process_set_referral_fee(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetReferralFee::try_from_bytes(data)?;
+    let new_referral_fee = u64::from_le_bytes(args.referral_fee);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.fee_authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Cap referral fee at the full claim fee.
+    let new_referral_fee = new_referral_fee.min(DENOMINATOR_BPS);
+
+    // Schedule referral fee change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::ReferralFee,
+        Change::encode_u64(new_referral_fee),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::schedule_change;
+
+/// Schedules a change of the RNG mode.
+pub fn process_set_rng_mode(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = SetRngMode::try_from_bytes(data)?;
+    let new_rng_mode = u64::from_le_bytes(args.rng_mode);
+
+    // Load accounts.
+    let [signer_info, config_info, change_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let config = config_info
+        .as_account_mut::<Config>(&skill_api::ID)?
+        .assert_mut_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Only the known modes are allowed.
+    if new_rng_mode != RNG_MODE_SLOT_HASHES && new_rng_mode != RNG_MODE_ENTROPY {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Schedule RNG mode change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::RngMode,
+        Change::encode_u64(new_rng_mode),
+    )?;
+
+    Ok(())
+}
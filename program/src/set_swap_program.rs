@@ -1,10 +1,13 @@
 use skill_api::prelude::*;
 use steel::*;
 
-/// Sets the swap program.
+use crate::schedule_change;
+
+/// Schedules a change of the swap program.
 pub fn process_set_swap_program(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, config_info, new_swap_program_info] = accounts else {
+    let [signer_info, config_info, new_swap_program_info, change_info, system_program] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
@@ -15,9 +18,17 @@ pub fn process_set_swap_program(accounts: &[AccountInfo<'_>], _data: &[u8]) -> P
             OreError::NotAuthorized.into(),
         )?;
     new_swap_program_info.is_executable()?;
+    system_program.is_program(&system_program::ID)?;
 
-    // Set swap program.
-    config.swap_program = *new_swap_program_info.key;
+    // Schedule swap program change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::SwapProgram,
+        new_swap_program_info.key.to_bytes(),
+    )?;
 
     Ok(())
 }
@@ -2,10 +2,14 @@ use entropy_api::state::Var;
 use skill_api::prelude::*;
 use steel::*;
 
-/// Sets the swap program.
+use crate::schedule_change;
+
+/// Schedules a change of the var address.
 pub fn process_set_var_address(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
     // Load accounts.
-    let [signer_info, board_info, config_info, new_var_address_info] = accounts else {
+    let [signer_info, board_info, config_info, new_var_address_info, change_info, system_program] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
@@ -20,9 +24,17 @@ pub fn process_set_var_address(accounts: &[AccountInfo<'_>], _data: &[u8]) -> Pr
         .as_account::<Var>(&entropy_api::ID)?
         .assert(|v| v.authority == *board_info.key)?
         .assert(|v| v.samples > 0)?;
+    system_program.is_program(&system_program::ID)?;
 
-    // Set var address.
-    config.var_address = *new_var_address_info.key;
+    // Schedule var address change.
+    schedule_change(
+        config,
+        signer_info,
+        change_info,
+        system_program,
+        ChangeKind::VarAddress,
+        new_var_address_info.key.to_bytes(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,70 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Pays out a tournament's prize pool. Completed tournaments pay the top finishers. Tournaments
+/// whose roster did not fill before the first matchday are cancelled, refunding entry fees to the
+/// players and the sponsored prize to the creator.
+pub fn process_settle_tournament(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let [signer_info, board_info, tournament_info, authority_info, player_infos @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let tournament = tournament_info
+        .as_account_mut::<Tournament>(&skill_api::ID)?
+        .assert_mut(|t| t.settled == 0)?;
+    let board = board_info
+        .as_account::<Board>(&skill_api::ID)?
+        .assert(|b| b.id == tournament.board_id)?;
+    authority_info
+        .is_writable()?
+        .has_address(&tournament.authority)?;
+
+    // Players are provided in roster order.
+    let player_count = tournament.player_count as usize;
+    if player_infos.len() < player_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    for (player_info, player) in player_infos.iter().zip(&tournament.players[..player_count]) {
+        player_info.is_writable()?.has_address(player)?;
+    }
+
+    if !tournament.is_full() {
+        // Cancel the tournament once the roster has closed.
+        if board.round_id < tournament.start_round {
+            sol_log("Tournament roster is still open");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let refunds = tournament.entry_fee * tournament.player_count;
+        for player_info in &player_infos[..player_count] {
+            tournament_info.send(tournament.entry_fee, player_info);
+        }
+        tournament_info.send(tournament.prize - refunds, authority_info);
+        sol_log(&format!("Tournament #{} cancelled", tournament.id));
+    } else {
+        // Pay the top finishers.
+        if !tournament.is_complete() {
+            sol_log("Tournament has matchdays left to score");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        for (place, player) in tournament.standings().into_iter().enumerate() {
+            let amount = tournament.prize_share(place);
+            if amount > 0 {
+                tournament_info.send(amount, &player_infos[player]);
+                sol_log(&format!(
+                    "#{}: {} wins {} SOL",
+                    place + 1,
+                    tournament.players[player],
+                    amount as f64 / LAMPORTS_PER_SOL as f64
+                ));
+            }
+        }
+    }
+    tournament.settled = 1;
+
+    Ok(())
+}
@@ -1,24 +1,21 @@
 use skill_api::prelude::*;
-use solana_program::log::sol_log;
+use solana_program::log::{sol_log, sol_log_data};
 use steel::*;
 
 /// Allows a miner to submit a commitment hash for the commit-reveal scheme.
 /// Must be called during the commit phase (after deploy phase, before reveal phase).
 /// The commitment is: keccak256(square || salt || authority)
+/// The automation executor may also commit on the authority's behalf, using the automation's
+/// pre-committed hashes or its own commitment to the automation's reveal rule.
 pub fn process_submit_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse instruction data
     let args = SubmitCommit::try_from_bytes(data)?;
-    let commitment = args.commitment;
-
-    // Validate commitment is non-zero
-    if commitment == [0u8; 32] {
-        sol_log("Invalid commitment: cannot be all zeros");
-        return Err(ProgramError::InvalidArgument);
-    }
 
     // Load accounts
     let clock = Clock::get()?;
-    let [signer_info, miner_info, board_info, round_info] = accounts else {
+    let [signer_info, authority_info, automation_info, miner_info, board_info, round_info] =
+        accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
@@ -28,10 +25,40 @@ pub fn process_submit_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     // Validate miner account
     miner_info
         .is_writable()?
-        .has_seeds(&[MINER, signer_info.key.as_ref()], &skill_api::ID)?;
+        .has_seeds(&[MINER, authority_info.key.as_ref()], &skill_api::ID)?;
+
+    // Resolve the commitment, either from the authority or from its automation executor.
+    let commitment = if signer_info.key == authority_info.key {
+        args.commitment
+    } else {
+        let automation = automation_info
+            .is_writable()?
+            .as_account_mut::<Automation>(&skill_api::ID)?
+            .assert_mut_err(
+                |a| a.executor == *signer_info.key && a.authority == *authority_info.key,
+                OreError::NotAuthorized.into(),
+            )?;
+        match AutomationReveal::from_u64(automation.reveal_mode) {
+            AutomationReveal::None => {
+                sol_log("Automation does not commit on behalf of the authority");
+                return Err(OreError::NotAuthorized.into());
+            }
+            AutomationReveal::Committed => {
+                let Some(commitment) = automation.next_commitment() else {
+                    sol_log("Automation has no pre-committed hashes left");
+                    return Err(ProgramError::InvalidAccountData);
+                };
+                commitment
+            }
+            AutomationReveal::Deployed => args.commitment,
+        }
+    };
 
-    // Validate board account
-    board_info.has_seeds(&[BOARD], &skill_api::ID)?;
+    // Validate commitment is non-zero
+    if commitment == [0u8; 32] {
+        sol_log("Invalid commitment: cannot be all zeros");
+        return Err(ProgramError::InvalidArgument);
+    }
 
     // Parse accounts
     let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
@@ -42,7 +69,7 @@ pub fn process_submit_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
 
     // Validate round account
     round_info.has_seeds(
-        &[ROUND, current_round_id.to_le_bytes().as_ref()],
+        &round_seeds(&board.id.to_le_bytes(), &current_round_id.to_le_bytes()),
         &skill_api::ID,
     )?;
     let round = round_info.as_account::<Round>(&skill_api::ID)?;
@@ -60,7 +87,7 @@ pub fn process_submit_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     }
 
     // Check if miner has deployed SOL this round (stake requirement)
-    if miner.round_id != current_round_id {
+    if miner.board_id != board.id || miner.round_id != current_round_id {
         sol_log("Must deploy SOL this round before committing");
         return Err(ProgramError::InvalidAccountData);
     }
@@ -74,10 +101,15 @@ pub fn process_submit_commit(accounts: &[AccountInfo<'_>], data: &[u8]) -> Progr
     // Submit the commitment
     miner.submit_commitment(commitment, current_round_id);
 
-    sol_log(&format!(
-        "Commitment submitted for round {}",
-        current_round_id
-    ));
+    debug_log!("Commitment submitted for round {}", current_round_id);
+    sol_log_data(&[CommitEvent {
+        disc: 10,
+        authority: *authority_info.key,
+        round_id: current_round_id,
+        board_id: board.id,
+        ts: clock.unix_timestamp,
+    }
+    .to_bytes()]);
 
     Ok(())
 }
@@ -9,12 +9,6 @@ pub fn process_submit_prediction(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
     let args = SubmitPrediction::try_from_bytes(data)?;
     let predicted_square = args.square;
 
-    // Validate prediction is in valid range (0-24)
-    if predicted_square > 24 {
-        sol_log("Invalid prediction: square must be 0-24");
-        return Err(ProgramError::InvalidArgument);
-    }
-
     // Load accounts
     let [signer_info, miner_info, board_info] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -28,13 +22,22 @@ pub fn process_submit_prediction(accounts: &[AccountInfo<'_>], data: &[u8]) -> P
         .is_writable()?
         .has_seeds(&[MINER, signer_info.key.as_ref()], &skill_api::ID)?;
 
-    // Validate board account
-    board_info.has_seeds(&[BOARD], &skill_api::ID)?;
-
     // Parse accounts
     let miner = miner_info.as_account_mut::<Miner>(&skill_api::ID)?;
     let board = board_info.as_account::<Board>(&skill_api::ID)?;
 
+    // Validate prediction is on the board
+    if predicted_square as u64 >= board.squares {
+        sol_log("Invalid prediction: square is not on the board");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Predictions count toward the board the miner is playing on.
+    if miner.board_id != board.id {
+        sol_log("Miner is playing on another board");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     // Get current round from board
     let current_round = board.round_id;
 
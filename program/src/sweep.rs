@@ -0,0 +1,51 @@
+use skill_api::prelude::*;
+use steel::*;
+
+use crate::{claim_ore_to, migrate_miner};
+
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Claims a miner's SOL and ORE rewards to its claim recipient. Anyone may sweep a miner that has
+/// set a claim recipient.
+pub fn process_sweep(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult {
+    // Load accounts.
+    let clock = Clock::get()?;
+    let [signer_info, miner_info, claim_recipient_info, recipient_info, mint_info, treasury_info, treasury_tokens_info, system_program, token_program, associated_token_program, config_info, referrer_miner_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    migrate_miner(miner_info, signer_info)?;
+    let miner = miner_info
+        .as_account_mut::<Miner>(&skill_api::ID)?
+        .assert_mut_err(
+            |m| m.has_claim_recipient(),
+            OreError::NoClaimRecipient.into(),
+        )?;
+    claim_recipient_info
+        .is_writable()?
+        .has_address(&miner.claim_recipient)?;
+
+    // Sweep SOL.
+    let amount = miner.claim_sol(&clock);
+    debug_log!("Sweeping {} SOL", amount as f64 / LAMPORTS_PER_SOL as f64);
+    miner_info.send(amount, claim_recipient_info);
+
+    // Sweep ORE.
+    claim_ore_to(
+        &clock,
+        signer_info,
+        miner_info,
+        claim_recipient_info,
+        mint_info,
+        recipient_info,
+        treasury_info,
+        treasury_tokens_info,
+        system_program,
+        token_program,
+        associated_token_program,
+        config_info,
+        referrer_miner_info,
+    )
+}
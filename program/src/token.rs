@@ -0,0 +1,201 @@
+use skill_api::prelude::*;
+use solana_program::program::invoke;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account, Mint},
+};
+use steel::*;
+
+// The SKILL mint is owned by either spl-token or Token-2022. These helpers work with both, so
+// each instruction only needs to check that the token program it was given owns the mint.
+
+/// Parses a zero-padded UTF-8 metadata field from instruction data.
+pub fn parse_metadata_str(bytes: &[u8]) -> Result<String, ProgramError> {
+    let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    String::from_utf8(bytes[..len].to_vec()).map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+/// Loads the SKILL mint, checking that the token program owns it.
+pub fn load_skill_mint(
+    mint_info: &AccountInfo<'_>,
+    token_program: &AccountInfo<'_>,
+) -> Result<Mint, ProgramError> {
+    mint_info.has_address(&MINT_ADDRESS)?;
+    if *token_program.key != spl_token::ID && *token_program.key != spl_token_2022::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    token_program.is_program(mint_info.owner)?;
+    let data = mint_info.try_borrow_data()?;
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?.base)
+}
+
+/// Loads an associated SKILL token account of the given owner.
+pub fn load_skill_token_account(
+    info: &AccountInfo<'_>,
+    owner: &Pubkey,
+    token_program: &AccountInfo<'_>,
+) -> Result<Account, ProgramError> {
+    info.has_address(&get_associated_token_address_with_program_id(
+        owner,
+        &MINT_ADDRESS,
+        token_program.key,
+    ))?
+    .has_owner(token_program.key)?;
+    let data = info.try_borrow_data()?;
+    let account = StateWithExtensions::<Account>::unpack(&data)?.base;
+    if account.mint != MINT_ADDRESS || account.owner != *owner {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(account)
+}
+
+/// Creates an associated SKILL token account for the given owner.
+pub fn create_skill_token_account<'info>(
+    payer_info: &AccountInfo<'info>,
+    owner_info: &AccountInfo<'info>,
+    token_account_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> ProgramResult {
+    invoke(
+        &spl_associated_token_account::instruction::create_associated_token_account(
+            payer_info.key,
+            owner_info.key,
+            mint_info.key,
+            token_program.key,
+        ),
+        &[
+            payer_info.clone(),
+            token_account_info.clone(),
+            owner_info.clone(),
+            mint_info.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )
+}
+
+/// Transfers SKILL tokens, authorized by a signer of this transaction.
+pub fn transfer_skill<'info>(
+    authority_info: &AccountInfo<'info>,
+    from_info: &AccountInfo<'info>,
+    to_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            from_info.key,
+            mint_info.key,
+            to_info.key,
+            authority_info.key,
+            &[],
+            amount,
+            TOKEN_DECIMALS,
+        )?,
+        &[
+            from_info.clone(),
+            mint_info.clone(),
+            to_info.clone(),
+            authority_info.clone(),
+            token_program.clone(),
+        ],
+    )
+}
+
+/// Transfers SKILL tokens, authorized by a PDA of this program.
+pub fn transfer_skill_signed<'info>(
+    authority_info: &AccountInfo<'info>,
+    from_info: &AccountInfo<'info>,
+    to_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            from_info.key,
+            mint_info.key,
+            to_info.key,
+            authority_info.key,
+            &[],
+            amount,
+            TOKEN_DECIMALS,
+        )?,
+        &[
+            from_info.clone(),
+            mint_info.clone(),
+            to_info.clone(),
+            authority_info.clone(),
+            token_program.clone(),
+        ],
+        &skill_api::ID,
+        seeds,
+    )
+}
+
+/// Mints SKILL tokens with the treasury as mint authority.
+pub fn mint_skill_signed<'info>(
+    mint_info: &AccountInfo<'info>,
+    to_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::mint_to_checked(
+            token_program.key,
+            mint_info.key,
+            to_info.key,
+            treasury_info.key,
+            &[],
+            amount,
+            TOKEN_DECIMALS,
+        )?,
+        &[
+            mint_info.clone(),
+            to_info.clone(),
+            treasury_info.clone(),
+            token_program.clone(),
+        ],
+        &skill_api::ID,
+        &[TREASURY],
+    )
+}
+
+/// Burns SKILL tokens held by the treasury.
+pub fn burn_skill_signed<'info>(
+    from_info: &AccountInfo<'info>,
+    mint_info: &AccountInfo<'info>,
+    treasury_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::burn_checked(
+            token_program.key,
+            from_info.key,
+            mint_info.key,
+            treasury_info.key,
+            &[],
+            amount,
+            TOKEN_DECIMALS,
+        )?,
+        &[
+            from_info.clone(),
+            mint_info.clone(),
+            treasury_info.clone(),
+            token_program.clone(),
+        ],
+        &skill_api::ID,
+        &[TREASURY],
+    )
+}
@@ -0,0 +1,89 @@
+use std::mem::offset_of;
+
+use skill_api::prelude::*;
+use solana_program::{log::sol_log, rent::Rent};
+use steel::*;
+
+use crate::migrate_round;
+
+/// Updates a board's parameters. Timing changes take effect from the board's next round. A new
+/// geometry applies to the round waiting to start, whose bonus squares were drawn for the old
+/// geometry and are cleared. The admin fee and weight are changed through the change timelock
+/// (see SetAdminFee and SetBoardWeight).
+pub fn process_update_board(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = UpdateBoard::try_from_bytes(data)?;
+    let min_deploy = u64::from_le_bytes(args.min_deploy);
+    let deploy_slots = u64::from_le_bytes(args.deploy_slots);
+    let commit_slots = u64::from_le_bytes(args.commit_slots);
+    let reveal_slots = u64::from_le_bytes(args.reveal_slots);
+    let squares = u64::from_le_bytes(args.squares);
+
+    // Load accounts.
+    let [signer_info, config_info, board_info, system_program, optional_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&skill_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    board_info.is_writable()?;
+    system_program.is_program(&system_program::ID)?;
+    if deploy_slots == 0 || !Board::is_valid_geometry(squares) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Resize the main board if it was created before boards were parameterized. The signer
+    // covers the additional rent. Boards from before multiple boards already count towards the
+    // total weight with the default weight (see migrate_config).
+    let old_size = board_info.data_len();
+    let new_size = 8 + std::mem::size_of::<Board>();
+    if old_size < new_size {
+        let rent = Rent::get()?;
+        let diff = rent.minimum_balance(new_size) - rent.minimum_balance(old_size);
+        board_info.collect(diff, signer_info)?;
+        board_info.resize(new_size)?;
+        board_info.try_borrow_mut_data()?[old_size..].fill(0);
+    }
+
+    // Update board. Boards created before multiple boards are loaded with id 0.
+    let board = board_info.as_account_mut::<Board>(&skill_api::ID)?;
+    board_info.has_seeds(&board_seeds(&board.id.to_le_bytes()), &skill_api::ID)?;
+    if old_size <= 8 + offset_of!(Board, weight) {
+        board.weight = DEFAULT_BOARD_WEIGHT;
+    }
+    if board.squares == 0 {
+        // Boards created before geometries were configurable are 5x5.
+        board.squares = LEGACY_MAX_SQUARES as u64;
+    }
+    if squares != board.squares && board.end_slot != u64::MAX {
+        sol_log("Board geometry can only change between rounds");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Clear the bonus squares of the round waiting to start, which may not fit the new geometry.
+    if squares != board.squares {
+        let [round_info, ..] = optional_accounts else {
+            sol_log("The round waiting to start is required to change geometry");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        round_info.has_address(&round_pda(board.id, board.round_id).0)?;
+        if !round_info.data_is_empty() {
+            migrate_round(round_info, signer_info)?;
+            let round = round_info.as_account_mut::<Round>(&skill_api::ID)?;
+            round.bonus_squares = [NO_BONUS_SQUARE; MAX_BONUS_SQUARES];
+        }
+    }
+    board.min_deploy = min_deploy;
+    board.deploy_slots = deploy_slots;
+    board.commit_slots = commit_slots;
+    board.reveal_slots = reveal_slots;
+    board.squares = squares;
+    sol_log(&format!("Board #{} updated", board.id));
+
+    Ok(())
+}
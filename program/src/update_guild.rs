@@ -0,0 +1,36 @@
+use skill_api::prelude::*;
+use solana_program::log::sol_log;
+use steel::*;
+
+/// Updates a guild's executor and revenue share.
+pub fn process_update_guild(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = UpdateGuild::try_from_bytes(data)?;
+    let executor = Pubkey::new_from_array(args.executor);
+    let revenue_share = u64::from_le_bytes(args.revenue_share);
+
+    // Load accounts.
+    let [signer_info, guild_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    let guild = guild_info
+        .as_account_mut::<Guild>(&skill_api::ID)?
+        .assert_mut_err(
+            |g| g.authority == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    if revenue_share > MAX_GUILD_REVENUE_SHARE {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update guild.
+    guild.executor = executor;
+    guild.revenue_share = revenue_share;
+    sol_log(&format!(
+        "Guild #{} updated: executor {}, revenue share {} bps",
+        guild.id, executor, revenue_share
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use skill_api::prelude::*;
+use solana_program::rent::Rent;
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+use steel::*;
+
+use crate::token::*;
+
+/// Updates the metadata URI of a Token-2022 SKILL mint.
+pub fn process_update_metadata_uri(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+    // Parse data.
+    let args = UpdateMetadataUri::try_from_bytes(data)?;
+    let uri = parse_metadata_str(&args.uri)?;
+
+    // Load accounts.
+    let [signer_info, config_info, mint_info, treasury_info, system_program, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    signer_info.is_signer()?;
+    config_info
+        .as_account::<Config>(&skill_api::ID)?
+        .assert_err(
+            |c| c.admin == *signer_info.key,
+            OreError::NotAuthorized.into(),
+        )?;
+    token_program.is_program(&spl_token_2022::ID)?;
+    load_skill_mint(mint_info.is_writable()?, token_program)?;
+    treasury_info.has_seeds(&[TREASURY], &skill_api::ID)?;
+    system_program.is_program(&system_program::ID)?;
+
+    // Fund the mint for the resized metadata. The signer covers the additional rent.
+    let new_len = {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+        let mut metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+        let old_size = metadata.tlv_size_of()?;
+        metadata.uri = uri.clone();
+        (mint_data.len() + metadata.tlv_size_of()?).saturating_sub(old_size)
+    };
+    let min_balance = Rent::get()?.minimum_balance(new_len);
+    if min_balance > mint_info.lamports() {
+        mint_info.collect(min_balance - mint_info.lamports(), signer_info)?;
+    }
+
+    // Update the URI, signed by Treasury as update authority.
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::update_field(
+            &spl_token_2022::ID,
+            mint_info.key,
+            treasury_info.key,
+            Field::Uri,
+            uri,
+        ),
+        &[
+            mint_info.clone(),
+            treasury_info.clone(),
+            token_program.clone(),
+        ],
+        &skill_api::ID,
+        &[TREASURY],
+    )?;
+
+    Ok(())
+}
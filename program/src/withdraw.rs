@@ -3,6 +3,8 @@ use solana_program::log::sol_log;
 use spl_token::amount_to_ui_amount;
 use steel::*;
 
+use crate::token::*;
+
 /// Withdraws ORE from the staking contract.
 pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
     // Parse data.
@@ -17,22 +19,19 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    mint_info.has_address(&MINT_ADDRESS)?.as_mint()?;
-    recipient_info
-        .is_writable()?
-        .as_associated_token_account(&signer_info.key, &mint_info.key)?;
+    load_skill_mint(mint_info, token_program)?;
+    recipient_info.is_writable()?;
     let stake = stake_info
         .as_account_mut::<Stake>(&skill_api::ID)?
         .assert_mut(|s| s.authority == *signer_info.key)?;
-    stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
+    load_skill_token_account(stake_tokens_info, stake_info.key, token_program)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
     system_program.is_program(&system_program::ID)?;
-    token_program.is_program(&spl_token::ID)?;
     associated_token_program.is_program(&spl_associated_token_account::ID)?;
 
     // Open recipient token account.
     if recipient_info.data_is_empty() {
-        create_associated_token_account(
+        create_skill_token_account(
             signer_info,
             signer_info,
             recipient_info,
@@ -41,16 +40,19 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
             token_program,
             associated_token_program,
         )?;
+    } else {
+        load_skill_token_account(recipient_info, signer_info.key, token_program)?;
     }
 
     // Deposit into stake account.
     let amount = stake.withdraw(amount, &clock, treasury);
 
     // Transfer ORE to recipient.
-    transfer_signed(
+    transfer_skill_signed(
         stake_info,
         stake_tokens_info,
         recipient_info,
+        mint_info,
         token_program,
         amount,
         &[STAKE, &stake.authority.to_bytes()],
@@ -66,9 +68,8 @@ pub fn process_withdraw(accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramRes
     );
 
     // Safety check.
-    let stake_tokens =
-        stake_tokens_info.as_associated_token_account(stake_info.key, mint_info.key)?;
-    assert!(stake_tokens.amount() >= stake.balance);
+    let stake_tokens = load_skill_token_account(stake_tokens_info, stake_info.key, token_program)?;
+    assert!(stake_tokens.amount >= stake.balance);
 
     Ok(())
 }
@@ -1,5 +1,5 @@
 use skill_api::prelude::*;
-use solana_program::{native_token::LAMPORTS_PER_SOL, rent::Rent};
+use solana_program::rent::Rent;
 use steel::*;
 
 /// Send SOL from the treasury to the WSOL account.
@@ -10,7 +10,7 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     signer_info.is_signer()?;
-    config_info
+    let config = config_info
         .as_account::<Config>(&skill_api::ID)?
         .assert(|c| c.bury_authority == *signer_info.key)?;
     let treasury = treasury_info.as_account_mut::<Treasury>(&skill_api::ID)?;
@@ -20,7 +20,7 @@ pub fn process_wrap(accounts: &[AccountInfo<'_>], _data: &[u8]) -> ProgramResult
     system_program.is_program(&system_program::ID)?;
 
     // Get amount
-    let amount = (LAMPORTS_PER_SOL * 100).min(treasury.balance);
+    let amount = config.buyback_cap.min(treasury.balance);
 
     // Send SOL to the WSOL account.
     treasury_info.send(amount, treasury_sol_info);
@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn test_set_change_delay_is_bounded() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    let mut context = world.start().await;
+    let ix = skill_api::sdk::set_change_delay(admin.pubkey(), MAX_CHANGE_DELAY_SLOTS + 1, 0);
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert_program_error(result, InstructionError::InvalidArgument);
+
+    let ix = skill_api::sdk::set_change_delay(admin.pubkey(), MAX_CHANGE_DELAY_SLOTS, 0);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+    let change = get_state::<Change>(&mut context, change_pda(0).0).await;
+    assert_eq!(change.value_u64(), MAX_CHANGE_DELAY_SLOTS);
+}
+
+#[tokio::test]
+async fn test_set_bonus_square_multiplier_is_capped() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    world.config.change_delay = 0;
+    let mut context = world.start().await;
+    let ix = skill_api::sdk::set_bonus_square_multiplier(admin.pubkey(), u64::MAX, 0);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+    let ix = skill_api::sdk::execute_change(context.payer.pubkey(), 0, admin.pubkey(), None);
+    process(&mut context, &[ix]).await.unwrap();
+    let config = get_state::<Config>(&mut context, CONFIG_ADDRESS).await;
+    assert_eq!(config.bonus_square_multiplier, MAX_BONUS_SQUARE_MULTIPLIER);
+}
+
+#[tokio::test]
+async fn test_schedule_change_saturates_effective_slot() {
+    // A delay set before it was bounded must not overflow the effective slot.
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    world.config.change_delay = u64::MAX;
+    let mut context = world.start().await;
+    let ix = skill_api::sdk::set_change_delay(admin.pubkey(), DEFAULT_CHANGE_DELAY_SLOTS, 0);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+    let change = get_state::<Change>(&mut context, change_pda(0).0).await;
+    assert_eq!(change.effective_slot, u64::MAX);
+}
+
+#[tokio::test]
+async fn test_set_admin_fee_changes_board_fee() {
+    let admin = Keypair::new();
+    let fee_authority = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    world.add_wallet(fee_authority.pubkey());
+    world.config.fee_authority = fee_authority.pubkey();
+    world.config.change_delay = 0;
+    let mut context = world.start().await;
+
+    // Only the fee authority schedules fee changes.
+    let ix = skill_api::sdk::set_admin_fee(admin.pubkey(), 0, 50, 0);
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert_program_error(result, ore_error(OreError::NotAuthorized));
+
+    let ix = skill_api::sdk::set_admin_fee(fee_authority.pubkey(), 0, 50, 0);
+    process_signed(&mut context, &[ix], &[&fee_authority])
+        .await
+        .unwrap();
+    let ix =
+        skill_api::sdk::execute_change(context.payer.pubkey(), 0, fee_authority.pubkey(), Some(0));
+    process(&mut context, &[ix]).await.unwrap();
+    let board = get_state::<Board>(&mut context, board_pda(0).0).await;
+    assert_eq!(board.admin_fee, 50);
+}
+
+#[tokio::test]
+async fn test_set_board_weight_changes_total_weight() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    world.config.change_delay = 0;
+    let mut context = world.start().await;
+    let ix = skill_api::sdk::set_board_weight(admin.pubkey(), 0, 3 * DEFAULT_BOARD_WEIGHT, 0);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+
+    // The change applies to the board, which must be given.
+    let ix = skill_api::sdk::execute_change(context.payer.pubkey(), 0, admin.pubkey(), None);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+
+    let ix = skill_api::sdk::execute_change(context.payer.pubkey(), 0, admin.pubkey(), Some(0));
+    process(&mut context, &[ix]).await.unwrap();
+    let board = get_state::<Board>(&mut context, board_pda(0).0).await;
+    assert_eq!(board.weight, 3 * DEFAULT_BOARD_WEIGHT);
+    let config = get_state::<Config>(&mut context, CONFIG_ADDRESS).await;
+    assert_eq!(config.total_board_weight, 3 * DEFAULT_BOARD_WEIGHT);
+}
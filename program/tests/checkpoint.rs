@@ -0,0 +1,111 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+/// The tested miner's authority.
+const AUTHORITY: Pubkey = Pubkey::new_from_array([9; 32]);
+
+/// A season of round 1 alone.
+fn season(id: u64) -> Season {
+    let mut season = Season::zeroed();
+    season.id = id;
+    season.start_round = 1;
+    season.end_round = 1;
+    season
+}
+
+/// The main board after a round the miner won, which is part of season 0.
+fn season_world() -> World {
+    let mut world = World::won(1);
+    world.round.season = 1;
+    world.add_state(miner_pda(AUTHORITY).0, &won_miner(AUTHORITY), 0);
+    world.add_state(season_pda(0).0, &season(0), 0);
+    world
+}
+
+fn checkpoint_ix(payer: Pubkey, season_id: Option<u64>) -> Instruction {
+    skill_api::sdk::checkpoint(payer, AUTHORITY, 0, 1, None, season_id)
+}
+
+/// Removes an account from an instruction.
+fn without_account(mut ix: Instruction, address: Pubkey) -> Instruction {
+    ix.accounts.retain(|a| a.pubkey != address);
+    ix
+}
+
+#[tokio::test]
+async fn test_checkpoint_requires_achievements() {
+    let mut context = season_world().start().await;
+    let ix = checkpoint_ix(context.payer.pubkey(), Some(0));
+    let ix = without_account(ix, achievements_pda(AUTHORITY).0);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn test_checkpoint_records_achievements() {
+    let mut achievements = Achievements::zeroed();
+    achievements.authority = AUTHORITY;
+    let mut world = season_world();
+    world.add_state(achievements_pda(AUTHORITY).0, &achievements, 0);
+    let mut context = world.start().await;
+    let ix = checkpoint_ix(context.payer.pubkey(), Some(0));
+    process(&mut context, &[ix]).await.unwrap();
+
+    let achievements = get_state::<Achievements>(&mut context, achievements_pda(AUTHORITY).0).await;
+    assert!(achievements.has(Achievement::FirstWin));
+}
+
+#[tokio::test]
+async fn test_checkpoint_requires_season() {
+    let mut context = season_world().start().await;
+    let ix = checkpoint_ix(context.payer.pubkey(), None);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+
+    // With the season, the win earns season points.
+    let ix = checkpoint_ix(context.payer.pubkey(), Some(0));
+    process(&mut context, &[ix]).await.unwrap();
+    let season_miner =
+        get_state::<SeasonMiner>(&mut context, season_miner_pda(0, AUTHORITY).0).await;
+    assert_eq!(season_miner.authority, AUTHORITY);
+    assert!(season_miner.points > 0);
+    let season = get_state::<Season>(&mut context, season_pda(0).0).await;
+    assert_eq!(season.leaders[0], AUTHORITY);
+    assert_eq!(season.leader_points[0], season_miner.points);
+}
+
+#[tokio::test]
+async fn test_checkpoint_adds_to_season_points() {
+    // The miner already has points in the season.
+    let mut world = season_world();
+    let mut season_miner = SeasonMiner::zeroed();
+    season_miner.authority = AUTHORITY;
+    season_miner.points = 700;
+    world.add_state(season_miner_pda(0, AUTHORITY).0, &season_miner, 0);
+    let mut context = world.start().await;
+    let ix = checkpoint_ix(context.payer.pubkey(), Some(0));
+    process(&mut context, &[ix]).await.unwrap();
+
+    let season_miner =
+        get_state::<SeasonMiner>(&mut context, season_miner_pda(0, AUTHORITY).0).await;
+    assert!(season_miner.points > 700);
+    let season = get_state::<Season>(&mut context, season_pda(0).0).await;
+    assert_eq!(season.leader_points[0], season_miner.points);
+}
+
+#[tokio::test]
+async fn test_checkpoint_rejects_other_season() {
+    let mut world = season_world();
+    world.add_state(season_pda(1).0, &season(1), 0);
+    let mut context = world.start().await;
+    let ix = checkpoint_ix(context.payer.pubkey(), Some(1));
+    assert!(process(&mut context, &[ix]).await.is_err());
+}
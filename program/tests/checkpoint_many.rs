@@ -0,0 +1,142 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+
+/// The most compute units a transaction can request.
+const MAX_TX_CU: u64 = 1_400_000;
+
+/// Miners of a won round, each in a guild of its own if `guilds` is set.
+fn miners(count: usize, guilds: bool) -> Vec<CheckpointMiner> {
+    (0..count)
+        .map(|i| CheckpointMiner {
+            authority: Pubkey::new_unique(),
+            guild: guilds.then(|| guild_pda(i as u64).0),
+        })
+        .collect()
+}
+
+/// The main board after a round `miners` won.
+fn won_world(miners: &[CheckpointMiner]) -> World {
+    let mut world = World::won(miners.len() as u64);
+    for (i, m) in miners.iter().enumerate() {
+        let mut miner = won_miner(m.authority);
+        if let Some(guild_address) = m.guild {
+            miner.guild = guild_address;
+            let mut guild = Guild::zeroed();
+            guild.id = i as u64;
+            world.add_state(guild_address, &guild, 0);
+        }
+        world.add_state(miner_pda(m.authority).0, &miner, 0);
+    }
+    world
+}
+
+fn checkpoint_many_ix(payer: Pubkey, miners: &[CheckpointMiner]) -> Instruction {
+    skill_api::sdk::checkpoint_many(payer, 0, 1, miners, None)
+}
+
+/// Checks a full batch of miners fits in a transaction, in size and in compute units.
+async fn bench_full_batch(guilds: bool) {
+    let miners = miners(64, guilds);
+    let batch = skill_api::sdk::checkpoint_many_batches(&miners, false)[0];
+    let mut context = won_world(batch).start().await;
+    let ixs = [
+        ComputeBudgetInstruction::set_compute_unit_limit(MAX_TX_CU as u32),
+        checkpoint_many_ix(context.payer.pubkey(), batch),
+    ];
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    let size = bincode::serialize(&tx).unwrap().len();
+    let (units, _logs) = simulate(&mut context, &ixs).await;
+    println!(
+        "checkpoint_many: {} miners{}, {} bytes, {} CU",
+        batch.len(),
+        if guilds { " with guilds" } else { "" },
+        size,
+        units
+    );
+    assert!(size <= PACKET_DATA_SIZE);
+    assert!(units < MAX_TX_CU);
+}
+
+#[tokio::test]
+async fn bench_checkpoint_many_full_batch() {
+    bench_full_batch(false).await;
+}
+
+#[tokio::test]
+async fn bench_checkpoint_many_full_batch_with_guilds() {
+    bench_full_batch(true).await;
+}
+
+#[test]
+fn test_checkpoint_many_batches() {
+    // Miners take two accounts, and one more with a guild or a season.
+    assert_eq!(
+        skill_api::sdk::checkpoint_many_batches(&miners(30, false), false)
+            .iter()
+            .map(|b| b.len())
+            .collect::<Vec<_>>(),
+        vec![12, 12, 6]
+    );
+    assert_eq!(
+        skill_api::sdk::checkpoint_many_batches(&miners(10, true), false)
+            .iter()
+            .map(|b| b.len())
+            .collect::<Vec<_>>(),
+        vec![8, 2]
+    );
+    assert_eq!(
+        skill_api::sdk::checkpoint_many_batches(&miners(10, true), true)
+            .iter()
+            .map(|b| b.len())
+            .collect::<Vec<_>>(),
+        vec![6, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_checkpoint_many_pays_miners() {
+    let miners = miners(3, false);
+    let mut context = won_world(&miners).start().await;
+    let ix = checkpoint_many_ix(context.payer.pubkey(), &miners);
+    process(&mut context, &[ix]).await.unwrap();
+    let admin_fee = board().admin_fee(WON_DEPLOYED).max(1);
+    for m in miners.iter() {
+        let miner = get_state::<Miner>(&mut context, miner_pda(m.authority).0).await;
+        assert_eq!(miner.checkpoint_id, 1);
+        assert_eq!(miner.rewards_sol, WON_DEPLOYED - admin_fee);
+    }
+}
+
+#[tokio::test]
+async fn test_checkpoint_many_requires_guild() {
+    let miners = miners(2, true);
+    let mut context = won_world(&miners).start().await;
+    let mut ix = checkpoint_many_ix(context.payer.pubkey(), &miners);
+    ix.accounts.retain(|a| Some(a.pubkey) != miners[1].guild);
+    let result = process(&mut context, &[ix]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_checkpoint_many_requires_achievements() {
+    let miners = miners(2, false);
+    let mut context = won_world(&miners).start().await;
+    let mut ix = checkpoint_many_ix(context.payer.pubkey(), &miners);
+    let achievements = achievements_pda(miners[0].authority).0;
+    ix.accounts.retain(|a| a.pubkey != achievements);
+    let result = process(&mut context, &[ix]).await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// The miner's claimable SOL.
+const REWARDS_SOL: u64 = 1_000_000;
+
+/// The address the miner's rewards are claimed to.
+const CLAIM_RECIPIENT: Pubkey = Pubkey::new_from_array([8; 32]);
+
+/// The main board, with the authority's miner holding SOL to claim, to `claim_recipient` if set.
+fn claim_world(authority: &Keypair, claim_recipient: Pubkey) -> World {
+    let mut miner = Miner::zeroed();
+    miner.authority = authority.pubkey();
+    miner.rewards_sol = REWARDS_SOL;
+    miner.claim_recipient = claim_recipient;
+    let mut world = World::default();
+    world.add_state(miner_pda(authority.pubkey()).0, &miner, REWARDS_SOL);
+    world
+}
+
+#[tokio::test]
+async fn test_claim_sol_pays_claim_recipient() {
+    let authority = Keypair::new();
+    let mut context = claim_world(&authority, CLAIM_RECIPIENT).start().await;
+    let ix = skill_api::sdk::claim_sol(authority.pubkey(), Some(CLAIM_RECIPIENT));
+    process_signed(&mut context, &[ix], &[&authority])
+        .await
+        .unwrap();
+    assert_eq!(
+        get_lamports(&mut context, CLAIM_RECIPIENT).await,
+        REWARDS_SOL
+    );
+    assert_eq!(get_lamports(&mut context, authority.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn test_claim_sol_requires_claim_recipient() {
+    let authority = Keypair::new();
+    let mut context = claim_world(&authority, CLAIM_RECIPIENT).start().await;
+    let ix = skill_api::sdk::claim_sol(authority.pubkey(), None);
+    let result = process_signed(&mut context, &[ix], &[&authority]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+
+    // Another address can't stand in for the recipient.
+    let ix = skill_api::sdk::claim_sol(authority.pubkey(), Some(Pubkey::new_unique()));
+    let result = process_signed(&mut context, &[ix], &[&authority]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_sweep_pays_claim_recipient() {
+    let authority = Keypair::new();
+    let mut context = claim_world(&authority, CLAIM_RECIPIENT).start().await;
+    let ix = skill_api::sdk::sweep(
+        context.payer.pubkey(),
+        authority.pubkey(),
+        CLAIM_RECIPIENT,
+        spl_token::ID,
+        Pubkey::default(),
+    );
+    process(&mut context, &[ix]).await.unwrap();
+    assert_eq!(
+        get_lamports(&mut context, CLAIM_RECIPIENT).await,
+        REWARDS_SOL
+    );
+
+    // The recipient's token account is opened for the ORE rewards.
+    let recipient_tokens = get_associated_token_address(&CLAIM_RECIPIENT, &MINT_ADDRESS);
+    assert!(get_lamports(&mut context, recipient_tokens).await > 0);
+}
+
+#[tokio::test]
+async fn test_sweep_requires_claim_recipient() {
+    let authority = Keypair::new();
+    let mut context = claim_world(&authority, Pubkey::default()).start().await;
+    let ix = skill_api::sdk::sweep(
+        context.payer.pubkey(),
+        authority.pubkey(),
+        authority.pubkey(),
+        spl_token::ID,
+        Pubkey::default(),
+    );
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, ore_error(OreError::NoClaimRecipient));
+}
@@ -0,0 +1,324 @@
+#![allow(dead_code)]
+
+use bytemuck::{Pod, Zeroable};
+use skill_api::prelude::*;
+use solana_program::{program_option::COption, program_pack::Pack};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+use steel::Discriminator;
+
+/// The fee collector the test config pays admin fees to.
+pub const FEE_COLLECTOR: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// The slot test rounds end at.
+pub const END_SLOT: u64 = 1_000;
+
+/// A program test with the program loaded. Under `cargo test-sbf` the compiled program is used,
+/// so compute units are metered as on chain.
+pub fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "skill",
+        skill_api::ID,
+        processor!(skill::process_instruction),
+    );
+    add_mint(&mut program_test);
+    add_account(
+        &mut program_test,
+        FEE_COLLECTOR,
+        1_000_000_000,
+        &[],
+        system_program::ID,
+    );
+    program_test
+}
+
+/// The config created by Initialize, with the test fee collector.
+pub fn config() -> Config {
+    let mut config = Config::zeroed();
+    config.admin = ADMIN_ADDRESS;
+    config.bury_authority = ADMIN_ADDRESS;
+    config.fee_collector = FEE_COLLECTOR;
+    config.fee_authority = ADMIN_ADDRESS;
+    config.pause_authority = ADMIN_ADDRESS;
+    config.change_delay = DEFAULT_CHANGE_DELAY_SLOTS;
+    config.buyback_cap = DEFAULT_BUYBACK_CAP;
+    config.referral_fee = DEFAULT_REFERRAL_FEE;
+    config.board_count = 1;
+    config.total_board_weight = DEFAULT_BOARD_WEIGHT;
+    config.vesting_period = DEFAULT_VESTING_PERIOD;
+    config.motherlode_odds = DEFAULT_MOTHERLODE_ODDS;
+    config.motherlode_rate = DEFAULT_MOTHERLODE_RATE;
+    config.rng_mode = RNG_MODE_SLOT_HASHES;
+    config.bonus_square_count = DEFAULT_BONUS_SQUARE_COUNT;
+    config.bonus_square_multiplier = DEFAULT_BONUS_SQUARE_MULTIPLIER;
+    config
+}
+
+/// The main board, with its current round ending at END_SLOT.
+pub fn board() -> Board {
+    let mut board = Board::zeroed();
+    board.round_id = 1;
+    board.start_slot = END_SLOT - Round::TOTAL_ROUND_SLOTS;
+    board.end_slot = END_SLOT;
+    board.deploy_slots = Round::DEPLOY_PHASE_SLOTS;
+    board.commit_slots = Round::COMMIT_PHASE_SLOTS;
+    board.reveal_slots = Round::REVEAL_PHASE_SLOTS;
+    board.admin_fee = DEFAULT_BOARD_ADMIN_FEE;
+    board.weight = DEFAULT_BOARD_WEIGHT;
+    board.squares = LEGACY_MAX_SQUARES as u64;
+    board
+}
+
+/// A round of the main board with nothing deployed yet.
+pub fn round(id: u64) -> Round {
+    let mut round = Round::zeroed();
+    round.id = id;
+    round.expires_at = END_SLOT + ONE_DAY_SLOTS;
+    round.commit_start_slot = END_SLOT - Round::COMMIT_PHASE_SLOTS - Round::REVEAL_PHASE_SLOTS;
+    round.reveal_start_slot = END_SLOT - Round::REVEAL_PHASE_SLOTS;
+    round.bonus_squares = [NO_BONUS_SQUARE; MAX_BONUS_SQUARES];
+    round
+}
+
+/// The SOL each miner of a won round deployed to the winning square.
+pub const WON_DEPLOYED: u64 = 1_000_000;
+
+/// A miner of round 1 of World::won, yet to checkpoint it.
+pub fn won_miner(authority: Pubkey) -> Miner {
+    let mut miner = Miner::zeroed();
+    miner.authority = authority;
+    miner.round_id = 1;
+    miner.deployed[0] = WON_DEPLOYED;
+    miner
+}
+
+/// The accounts a test starts from: the config, the main board, its round and the treasury, plus
+/// any accounts the test adds. Tests adjust the fields before starting.
+pub struct World {
+    pub config: Config,
+    pub board: Board,
+    pub round: Round,
+    pub treasury: Treasury,
+    program_test: ProgramTest,
+}
+
+impl Default for World {
+    /// The main board in its round 1, ending at END_SLOT.
+    fn default() -> Self {
+        let board = board();
+        Self {
+            config: config(),
+            round: round(board.round_id),
+            board,
+            treasury: Treasury::zeroed(),
+            program_test: program_test(),
+        }
+    }
+}
+
+impl World {
+    /// The main board after its round 1, in which `miners` miners each deployed WON_DEPLOYED to
+    /// the winning square 0.
+    pub fn won(miners: u64) -> Self {
+        let mut world = Self::default();
+        world.board.round_id = 2;
+        world.round.slot_hash = [1; 32];
+        world.round.deployed[0] = WON_DEPLOYED * miners;
+        world.round.count[0] = miners;
+        world.round.total_deployed = WON_DEPLOYED * miners;
+        world
+    }
+
+    /// Makes `admin` the config admin, with SOL to sign.
+    pub fn set_admin(&mut self, admin: Pubkey) {
+        self.config.admin = admin;
+        self.add_wallet(admin);
+    }
+
+    /// Adds a system account with SOL, for signers other than the payer.
+    pub fn add_wallet(&mut self, address: Pubkey) {
+        self.add_account(address, 1_000_000_000, &[], system_program::ID);
+    }
+
+    /// Adds a program account holding `state`, funded for rent plus `lamports`.
+    pub fn add_state<T: Discriminator + Pod>(&mut self, address: Pubkey, state: &T, lamports: u64) {
+        self.add_owned_state(address, state, lamports, skill_api::ID);
+    }
+
+    /// Adds an account holding `state` owned by another program, such as an entropy var.
+    pub fn add_owned_state<T: Discriminator + Pod>(
+        &mut self,
+        address: Pubkey,
+        state: &T,
+        lamports: u64,
+        owner: Pubkey,
+    ) {
+        let data = account_data(state);
+        let lamports = Rent::default().minimum_balance(data.len()) + lamports;
+        self.add_account(address, lamports, &data, owner);
+    }
+
+    /// Adds a raw account.
+    pub fn add_account(&mut self, address: Pubkey, lamports: u64, data: &[u8], owner: Pubkey) {
+        add_account(&mut self.program_test, address, lamports, data, owner);
+    }
+
+    /// Adds the config, board, round and treasury accounts, and starts the test.
+    pub async fn start(mut self) -> ProgramTestContext {
+        let (config, board, round, treasury) = (self.config, self.board, self.round, self.treasury);
+        self.add_state(CONFIG_ADDRESS, &config, 0);
+        self.add_state(board_pda(board.id).0, &board, 0);
+        self.add_state(
+            round_pda(board.id, round.id).0,
+            &round,
+            round.total_deployed,
+        );
+        self.add_state(TREASURY_ADDRESS, &treasury, 0);
+        self.program_test.start_with_context().await
+    }
+}
+
+/// The data of an account holding `state`.
+pub fn account_data<T: Discriminator + Pod>(state: &T) -> Vec<u8> {
+    let mut data = vec![0; 8];
+    data[0] = T::discriminator();
+    data.extend_from_slice(bytemuck::bytes_of(state));
+    data
+}
+
+pub fn add_account(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    lamports: u64,
+    data: &[u8],
+    owner: Pubkey,
+) {
+    program_test.add_account(
+        address,
+        Account {
+            lamports,
+            data: data.to_vec(),
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Adds the SKILL mint, with the treasury as its mint authority, and the treasury's token account.
+fn add_mint(program_test: &mut ProgramTest) {
+    let mut mint = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::Some(TREASURY_ADDRESS),
+        supply: 0,
+        decimals: TOKEN_DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::Some(TREASURY_ADDRESS),
+    }
+    .pack_into_slice(&mut mint);
+    let lamports = Rent::default().minimum_balance(mint.len());
+    add_account(program_test, MINT_ADDRESS, lamports, &mint, spl_token::ID);
+
+    let mut tokens = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint: MINT_ADDRESS,
+        owner: TREASURY_ADDRESS,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut tokens);
+    let lamports = Rent::default().minimum_balance(tokens.len());
+    add_account(
+        program_test,
+        treasury_tokens_address(&spl_token::ID),
+        lamports,
+        &tokens,
+        spl_token::ID,
+    );
+}
+
+/// Signs and sends a transaction from the payer.
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+) -> Result<(), BanksClientError> {
+    process_signed(context, instructions, &[]).await
+}
+
+/// Signs and sends a transaction from the payer, also signed by `signers`.
+pub async fn process_signed(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// The lamports held by an account.
+pub async fn get_lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+/// Simulates a transaction from the payer, returning the compute units it consumed and its logs.
+pub async fn simulate(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+) -> (u64, Vec<String>) {
+    let blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        blockhash,
+    );
+    let result = context.banks_client.simulate_transaction(tx).await.unwrap();
+    if let Some(Err(err)) = result.result {
+        panic!("simulation failed: {:?}", err);
+    }
+    let details = result.simulation_details.unwrap();
+    (details.units_consumed, details.logs)
+}
+
+/// Reads a program account.
+pub async fn get_state<T: Discriminator + Pod>(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+) -> T {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account not found");
+    *bytemuck::from_bytes(&account.data[8..8 + std::mem::size_of::<T>()])
+}
+
+/// Asserts that a transaction failed with the given program error.
+pub fn assert_program_error(result: Result<(), BanksClientError>, error: InstructionError) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, err) => assert_eq!(err, error),
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+/// The instruction error of an OreError.
+pub fn ore_error(error: OreError) -> InstructionError {
+    InstructionError::Custom(error as u32)
+}
@@ -0,0 +1,101 @@
+//! Compute unit benchmarks of the hot instructions. Run them against the compiled program, with
+//! and without formatted logs, to compare the builds:
+//!
+//! cargo test-sbf --test compute_units -- --nocapture
+//! cargo test-sbf --test compute_units --features verbose-logs -- --nocapture
+
+mod common;
+
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+/// The compute units an instruction gets by default.
+const INSTRUCTION_CU_LIMIT: u64 = 200_000;
+
+/// The tested miner's authority.
+const AUTHORITY: Pubkey = Pubkey::new_from_array([9; 32]);
+
+/// Logs the compute units of a benchmark and checks they fit in the default limit. Builds
+/// without `verbose-logs` must not log formatted messages.
+fn report(name: &str, units: u64, logs: &[String], check_logs: bool) {
+    let build = if cfg!(feature = "verbose-logs") {
+        "verbose-logs"
+    } else {
+        "default"
+    };
+    println!("{} ({}): {} CU", name, build, units);
+    assert!(units < INSTRUCTION_CU_LIMIT);
+    if check_logs && !cfg!(feature = "verbose-logs") {
+        assert!(
+            logs.iter().all(|l| !l.starts_with("Program log:")),
+            "{:#?}",
+            logs
+        );
+    }
+}
+
+#[tokio::test]
+async fn bench_deploy() {
+    // The payer deploys to the main board's waiting round.
+    let mut world = World::default();
+    world.board.start_slot = u64::MAX;
+    world.board.end_slot = u64::MAX;
+    let mut context = world.start().await;
+    let payer = context.payer.pubkey();
+    let mut squares = [false; MAX_SQUARES];
+    squares[..LEGACY_MAX_SQUARES].fill(true);
+    let ix = skill_api::sdk::deploy(
+        payer,
+        payer,
+        0,
+        1_000_000,
+        board().round_id,
+        squares,
+        Pubkey::default(),
+        None,
+    );
+    let (units, logs) = simulate(&mut context, &[ix]).await;
+    report("deploy", units, &logs, true);
+}
+
+#[tokio::test]
+async fn bench_reset() {
+    let mut world = World::won(1);
+    world.board.round_id = world.round.id;
+    world.round.slot_hash = [0; 32];
+    let (board, round) = (world.board, world.round);
+    let mut context = world.start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = skill_api::sdk::reset(
+        context.payer.pubkey(),
+        board.id,
+        FEE_COLLECTOR,
+        spl_token::ID,
+        round.id,
+        Pubkey::default(),
+        false,
+    );
+
+    // Reset mints through the token program, which logs in every build.
+    let (units, logs) = simulate(&mut context, &[ix]).await;
+    report("reset", units, &logs, false);
+}
+
+#[tokio::test]
+async fn bench_checkpoint() {
+    let mut world = World::won(1);
+    world.add_state(miner_pda(AUTHORITY).0, &won_miner(AUTHORITY), 0);
+    let (board, round) = (world.board, world.round);
+    let mut context = world.start().await;
+    let ix = skill_api::sdk::checkpoint(
+        context.payer.pubkey(),
+        AUTHORITY,
+        board.id,
+        round.id,
+        None,
+        None,
+    );
+    let (units, logs) = simulate(&mut context, &[ix]).await;
+    report("checkpoint", units, &logs, true);
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// The automation's authority.
+const AUTHORITY: Pubkey = Pubkey::new_from_array([9; 32]);
+
+#[tokio::test]
+async fn test_tripped_limit_closes_automation_before_round_starts() {
+    // An automation that has run all its rounds, on a board waiting for its next round.
+    let executor = Keypair::new();
+    let mut world = World::default();
+    world.board.start_slot = u64::MAX;
+    world.board.end_slot = u64::MAX;
+    world.add_wallet(executor.pubkey());
+    let mut automation = Automation::zeroed();
+    automation.authority = AUTHORITY;
+    automation.executor = executor.pubkey();
+    automation.amount = 1_000_000;
+    automation.balance = 10_000_000;
+    automation.max_rounds = 1;
+    automation.rounds = 1;
+    world.add_state(automation_pda(AUTHORITY).0, &automation, automation.balance);
+    let mut miner = Miner::zeroed();
+    miner.authority = AUTHORITY;
+    world.add_state(miner_pda(AUTHORITY).0, &miner, 0);
+    let board = world.board;
+    let mut context = world.start().await;
+
+    let ix = skill_api::sdk::deploy(
+        executor.pubkey(),
+        AUTHORITY,
+        board.id,
+        0,
+        board.round_id,
+        [false; MAX_SQUARES],
+        Pubkey::default(),
+        None,
+    );
+    process_signed(&mut context, &[ix], &[&executor])
+        .await
+        .unwrap();
+
+    // The automation is closed without starting the round or resetting the miner.
+    assert_eq!(
+        get_lamports(&mut context, automation_pda(AUTHORITY).0).await,
+        0
+    );
+    let board = get_state::<Board>(&mut context, board_pda(board.id).0).await;
+    assert_eq!(board.end_slot, u64::MAX);
+    let miner = get_state::<Miner>(&mut context, miner_pda(AUTHORITY).0).await;
+    assert_eq!(miner.round_id, 0);
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+
+/// The guild's executor.
+const EXECUTOR: Pubkey = Pubkey::new_from_array([9; 32]);
+
+/// The member's own SOL in its automation.
+const MEMBER_BALANCE: u64 = 400_000;
+
+/// The SOL the guild funded the member's automation with.
+const GUILD_BALANCE: u64 = 600_000;
+
+/// The main board, with a guild member whose automation holds both its own and guild funds.
+fn guild_world(member: &Keypair) -> World {
+    let mut world = World::default();
+    world.add_wallet(member.pubkey());
+
+    let guild_address = guild_pda(0).0;
+    let mut guild = Guild::zeroed();
+    guild.authority = EXECUTOR;
+    guild.executor = EXECUTOR;
+    guild.member_count = 1;
+    world.add_state(guild_address, &guild, 0);
+
+    let mut miner = Miner::zeroed();
+    miner.authority = member.pubkey();
+    miner.guild = guild_address;
+    world.add_state(miner_pda(member.pubkey()).0, &miner, 0);
+
+    let mut automation = Automation::zeroed();
+    automation.authority = member.pubkey();
+    automation.executor = EXECUTOR;
+    automation.balance = MEMBER_BALANCE;
+    automation.fund_from_guild(guild_address, GUILD_BALANCE);
+    world.add_state(
+        automation_pda(member.pubkey()).0,
+        &automation,
+        automation.balance,
+    );
+    world
+}
+
+fn close_ix(member: Pubkey, guild: Option<Pubkey>) -> Instruction {
+    skill_api::sdk::automate(
+        member,
+        0,
+        0,
+        0,
+        Pubkey::default(),
+        0,
+        0,
+        0,
+        false,
+        Pubkey::default(),
+        guild,
+    )
+}
+
+#[tokio::test]
+async fn test_close_returns_guild_funds() {
+    let member = Keypair::new();
+    let mut context = guild_world(&member).start().await;
+    let guild_address = guild_pda(0).0;
+    let automation_size = 8 + std::mem::size_of::<Automation>();
+    let automation_rent = Rent::default().minimum_balance(automation_size);
+    let guild_lamports = get_lamports(&mut context, guild_address).await;
+    let member_lamports = get_lamports(&mut context, member.pubkey()).await;
+
+    let ix = close_ix(member.pubkey(), Some(guild_address));
+    process_signed(&mut context, &[ix], &[&member])
+        .await
+        .unwrap();
+
+    // The member gets back only its own funds and the rent.
+    assert_eq!(
+        get_lamports(&mut context, member.pubkey()).await,
+        member_lamports + automation_rent + MEMBER_BALANCE
+    );
+    assert_eq!(
+        get_lamports(&mut context, guild_address).await,
+        guild_lamports + GUILD_BALANCE
+    );
+    let guild = get_state::<Guild>(&mut context, guild_address).await;
+    assert_eq!(guild.balance, GUILD_BALANCE);
+}
+
+#[tokio::test]
+async fn test_close_requires_guild() {
+    let member = Keypair::new();
+    let mut context = guild_world(&member).start().await;
+    let ix = close_ix(member.pubkey(), None);
+    let result = process_signed(&mut context, &[ix], &[&member]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn test_leave_guild_returns_guild_funds() {
+    let member = Keypair::new();
+    let mut context = guild_world(&member).start().await;
+    let guild_address = guild_pda(0).0;
+    let ix = skill_api::sdk::leave_guild(member.pubkey(), guild_address);
+    process_signed(&mut context, &[ix], &[&member])
+        .await
+        .unwrap();
+
+    let automation = get_state::<Automation>(&mut context, automation_pda(member.pubkey()).0).await;
+    assert_eq!(automation.balance, MEMBER_BALANCE);
+    assert_eq!(automation.guild_balance, 0);
+    let guild = get_state::<Guild>(&mut context, guild_address).await;
+    assert_eq!(guild.balance, GUILD_BALANCE);
+    assert_eq!(guild.member_count, 0);
+}
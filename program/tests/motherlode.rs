@@ -0,0 +1,67 @@
+mod common;
+
+use common::*;
+use skill_api::prelude::*;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+/// The ORE in the tested board's motherlode pool.
+const BOARD_MOTHERLODE: u64 = 500;
+
+/// The ORE left in the pool shared by all boards before they had their own.
+const LEGACY_MOTHERLODE: u64 = 1_000;
+
+/// Board `board_id`, whose round ends with a miner on the winning square and always hits the
+/// motherlode.
+fn motherlode_world(board_id: u64) -> World {
+    let mut world = World::default();
+    world.config.motherlode_odds = 1;
+    world.board.id = board_id;
+    world.board.motherlode = BOARD_MOTHERLODE;
+    world.round.deployed[0] = 1_000_000;
+    world.round.count[0] = 1;
+    world.round.total_deployed = 1_000_000;
+    world.treasury.motherlode = LEGACY_MOTHERLODE;
+    world
+}
+
+/// Resets board `board_id` once its round ends.
+async fn reset(context: &mut ProgramTestContext, board_id: u64) -> (Board, Round, Treasury) {
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let round_id = board().round_id;
+    let ix = skill_api::sdk::reset(
+        context.payer.pubkey(),
+        board_id,
+        FEE_COLLECTOR,
+        spl_token::ID,
+        round_id,
+        Pubkey::default(),
+        false,
+    );
+    process(context, &[ix]).await.unwrap();
+    (
+        get_state::<Board>(context, board_pda(board_id).0).await,
+        get_state::<Round>(context, round_pda(board_id, round_id).0).await,
+        get_state::<Treasury>(context, TREASURY_ADDRESS).await,
+    )
+}
+
+#[tokio::test]
+async fn test_motherlode_pays_only_the_hitting_board() {
+    let mut context = motherlode_world(1).start().await;
+    let (board, round, treasury) = reset(&mut context, 1).await;
+
+    // The board's own pool pays out, then refills with the board's share of the rate.
+    assert_eq!(round.motherlode, BOARD_MOTHERLODE);
+    assert_eq!(board.motherlode, DEFAULT_MOTHERLODE_RATE);
+    assert_eq!(treasury.motherlode, LEGACY_MOTHERLODE);
+}
+
+#[tokio::test]
+async fn test_main_board_motherlode_pays_legacy_pool() {
+    let mut context = motherlode_world(0).start().await;
+    let (board, round, treasury) = reset(&mut context, 0).await;
+    assert_eq!(round.motherlode, BOARD_MOTHERLODE + LEGACY_MOTHERLODE);
+    assert_eq!(board.motherlode, DEFAULT_MOTHERLODE_RATE);
+    assert_eq!(treasury.motherlode, 0);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use bytemuck::Zeroable;
+use common::*;
+use entropy_api::state::Var;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+/// A board's var, armed for the round ending at END_SLOT.
+fn var(board_address: Pubkey, revealed: bool) -> Var {
+    let mut var = Var::zeroed();
+    var.authority = board_address;
+    var.samples = 1;
+    var.end_at = END_SLOT;
+    if revealed {
+        var.slot_hash = [1; 32];
+        var.seed = [2; 32];
+        var.value = [3; 32];
+    }
+    var
+}
+
+/// The main board in entropy mode, with its var revealed or not.
+fn entropy_world(revealed: bool) -> World {
+    let mut world = World::default();
+    world.config.rng_mode = RNG_MODE_ENTROPY;
+    let board_address = board_pda(world.board.id).0;
+    world.add_owned_state(
+        board_var_pda(board_address).0,
+        &var(board_address, revealed),
+        0,
+        entropy_api::ID,
+    );
+    world
+}
+
+fn reset_ix(payer: Pubkey, entropy: bool) -> Instruction {
+    let board = board();
+    skill_api::sdk::reset(
+        payer,
+        board.id,
+        FEE_COLLECTOR,
+        spl_token::ID,
+        board.round_id,
+        Pubkey::default(),
+        entropy,
+    )
+}
+
+#[tokio::test]
+async fn test_entropy_reset_uses_revealed_var() {
+    let mut context = entropy_world(true).start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = reset_ix(context.payer.pubkey(), true);
+    process(&mut context, &[ix]).await.unwrap();
+    let round = get_state::<Round>(&mut context, round_pda(0, board().round_id).0).await;
+    assert_eq!(round.slot_hash, [3; 32]);
+}
+
+#[tokio::test]
+async fn test_entropy_reset_requires_var() {
+    let mut context = entropy_world(true).start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = reset_ix(context.payer.pubkey(), false);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn test_entropy_reset_requires_var_after_timeout() {
+    let mut context = entropy_world(false).start().await;
+    context
+        .warp_to_slot(END_SLOT + ENTROPY_TIMEOUT_SLOTS)
+        .unwrap();
+    let ix = reset_ix(context.payer.pubkey(), false);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, InstructionError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn test_entropy_reset_rejects_other_var() {
+    let mut world = entropy_world(true);
+    let board_address = board_pda(0).0;
+    let other = Pubkey::new_unique();
+    world.add_owned_state(other, &var(board_address, true), 0, entropy_api::ID);
+    let mut context = world.start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let mut ix = reset_ix(context.payer.pubkey(), true);
+    let var_index = ix.accounts.len() - 2;
+    ix.accounts[var_index].pubkey = other;
+    let result = process(&mut context, &[ix]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_entropy_reset_waits_for_reveal() {
+    let mut context = entropy_world(false).start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = reset_ix(context.payer.pubkey(), true);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, ore_error(OreError::EntropyNotReady));
+}
+
+#[tokio::test]
+async fn test_entropy_reset_waits_until_timeout() {
+    let mut context = entropy_world(false).start().await;
+    context
+        .warp_to_slot(END_SLOT + ENTROPY_TIMEOUT_SLOTS - 1)
+        .unwrap();
+    let ix = reset_ix(context.payer.pubkey(), true);
+    let result = process(&mut context, &[ix]).await;
+    assert_program_error(result, ore_error(OreError::EntropyNotReady));
+}
+
+#[tokio::test]
+async fn test_entropy_reset_falls_back_after_timeout() {
+    let mut context = entropy_world(false).start().await;
+    context
+        .warp_to_slot(END_SLOT + ENTROPY_TIMEOUT_SLOTS)
+        .unwrap();
+    let ix = reset_ix(context.payer.pubkey(), true);
+    process(&mut context, &[ix]).await.unwrap();
+    let round = get_state::<Round>(&mut context, round_pda(0, board().round_id).0).await;
+    assert!(round.is_finalized());
+    assert_ne!(round.slot_hash, [3; 32]);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn new_season_ix(admin: Pubkey, season_id: u64, start_round: u64, end_round: u64) -> Instruction {
+    skill_api::sdk::new_season(admin, season_id, 0, start_round, end_round, 0)
+}
+
+#[tokio::test]
+async fn test_new_season_stamps_rounds() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    let mut context = world.start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = new_season_ix(admin.pubkey(), 0, 2, 5);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+    let board = get_state::<Board>(&mut context, board_pda(0).0).await;
+    assert_eq!(board.season, 1);
+    assert_eq!(board.season_start_round, 2);
+    assert_eq!(board.season_end_round, 5);
+
+    // The next round is opened as part of the season.
+    let ix = skill_api::sdk::reset(
+        context.payer.pubkey(),
+        0,
+        FEE_COLLECTOR,
+        spl_token::ID,
+        board.round_id,
+        Pubkey::default(),
+        false,
+    );
+    process(&mut context, &[ix]).await.unwrap();
+    let round = get_state::<Round>(&mut context, round_pda(0, 2).0).await;
+    assert_eq!(round.season_id(), Some(0));
+}
+
+#[tokio::test]
+async fn test_new_season_must_start_after_current_round() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    let mut context = world.start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = new_season_ix(admin.pubkey(), 0, 1, 5);
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_new_season_cannot_overlap() {
+    let admin = Keypair::new();
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    let mut context = world.start().await;
+    context.warp_to_slot(END_SLOT + INTERMISSION_SLOTS).unwrap();
+    let ix = new_season_ix(admin.pubkey(), 0, 2, 5);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+    let ix = new_season_ix(admin.pubkey(), 1, 5, 10);
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert!(result.is_err());
+    let ix = new_season_ix(admin.pubkey(), 1, 6, 10);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::*;
+use skill_api::prelude::*;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// The main board waiting for its next round to start, with bonus squares drawn for 5x5, and
+/// `admin` administering the config.
+fn board_world(admin: &Keypair) -> World {
+    let mut world = World::default();
+    world.set_admin(admin.pubkey());
+    world.board.start_slot = u64::MAX;
+    world.board.end_slot = u64::MAX;
+    world.round.bonus_squares = [
+        3,
+        12,
+        24,
+        NO_BONUS_SQUARE,
+        NO_BONUS_SQUARE,
+        NO_BONUS_SQUARE,
+        NO_BONUS_SQUARE,
+    ];
+    world
+}
+
+fn update_board_ix(admin: Pubkey, board: &Board, squares: u64) -> Instruction {
+    skill_api::sdk::update_board(
+        admin,
+        board.id,
+        board.round_id,
+        board.min_deploy,
+        board.deploy_slots,
+        board.commit_slots,
+        board.reveal_slots,
+        squares,
+    )
+}
+
+#[tokio::test]
+async fn test_geometry_change_clears_bonus_squares() {
+    let admin = Keypair::new();
+    let world = board_world(&admin);
+    let board = world.board;
+    let mut context = world.start().await;
+    let ix = update_board_ix(admin.pubkey(), &board, 9);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+
+    let updated = get_state::<Board>(&mut context, board_pda(board.id).0).await;
+    assert_eq!(updated.squares, 9);
+    let round = get_state::<Round>(&mut context, round_pda(board.id, board.round_id).0).await;
+    assert_eq!(round.bonus_squares, [NO_BONUS_SQUARE; MAX_BONUS_SQUARES]);
+    assert_eq!(round.active_bonus_squares().count(), 0);
+}
+
+#[tokio::test]
+async fn test_geometry_change_requires_round() {
+    let admin = Keypair::new();
+    let world = board_world(&admin);
+    let board = world.board;
+    let mut context = world.start().await;
+    let mut ix = update_board_ix(admin.pubkey(), &board, 9);
+    ix.accounts.pop();
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_geometry_unchanged_keeps_bonus_squares() {
+    let admin = Keypair::new();
+    let world = board_world(&admin);
+    let board = world.board;
+    let mut context = world.start().await;
+    let ix = update_board_ix(admin.pubkey(), &board, board.squares);
+    process_signed(&mut context, &[ix], &[&admin])
+        .await
+        .unwrap();
+
+    let round = get_state::<Round>(&mut context, round_pda(board.id, board.round_id).0).await;
+    assert_eq!(round.bonus_squares[..3], [3, 12, 24]);
+}
+
+#[tokio::test]
+async fn test_update_board_rejects_other_board_accounts() {
+    let admin = Keypair::new();
+    let mut world = board_world(&admin);
+    let board = world.board;
+    let other = Pubkey::new_unique();
+    world.add_state(other, &board, 0);
+    let mut context = world.start().await;
+
+    // A board account at another address than the board's.
+    let mut ix = update_board_ix(admin.pubkey(), &board, board.squares);
+    ix.accounts[2].pubkey = other;
+    let result = process_signed(&mut context, &[ix], &[&admin]).await;
+    assert!(result.is_err());
+}